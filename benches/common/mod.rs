@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use std::env;
+use std::hint;
+use std::time::Duration;
+use std::time::Instant;
+
+const BATCH_SIZE: u32 = 1000;
+
+const TARGET_DURATION: Duration = Duration::from_millis(500);
+
+// Runs the function repeatedly for about [TARGET_DURATION] and prints the
+// mean duration of each call. The first argument that is not an option
+// filters the benchmarks by name, like it would for the default harness.
+pub(crate) fn bench<F, R>(name: &str, mut f: F)
+where
+    F: FnMut() -> R,
+{
+    if let Some(filter) = env::args().skip(1).find(|x| !x.starts_with('-')) {
+        if !name.contains(&*filter) {
+            return;
+        }
+    }
+
+    let start = Instant::now();
+    let mut iterations = 0;
+    let elapsed = loop {
+        for _ in 0..BATCH_SIZE {
+            let _ = hint::black_box(f());
+        }
+        iterations += BATCH_SIZE;
+
+        let elapsed = start.elapsed();
+        if elapsed >= TARGET_DURATION {
+            break elapsed;
+        }
+    };
+    let nanos = elapsed.as_secs_f64() * 1e9 / f64::from(iterations);
+    println!("{:<40} {:>10.1} ns", name, nanos);
+}
@@ -0,0 +1,41 @@
+use std::ffi::OsString;
+
+use os_str_bytes::OsStringBytes;
+
+mod common;
+use common::bench;
+
+const LENGTH: usize = 64;
+
+#[cfg(unix)]
+fn invalid_string() -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+
+    OsString::from_vec(b"foo\xFFbar".repeat(LENGTH))
+}
+
+#[cfg(windows)]
+fn invalid_string() -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+
+    let string: Vec<_> = "foo"
+        .encode_utf16()
+        .chain([0xD800])
+        .chain("bar".encode_utf16())
+        .collect();
+    OsString::from_wide(&string.repeat(LENGTH))
+}
+
+fn main() {
+    let valid = OsString::from("foo_bar".repeat(LENGTH));
+    let invalid = invalid_string();
+
+    bench("clone_valid", || valid.clone());
+    bench("into_io_vec_lossy_valid", || {
+        valid.clone().into_io_vec_lossy()
+    });
+    bench("clone_invalid", || invalid.clone());
+    bench("into_io_vec_lossy_invalid", || {
+        invalid.clone().into_io_vec_lossy()
+    });
+}
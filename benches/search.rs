@@ -0,0 +1,23 @@
+use std::ffi::OsStr;
+
+use os_str_bytes::OsStrBytesExt;
+
+mod common;
+use common::bench;
+
+fn main() {
+    let argument = OsStr::new("--color=always");
+    let long_string = "foo_bar_".repeat(64);
+    let long_string = OsStr::new(&long_string);
+
+    bench("contains_longer_pattern", || {
+        argument.contains("--color=always-and-more")
+    });
+    bench("contains_short_pattern", || argument.contains("always"));
+    bench("find_ascii_char", || argument.find('='));
+    bench("find_non_ascii_char", || argument.find('\u{1F4A9}'));
+    bench("find_str", || argument.find("=a"));
+    bench("find_long_string", || long_string.find("bar_baz"));
+    bench("rfind_ascii_char", || argument.rfind('-'));
+    bench("split_once_ascii_char", || argument.split_once('='));
+}
@@ -0,0 +1,16 @@
+use os_str_bytes::RawOsString;
+
+mod common;
+use common::bench;
+
+fn main() {
+    let string = "foo\u{1F4A9}bar".repeat(1024);
+    let raw = RawOsString::new(string.clone());
+
+    bench("new", || RawOsString::new(string.clone()));
+    bench("is_unicode", || raw.is_unicode());
+    bench("is_unicode_cached", || raw.is_unicode_cached());
+    bench("to_str", || raw.to_str());
+    bench("into_string", || raw.clone().into_string());
+    bench("to_str_lossy", || raw.to_str_lossy());
+}
@@ -0,0 +1,841 @@
+//! A subset of [`OsStrBytesExt`] that never allocates.
+//!
+//! The methods of [`OsStrBytesBorrowedExt`] only search, slice, trim, and
+//! iterate over borrowed strings. Code with strict latency requirements can
+//! import this trait instead of [`OsStrBytesExt`] to guarantee that no
+//! method it calls will allocate, and audits only need to consider this
+//! module.
+//!
+//! Both traits are implemented for [`OsStr`] using the same method names, so
+//! they should not be imported in the same scope.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::ffi::OsStr;
+//!
+//! use os_str_bytes::borrowed::OsStrBytesBorrowedExt;
+//!
+//! let os_string = OsStr::new("  foo=bar  ");
+//! let (key, value) = os_string.trim_matches(' ').split_once('=').unwrap();
+//! assert_eq!("foo", key);
+//! assert_eq!("bar", value);
+//! ```
+//!
+//! [`OsStrBytesExt`]: super::OsStrBytesExt
+
+#![cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "borrowed")))]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::ffi::OsStr;
+
+use super::iter::ChunkBySize;
+use super::iter::CodePoints;
+use super::iter::ComponentsNaive;
+use super::iter::MatchIndicesOs;
+use super::iter::RSplit;
+use super::iter::Split;
+use super::iter::SplitIndices;
+use super::iter::SplitKeep;
+use super::iter::SplitWith;
+use super::iter::Units;
+use super::iter::Utf8Chunks;
+use super::private;
+use super::BoundaryError;
+use super::CharOrInvalid;
+use super::EmptyPatternError;
+use super::LimitUnit;
+use super::NonUnicodeOsStr;
+use super::OsStrBytesExt;
+use super::Pattern;
+use super::SliceIndex;
+use super::SplitOptions;
+
+/// An extension trait providing the methods of [`OsStrBytesExt`] that never
+/// allocate.
+///
+/// For more information, see [the module-level documentation][module].
+///
+/// [module]: self
+pub trait OsStrBytesBorrowedExt: private::Sealed {
+    /// Equivalent to [`OsStrBytesExt::byte_len`].
+    #[must_use]
+    fn byte_len(&self) -> usize;
+
+    /// Equivalent to [`OsStrBytesExt::ceil_char_boundary`].
+    #[must_use]
+    fn ceil_char_boundary(&self, index: usize) -> usize;
+
+    /// Equivalent to [`OsStrBytesExt::chunk_by_size`].
+    #[track_caller]
+    fn chunk_by_size(&self, size: usize) -> ChunkBySize<'_>;
+
+    /// Equivalent to [`OsStrBytesExt::code_points`].
+    fn code_points(&self) -> CodePoints<'_>;
+
+    /// Equivalent to [`OsStrBytesExt::components_naive`].
+    fn components_naive<'a>(
+        &'a self,
+        separators: &'a [char],
+    ) -> ComponentsNaive<'a>;
+
+    /// Equivalent to [`OsStrBytesExt::contains`].
+    #[must_use]
+    fn contains<P>(&self, pat: P) -> bool
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::contains_os`].
+    #[must_use]
+    fn contains_os(&self, pat: &Self) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::ends_with`].
+    #[must_use]
+    fn ends_with<P>(&self, pat: P) -> bool
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::ends_with_any`].
+    ///
+    /// Unlike that method, this one requires patterns to implement [`Copy`],
+    /// since cloning a pattern such as an owned [`Cow`] would allocate.
+    ///
+    /// [`Cow`]: std::borrow::Cow
+    #[must_use]
+    fn ends_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Copy + Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::eq_constant_time`].
+    #[must_use]
+    fn eq_constant_time(&self, other: &Self) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::eq_os_ignoring_trailing_separators`].
+    #[must_use]
+    fn eq_os_ignoring_trailing_separators(
+        &self,
+        other: &Self,
+        separators: &[char],
+    ) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::find`].
+    #[must_use]
+    fn find<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::find_any_of`].
+    #[must_use]
+    #[track_caller]
+    fn find_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize>;
+
+    /// Equivalent to [`OsStrBytesExt::find_map_chunks`].
+    fn find_map_chunks<F, T>(&self, f: F) -> Option<T>
+    where
+        F: FnMut(usize, &NonUnicodeOsStr, &str) -> Option<T>;
+
+    /// Equivalent to [`OsStrBytesExt::fingerprint`].
+    #[must_use]
+    fn fingerprint(&self) -> u64;
+
+    /// Equivalent to [`OsStrBytesExt::first_char`].
+    #[must_use]
+    fn first_char(&self) -> Option<char>;
+
+    /// Equivalent to [`OsStrBytesExt::floor_char_boundary`].
+    #[must_use]
+    fn floor_char_boundary(&self, index: usize) -> usize;
+
+    /// Equivalent to [`OsStrBytesExt::get_range`].
+    #[must_use]
+    fn get_range<I>(&self, index: I) -> Option<&Self>
+    where
+        I: SliceIndex;
+
+    /// Equivalent to [`OsStrBytesExt::has_extension`].
+    #[must_use]
+    fn has_extension(&self, ext: &str, case_insensitive: bool) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::has_invalid_sequences`].
+    #[must_use]
+    fn has_invalid_sequences(&self) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::index`].
+    #[must_use]
+    #[track_caller]
+    fn index<I>(&self, index: I) -> &Self
+    where
+        I: SliceIndex;
+
+    /// Equivalent to [`OsStrBytesExt::is_ascii`].
+    #[must_use]
+    fn is_ascii(&self) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::is_unicode`].
+    #[must_use]
+    fn is_unicode(&self) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::last_char`].
+    #[must_use]
+    fn last_char(&self) -> Option<char>;
+
+    /// Equivalent to [`OsStrBytesExt::len_chars`].
+    #[must_use]
+    fn len_chars(&self) -> usize;
+
+    /// Equivalent to [`OsStrBytesExt::match_indices_os`].
+    fn match_indices_os<'a>(&'a self, pat: &'a Self) -> MatchIndicesOs<'a>;
+
+    /// Equivalent to [`OsStrBytesExt::rfind`].
+    #[must_use]
+    fn rfind<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::rfind_any_of`].
+    #[must_use]
+    #[track_caller]
+    fn rfind_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize>;
+
+    /// Equivalent to [`OsStrBytesExt::rsplit`].
+    #[track_caller]
+    fn rsplit<P>(&self, pat: P) -> RSplit<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::rsplit_once`].
+    #[must_use]
+    fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::slice_from`].
+    #[must_use]
+    fn slice_from(&self, index: usize) -> &Self;
+
+    /// Equivalent to [`OsStrBytesExt::slice_up_to`].
+    #[must_use]
+    fn slice_up_to(&self, index: usize) -> &Self;
+
+    /// Equivalent to [`OsStrBytesExt::split`].
+    #[track_caller]
+    fn split<P>(&self, pat: P) -> Split<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::split_at`].
+    #[must_use]
+    #[track_caller]
+    fn split_at(&self, mid: usize) -> (&Self, &Self);
+
+    /// Equivalent to [`OsStrBytesExt::split_at_checked`].
+    #[must_use]
+    fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)>;
+
+    /// Equivalent to [`OsStrBytesExt::split_first`].
+    #[must_use]
+    fn split_first(&self) -> Option<(CharOrInvalid<'_>, &Self)>;
+
+    /// Equivalent to [`OsStrBytesExt::split_indices`].
+    #[track_caller]
+    fn split_indices<P>(&self, pat: P) -> SplitIndices<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::split_keep`].
+    #[track_caller]
+    fn split_keep<P>(&self, pat: P) -> SplitKeep<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::split_last`].
+    #[must_use]
+    fn split_last(&self) -> Option<(CharOrInvalid<'_>, &Self)>;
+
+    /// Equivalent to [`OsStrBytesExt::split_leading_digits`].
+    #[must_use]
+    fn split_leading_digits(&self) -> (Option<&str>, &Self);
+
+    /// Equivalent to [`OsStrBytesExt::split_nul`].
+    fn split_nul(&self) -> SplitWith<'_, char>;
+
+    /// Equivalent to [`OsStrBytesExt::split_once`].
+    #[must_use]
+    fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::split_once_any`].
+    ///
+    /// Unlike that method, this one requires patterns to implement [`Copy`],
+    /// since cloning a pattern such as an owned [`Cow`] would allocate.
+    ///
+    /// [`Cow`]: std::borrow::Cow
+    #[must_use]
+    fn split_once_any<P>(&self, pats: &[P]) -> Option<(&Self, &Self, usize)>
+    where
+        P: Copy + Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::split_once_ascii`].
+    #[must_use]
+    #[track_caller]
+    fn split_once_ascii(&self, byte: u8) -> Option<(&Self, &Self)>;
+
+    /// Equivalent to [`OsStrBytesExt::split_trailing_digits`].
+    #[must_use]
+    fn split_trailing_digits(&self) -> (&Self, Option<&str>);
+
+    /// Equivalent to [`OsStrBytesExt::split_with`].
+    #[track_caller]
+    fn split_with<P>(&self, pat: P, options: SplitOptions) -> SplitWith<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::starts_with`].
+    #[must_use]
+    fn starts_with<P>(&self, pat: P) -> bool
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::starts_with_alphabetic`].
+    #[must_use]
+    fn starts_with_alphabetic(&self) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::starts_with_any`].
+    ///
+    /// Unlike that method, this one requires patterns to implement [`Copy`],
+    /// since cloning a pattern such as an owned [`Cow`] would allocate.
+    ///
+    /// [`Cow`]: std::borrow::Cow
+    #[must_use]
+    fn starts_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Copy + Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::starts_with_ascii_digit`].
+    #[must_use]
+    fn starts_with_ascii_digit(&self) -> bool;
+
+    /// Equivalent to [`OsStrBytesExt::strip_bom`].
+    #[must_use]
+    fn strip_bom(&self) -> &Self;
+
+    /// Equivalent to [`OsStrBytesExt::strip_circumfix`].
+    #[must_use]
+    fn strip_circumfix<P, S>(&self, prefix: P, suffix: S) -> Option<&Self>
+    where
+        P: Pattern,
+        S: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::strip_prefix`].
+    #[must_use]
+    fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::strip_suffix`].
+    #[must_use]
+    fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::trim_end_matches`].
+    #[must_use]
+    fn trim_end_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::trim_end_matches_counted`].
+    #[must_use]
+    fn trim_end_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::trim_matches`].
+    #[must_use]
+    fn trim_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::trim_matches_counted`].
+    #[must_use]
+    fn trim_matches_counted<P>(&self, pat: P) -> (&Self, usize, usize)
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::trim_quotes`].
+    #[must_use]
+    fn trim_quotes(&self) -> &Self;
+
+    /// Equivalent to [`OsStrBytesExt::trim_start_matches`].
+    #[must_use]
+    fn trim_start_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::trim_start_matches_counted`].
+    #[must_use]
+    fn trim_start_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::truncate_to_limit`].
+    #[must_use]
+    fn truncate_to_limit(&self, max_units: usize, unit: LimitUnit) -> &Self;
+
+    /// Equivalent to [`OsStrBytesExt::try_rsplit`].
+    fn try_rsplit<P>(
+        &self,
+        pat: P,
+    ) -> Result<RSplit<'_, P>, EmptyPatternError>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::try_split`].
+    fn try_split<P>(&self, pat: P) -> Result<Split<'_, P>, EmptyPatternError>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`OsStrBytesExt::units`].
+    fn units(&self) -> Units<'_>;
+
+    /// Equivalent to [`OsStrBytesExt::utf8_chunks`].
+    fn utf8_chunks(&self) -> Utf8Chunks<'_>;
+
+    /// Equivalent to [`OsStrBytesExt::validate_boundary`].
+    fn validate_boundary(&self, index: usize) -> Result<(), BoundaryError>;
+}
+
+impl OsStrBytesBorrowedExt for OsStr {
+    #[inline]
+    fn byte_len(&self) -> usize {
+        OsStrBytesExt::byte_len(self)
+    }
+
+    #[inline]
+    fn ceil_char_boundary(&self, index: usize) -> usize {
+        OsStrBytesExt::ceil_char_boundary(self, index)
+    }
+
+    #[inline]
+    fn chunk_by_size(&self, size: usize) -> ChunkBySize<'_> {
+        OsStrBytesExt::chunk_by_size(self, size)
+    }
+
+    #[inline]
+    fn code_points(&self) -> CodePoints<'_> {
+        OsStrBytesExt::code_points(self)
+    }
+
+    #[inline]
+    fn components_naive<'a>(
+        &'a self,
+        separators: &'a [char],
+    ) -> ComponentsNaive<'a> {
+        OsStrBytesExt::components_naive(self, separators)
+    }
+
+    #[inline]
+    fn contains<P>(&self, pat: P) -> bool
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::contains(self, pat)
+    }
+
+    #[inline]
+    fn contains_os(&self, pat: &Self) -> bool {
+        OsStrBytesExt::contains_os(self, pat)
+    }
+
+    #[inline]
+    fn ends_with<P>(&self, pat: P) -> bool
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::ends_with(self, pat)
+    }
+
+    #[inline]
+    fn ends_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Copy + Pattern,
+    {
+        OsStrBytesExt::ends_with_any(self, pats)
+    }
+
+    #[inline]
+    fn eq_constant_time(&self, other: &Self) -> bool {
+        OsStrBytesExt::eq_constant_time(self, other)
+    }
+
+    #[inline]
+    fn eq_os_ignoring_trailing_separators(
+        &self,
+        other: &Self,
+        separators: &[char],
+    ) -> bool {
+        OsStrBytesExt::eq_os_ignoring_trailing_separators(
+            self, other, separators,
+        )
+    }
+
+    #[inline]
+    fn find<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::find(self, pat)
+    }
+
+    #[inline]
+    fn find_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize> {
+        OsStrBytesExt::find_any_of(self, bytes)
+    }
+
+    #[inline]
+    fn find_map_chunks<F, T>(&self, f: F) -> Option<T>
+    where
+        F: FnMut(usize, &NonUnicodeOsStr, &str) -> Option<T>,
+    {
+        OsStrBytesExt::find_map_chunks(self, f)
+    }
+
+    #[inline]
+    fn fingerprint(&self) -> u64 {
+        OsStrBytesExt::fingerprint(self)
+    }
+
+    #[inline]
+    fn first_char(&self) -> Option<char> {
+        OsStrBytesExt::first_char(self)
+    }
+
+    #[inline]
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        OsStrBytesExt::floor_char_boundary(self, index)
+    }
+
+    #[inline]
+    fn get_range<I>(&self, index: I) -> Option<&Self>
+    where
+        I: SliceIndex,
+    {
+        OsStrBytesExt::get_range(self, index)
+    }
+
+    #[inline]
+    fn has_extension(&self, ext: &str, case_insensitive: bool) -> bool {
+        OsStrBytesExt::has_extension(self, ext, case_insensitive)
+    }
+
+    #[inline]
+    fn has_invalid_sequences(&self) -> bool {
+        OsStrBytesExt::has_invalid_sequences(self)
+    }
+
+    #[inline]
+    fn index<I>(&self, index: I) -> &Self
+    where
+        I: SliceIndex,
+    {
+        OsStrBytesExt::index(self, index)
+    }
+
+    #[inline]
+    fn is_ascii(&self) -> bool {
+        OsStrBytesExt::is_ascii(self)
+    }
+
+    #[inline]
+    fn is_unicode(&self) -> bool {
+        OsStrBytesExt::is_unicode(self)
+    }
+
+    #[inline]
+    fn last_char(&self) -> Option<char> {
+        OsStrBytesExt::last_char(self)
+    }
+
+    #[inline]
+    fn len_chars(&self) -> usize {
+        OsStrBytesExt::len_chars(self)
+    }
+
+    #[inline]
+    fn match_indices_os<'a>(&'a self, pat: &'a Self) -> MatchIndicesOs<'a> {
+        OsStrBytesExt::match_indices_os(self, pat)
+    }
+
+    #[inline]
+    fn rfind<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::rfind(self, pat)
+    }
+
+    #[inline]
+    fn rfind_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize> {
+        OsStrBytesExt::rfind_any_of(self, bytes)
+    }
+
+    #[inline]
+    fn rsplit<P>(&self, pat: P) -> RSplit<'_, P>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::rsplit(self, pat)
+    }
+
+    #[inline]
+    fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::rsplit_once(self, pat)
+    }
+
+    #[inline]
+    fn slice_from(&self, index: usize) -> &Self {
+        OsStrBytesExt::slice_from(self, index)
+    }
+
+    #[inline]
+    fn slice_up_to(&self, index: usize) -> &Self {
+        OsStrBytesExt::slice_up_to(self, index)
+    }
+
+    #[inline]
+    fn split<P>(&self, pat: P) -> Split<'_, P>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::split(self, pat)
+    }
+
+    #[inline]
+    fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        OsStrBytesExt::split_at(self, mid)
+    }
+
+    #[inline]
+    fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)> {
+        OsStrBytesExt::split_at_checked(self, mid)
+    }
+
+    #[inline]
+    fn split_first(&self) -> Option<(CharOrInvalid<'_>, &Self)> {
+        OsStrBytesExt::split_first(self)
+    }
+
+    #[inline]
+    fn split_indices<P>(&self, pat: P) -> SplitIndices<'_, P>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::split_indices(self, pat)
+    }
+
+    #[inline]
+    fn split_keep<P>(&self, pat: P) -> SplitKeep<'_, P>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::split_keep(self, pat)
+    }
+
+    #[inline]
+    fn split_last(&self) -> Option<(CharOrInvalid<'_>, &Self)> {
+        OsStrBytesExt::split_last(self)
+    }
+
+    #[inline]
+    fn split_leading_digits(&self) -> (Option<&str>, &Self) {
+        OsStrBytesExt::split_leading_digits(self)
+    }
+
+    #[inline]
+    fn split_nul(&self) -> SplitWith<'_, char> {
+        OsStrBytesExt::split_nul(self)
+    }
+
+    #[inline]
+    fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::split_once(self, pat)
+    }
+
+    #[inline]
+    fn split_once_any<P>(&self, pats: &[P]) -> Option<(&Self, &Self, usize)>
+    where
+        P: Copy + Pattern,
+    {
+        OsStrBytesExt::split_once_any(self, pats)
+    }
+
+    #[inline]
+    fn split_once_ascii(&self, byte: u8) -> Option<(&Self, &Self)> {
+        OsStrBytesExt::split_once_ascii(self, byte)
+    }
+
+    #[inline]
+    fn split_trailing_digits(&self) -> (&Self, Option<&str>) {
+        OsStrBytesExt::split_trailing_digits(self)
+    }
+
+    #[inline]
+    fn split_with<P>(&self, pat: P, options: SplitOptions) -> SplitWith<'_, P>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::split_with(self, pat, options)
+    }
+
+    #[inline]
+    fn starts_with<P>(&self, pat: P) -> bool
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::starts_with(self, pat)
+    }
+
+    #[inline]
+    fn starts_with_alphabetic(&self) -> bool {
+        OsStrBytesExt::starts_with_alphabetic(self)
+    }
+
+    #[inline]
+    fn starts_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Copy + Pattern,
+    {
+        OsStrBytesExt::starts_with_any(self, pats)
+    }
+
+    #[inline]
+    fn starts_with_ascii_digit(&self) -> bool {
+        OsStrBytesExt::starts_with_ascii_digit(self)
+    }
+
+    #[inline]
+    fn strip_bom(&self) -> &Self {
+        OsStrBytesExt::strip_bom(self)
+    }
+
+    #[inline]
+    fn strip_circumfix<P, S>(&self, prefix: P, suffix: S) -> Option<&Self>
+    where
+        P: Pattern,
+        S: Pattern,
+    {
+        OsStrBytesExt::strip_circumfix(self, prefix, suffix)
+    }
+
+    #[inline]
+    fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::strip_prefix(self, pat)
+    }
+
+    #[inline]
+    fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::strip_suffix(self, pat)
+    }
+
+    #[inline]
+    fn trim_end_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::trim_end_matches(self, pat)
+    }
+
+    #[inline]
+    fn trim_end_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::trim_end_matches_counted(self, pat)
+    }
+
+    #[inline]
+    fn trim_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::trim_matches(self, pat)
+    }
+
+    #[inline]
+    fn trim_matches_counted<P>(&self, pat: P) -> (&Self, usize, usize)
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::trim_matches_counted(self, pat)
+    }
+
+    #[inline]
+    fn trim_quotes(&self) -> &Self {
+        OsStrBytesExt::trim_quotes(self)
+    }
+
+    #[inline]
+    fn trim_start_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::trim_start_matches(self, pat)
+    }
+
+    #[inline]
+    fn trim_start_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::trim_start_matches_counted(self, pat)
+    }
+
+    #[inline]
+    fn truncate_to_limit(&self, max_units: usize, unit: LimitUnit) -> &Self {
+        OsStrBytesExt::truncate_to_limit(self, max_units, unit)
+    }
+
+    #[inline]
+    fn try_rsplit<P>(&self, pat: P) -> Result<RSplit<'_, P>, EmptyPatternError>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::try_rsplit(self, pat)
+    }
+
+    #[inline]
+    fn try_split<P>(&self, pat: P) -> Result<Split<'_, P>, EmptyPatternError>
+    where
+        P: Pattern,
+    {
+        OsStrBytesExt::try_split(self, pat)
+    }
+
+    #[inline]
+    fn units(&self) -> Units<'_> {
+        OsStrBytesExt::units(self)
+    }
+
+    #[inline]
+    fn utf8_chunks(&self) -> Utf8Chunks<'_> {
+        OsStrBytesExt::utf8_chunks(self)
+    }
+
+    #[inline]
+    fn validate_boundary(&self, index: usize) -> Result<(), BoundaryError> {
+        OsStrBytesExt::validate_boundary(self, index)
+    }
+}
@@ -0,0 +1,299 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::mem;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::Ordering as AtomicOrdering;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(feature = "small_string")]
+const INLINE_CAPACITY: usize = 22;
+
+#[cfg(feature = "small_string")]
+#[derive(Clone, Copy)]
+pub(super) struct Inline {
+    length: u8,
+    buffer: [u8; INLINE_CAPACITY],
+}
+
+#[cfg(feature = "small_string")]
+impl Inline {
+    fn new(string: &[u8]) -> Option<Self> {
+        let length = string.len();
+        (length <= INLINE_CAPACITY).then(|| {
+            let mut buffer = [0; INLINE_CAPACITY];
+            buffer[..length].copy_from_slice(string);
+            Self {
+                length: length as u8,
+                buffer,
+            }
+        })
+    }
+}
+
+// The storage used by [RawOsString]. Without the "small_string" feature, this
+// type is only a wrapper for [Vec].
+//
+// [RawOsString]: super::RawOsString
+pub(super) enum Buffer {
+    #[cfg(feature = "small_string")]
+    Inline(Inline),
+    Heap(Vec<u8>),
+}
+
+impl Buffer {
+    pub(super) fn from_slice(string: &[u8]) -> Self {
+        #[cfg(feature = "small_string")]
+        if let Some(string) = Inline::new(string) {
+            return Self::Inline(string);
+        }
+        Self::Heap(string.to_vec())
+    }
+
+    // Vectors are never moved inline, since they have already been
+    // allocated and may be converted back without copying.
+    pub(super) const fn from_vec(string: Vec<u8>) -> Self {
+        Self::Heap(string)
+    }
+
+    pub(super) fn with_capacity(capacity: usize) -> Self {
+        let mut string = Self::default();
+        string.reserve(capacity);
+        string
+    }
+
+    // The buffer is taken instead of moved, since this type implements
+    // [Drop] when the "zeroize" feature is enabled.
+    pub(super) fn into_vec(mut self) -> Vec<u8> {
+        match &mut self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(_) => self.to_vec(),
+            Self::Heap(string) => mem::take(string),
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub(super) fn extend_from_slice(&mut self, other: &[u8]) {
+        self.reserve(other.len());
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(string) => {
+                // Reserving moved the string to the heap if the result would
+                // not fit.
+                let length = usize::from(string.length);
+                let new_length = length + other.len();
+                string.buffer[length..new_length].copy_from_slice(other);
+                string.length = new_length as u8;
+            }
+            Self::Heap(string) => string.extend_from_slice(other),
+        }
+    }
+
+    pub(super) fn reserve(&mut self, additional: usize) {
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(string) => {
+                if additional > INLINE_CAPACITY - usize::from(string.length) {
+                    let mut string = self.to_vec();
+                    string.reserve(additional);
+                    *self = Self::Heap(string);
+                }
+            }
+            Self::Heap(string) => string.reserve(additional),
+        }
+    }
+
+    pub(super) fn shrink_to_fit(&mut self) {
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(_) => {}
+            Self::Heap(string) => string.shrink_to_fit(),
+        }
+    }
+
+    pub(super) fn split_off(&mut self, at: usize) -> Self {
+        let suffix = Self::from_slice(&self[at..]);
+        self.truncate(at);
+        suffix
+    }
+
+    pub(super) fn truncate(&mut self, len: usize) {
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(string) => {
+                if len < string.length.into() {
+                    string.length = len as u8;
+                }
+            }
+            Self::Heap(string) => string.truncate(len),
+        }
+    }
+}
+
+impl Clone for Buffer {
+    fn clone(&self) -> Self {
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(string) => Self::Inline(*string),
+            Self::Heap(string) => Self::from_slice(string),
+        }
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::from_slice(&[])
+    }
+}
+
+impl Deref for Buffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(string) => &string.buffer[..string.length.into()],
+            Self::Heap(string) => string,
+        }
+    }
+}
+
+impl DerefMut for Buffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(string) => &mut string.buffer[..string.length.into()],
+            Self::Heap(string) => string,
+        }
+    }
+}
+
+impl Eq for Buffer {}
+
+impl Hash for Buffer {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).hash(state);
+    }
+}
+
+impl Ord for Buffer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl PartialOrd for Buffer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for Buffer {
+    fn zeroize(&mut self) {
+        match self {
+            #[cfg(feature = "small_string")]
+            Self::Inline(string) => {
+                string.buffer.zeroize();
+                string.length = 0;
+            }
+            Self::Heap(string) => string.zeroize(),
+        }
+    }
+}
+
+const UNKNOWN: u8 = 0;
+const UNICODE: u8 = 1;
+const NOT_UNICODE: u8 = 2;
+
+// Records whether the content of [RawOsString] is valid UTF-8, once it has
+// been checked. The cache is not part of the value, so all instances compare
+// equal, and it must be reset whenever the content is modified.
+//
+// [RawOsString]: super::RawOsString
+pub(super) struct UnicodeCache(AtomicU8);
+
+impl UnicodeCache {
+    pub(super) const fn new() -> Self {
+        Self(AtomicU8::new(UNKNOWN))
+    }
+
+    pub(super) fn get_or_init<F>(&self, f: F) -> bool
+    where
+        F: FnOnce() -> bool,
+    {
+        match self.0.load(AtomicOrdering::Relaxed) {
+            UNICODE => true,
+            NOT_UNICODE => false,
+            _ => {
+                let unicode = f();
+                self.0.store(
+                    if unicode { UNICODE } else { NOT_UNICODE },
+                    AtomicOrdering::Relaxed,
+                );
+                unicode
+            }
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        *self.0.get_mut() = UNKNOWN;
+    }
+}
+
+impl Clone for UnicodeCache {
+    fn clone(&self) -> Self {
+        Self(AtomicU8::new(self.0.load(AtomicOrdering::Relaxed)))
+    }
+}
+
+impl Default for UnicodeCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Eq for UnicodeCache {}
+
+impl Ord for UnicodeCache {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl PartialEq for UnicodeCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for UnicodeCache {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
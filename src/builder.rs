@@ -0,0 +1,223 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::ops::Range;
+
+use super::util::MAX_UTF8_LENGTH;
+use super::OsStrBytesExt;
+
+/// A builder for platform strings that records the range of each appended
+/// segment.
+///
+/// Every recorded range is bounded by [valid boundaries] of the final string,
+/// so it can be passed to [`OsStrBytesExt::index`] without panicking.
+///
+/// Appending a segment can combine the end of the string with the start of
+/// the segment into a single character. For example, on Windows, a high
+/// surrogate followed by a low surrogate is a supplementary character. That
+/// character is then considered to be part of the later segment, and earlier
+/// segments are shortened to exclude it.
+///
+/// [`OsStrBytesExt::index`]: super::OsStrBytesExt::index
+/// [valid boundaries]: super::OsStrBytesExt#indices
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::OsStrBuilder;
+/// use os_str_bytes::OsStrBytesExt;
+///
+/// let mut builder = OsStrBuilder::with_capacity(16);
+/// builder.push("cargo");
+/// builder.push(" ");
+/// builder.push("build");
+///
+/// let (string, segments) = builder.finish();
+/// assert_eq!("cargo build", string);
+/// assert_eq!("build", string.index(segments[2].clone()));
+/// ```
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Debug, Default)]
+pub struct OsStrBuilder {
+    string: OsString,
+    segments: Vec<Range<usize>>,
+}
+
+impl OsStrBuilder {
+    /// Creates an empty builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let builder = OsStrBuilder::new();
+    /// assert!(builder.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty builder with space for at least the given number of
+    /// bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let builder = OsStrBuilder::with_capacity(16);
+    /// assert!(builder.as_os_str().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            string: OsString::with_capacity(capacity),
+            segments: Vec::new(),
+        }
+    }
+
+    /// Returns the string built so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let mut builder = OsStrBuilder::new();
+    /// builder.push("foo");
+    /// assert_eq!("foo", builder.as_os_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_os_str(&self) -> &OsStr {
+        &self.string
+    }
+
+    /// Returns the string built so far and the ranges of its segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let mut builder = OsStrBuilder::new();
+    /// builder.push("foo");
+    /// builder.push("bar");
+    /// assert_eq!([0..3, 3..6], *builder.finish().1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn finish(self) -> (OsString, Vec<Range<usize>>) {
+        (self.string, self.segments)
+    }
+
+    /// Returns `true` if no segments have been appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let mut builder = OsStrBuilder::new();
+    /// assert!(builder.is_empty());
+    /// builder.push("");
+    /// assert!(!builder.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns the number of segments that have been appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let mut builder = OsStrBuilder::new();
+    /// builder.push("foo");
+    /// builder.push("bar");
+    /// assert_eq!(2, builder.len());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Appends a segment to the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let mut builder = OsStrBuilder::new();
+    /// builder.push("foo");
+    /// assert_eq!([0..3], builder.segments());
+    /// ```
+    pub fn push<S>(&mut self, string: &S)
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let start = self.string.len();
+        self.string.push(string.as_ref());
+        self.segments.push(start..self.string.len());
+
+        // Only boundaries within the last character of the previous string
+        // can be affected.
+        let limit = start.saturating_sub(MAX_UTF8_LENGTH - 1);
+        for index in (0..self.segments.len()).rev() {
+            let segment = &self.segments[index];
+            if segment.end < limit {
+                break;
+            }
+            let segment = self.string.floor_char_boundary(segment.start)
+                ..self.string.floor_char_boundary(segment.end);
+            self.segments[index] = segment;
+        }
+    }
+
+    /// Reserves space for at least the given number of additional bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let mut builder = OsStrBuilder::new();
+    /// builder.reserve(16);
+    /// builder.push("foo");
+    /// assert_eq!("foo", builder.as_os_str());
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.string.reserve(additional);
+    }
+
+    /// Returns the ranges of the segments appended so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::OsStrBuilder;
+    ///
+    /// let mut builder = OsStrBuilder::new();
+    /// builder.push("foo");
+    /// builder.push("");
+    /// builder.push("bar");
+    /// assert_eq!([0..3, 3..3, 3..6], builder.segments());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn segments(&self) -> &[Range<usize>] {
+        &self.segments
+    }
+}
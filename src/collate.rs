@@ -0,0 +1,68 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::cmp::Ordering;
+use std::ffi::OsStr;
+use std::str;
+
+use icu_collator::Collator;
+
+fn split_unicode(string: &OsStr) -> (&str, &[u8]) {
+    let string = string.as_encoded_bytes();
+    let (prefix, suffix) = match str::from_utf8(string) {
+        Ok(string) => return (string, &[]),
+        Err(error) => string.split_at(error.valid_up_to()),
+    };
+    let prefix = internal_expect!(
+        str::from_utf8(prefix).ok(),
+        return ("", string),
+        "prefix should be valid UTF-8",
+    );
+    (prefix, suffix)
+}
+
+/// Compares two platform strings using the rules of a locale.
+///
+/// Each string is compared as its longest prefix that is valid Unicode,
+/// followed by the remainder of the string. Prefixes are compared using the
+/// collator, and remainders are only compared if the prefixes are equal.
+/// Remainders are compared by their bytes in the [unspecified encoding] of
+/// [`OsStr`], so strings containing invalid sequences will still have a
+/// consistent order. Therefore, sorting file names with this function will
+/// place them similarly to how file managers do, even if some are not
+/// representable as [`prim@str`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use std::ffi::OsStr;
+///
+/// use icu_collator::Collator;
+/// use icu_collator::CollatorOptions;
+///
+/// let collator =
+///     Collator::try_new(&Default::default(), CollatorOptions::new())
+///         .unwrap();
+///
+/// let apple = OsStr::new("apple");
+/// let banana = OsStr::new("Banana");
+/// assert!(banana < apple);
+/// let ordering = os_str_bytes::collate(apple, banana, &collator);
+/// assert_eq!(Ordering::Less, ordering);
+/// ```
+///
+/// [unspecified encoding]: super#encoding-conversions
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "icu")))]
+#[inline]
+#[must_use]
+pub fn collate(
+    string: &OsStr,
+    other: &OsStr,
+    collator: &Collator,
+) -> Ordering {
+    let (prefix, suffix) = split_unicode(string);
+    let (other_prefix, other_suffix) = split_unicode(other);
+    collator
+        .compare(prefix, other_prefix)
+        .then_with(|| suffix.cmp(other_suffix))
+}
@@ -0,0 +1,439 @@
+//! Collections keyed by platform strings.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::ffi::OsStr;
+//!
+//! use os_str_bytes::collections::OsStrTrieMap;
+//!
+//! let mut routes = OsStrTrieMap::new();
+//! let _ = routes.insert("/usr", 1);
+//! let _ = routes.insert("/usr/lib", 2);
+//!
+//! assert_eq!(
+//!     Some((OsStr::new("/usr/lib"), &2)),
+//!     routes.longest_prefix_of(OsStr::new("/usr/lib/foo.so")),
+//! );
+//! assert_eq!(None, routes.longest_prefix_of(OsStr::new("/var")));
+//! ```
+
+#![cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "collections")))]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::DebugMap;
+use std::fmt::Formatter;
+use std::mem;
+use std::ops::Range;
+
+use super::ext;
+use super::RawOsStr;
+
+// Returns the lengths of the substrings between consecutive valid
+// boundaries.
+//
+// When a key is a prefix of another key and ends at a valid boundary of that
+// key, the boundaries before that index are the same for both strings.
+// Therefore, nodes only need to be created at these indices.
+fn units(string: &OsStr) -> impl '_ + Iterator<Item = usize> {
+    let length = string.len();
+    let mut start = 0;
+    (1..=length).filter_map(move |index| {
+        if index != length && !ext::is_boundary(string, index) {
+            return None;
+        }
+        Some(index - mem::replace(&mut start, index))
+    })
+}
+
+/// Returns the range of strings in a sorted slice that start with a prefix.
+///
+/// The slice must be sorted by the [`Ord`] implementation for [`OsStr`], such
+/// as by calling [`slice::sort`] on a slice of [`OsString`]. Since strings
+/// starting with the same prefix are adjacent in that order, two binary
+/// searches are sufficient to find them.
+///
+/// Other orders will produce unspecified results. In particular:
+/// - On Windows, that implementation does not compare by UTF-16 code units,
+///   so lists returned in that order by the operating system must be sorted
+///   again.
+/// - [`Path`] values are compared by component, so slices of [`PathBuf`]
+///   sorted using [`Ord`] may not be sorted as required.
+///
+/// Strings are included if their [encoded bytes] start with those of the
+/// prefix, since that is the representation compared by [`Ord`]. Thus, on
+/// Unix, the prefix may end partway through a character of an included
+/// string. On Windows, an unpaired surrogate at the end of the prefix will not
+/// match the first half of a surrogate pair.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+/// use std::ffi::OsString;
+///
+/// use os_str_bytes::collections;
+///
+/// let mut names: Vec<_> =
+///     ["foobar", "baz", "foo", "fop"].map(OsString::from).into();
+/// names.sort();
+///
+/// let range = collections::prefix_range(&names, OsStr::new("foo"));
+/// assert_eq!(["foo", "foobar"], names[range]);
+/// assert!(collections::prefix_range(&names, OsStr::new("qux")).is_empty());
+/// ```
+///
+/// [encoded bytes]: OsStr::as_encoded_bytes
+/// [`OsString`]: std::ffi::OsString
+/// [`Path`]: std::path::Path
+/// [`PathBuf`]: std::path::PathBuf
+#[must_use]
+pub fn prefix_range<S>(sorted: &[S], prefix: &OsStr) -> Range<usize>
+where
+    S: AsRef<OsStr>,
+{
+    let prefix = prefix.as_encoded_bytes();
+    let start =
+        sorted.partition_point(|x| x.as_ref().as_encoded_bytes() < prefix);
+    let end = start
+        + sorted[start..].partition_point(|x| {
+            x.as_ref().as_encoded_bytes().starts_with(prefix)
+        });
+    start..end
+}
+
+struct Node<V> {
+    value: Option<V>,
+    children: BTreeMap<Box<[u8]>, Self>,
+}
+
+impl<V> Node<V> {
+    const fn new() -> Self {
+        Self {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.is_none() && self.children.is_empty()
+    }
+
+    fn remove(
+        &mut self,
+        string: &[u8],
+        mut units: impl Iterator<Item = usize>,
+    ) -> Option<V> {
+        let Some(length) = units.next() else {
+            return self.value.take();
+        };
+        let (unit, string) = string.split_at(length);
+        let child = self.children.get_mut(unit)?;
+        let value = child.remove(string, units);
+        if child.is_empty() {
+            let _ = self.children.remove(unit);
+        }
+        value
+    }
+}
+
+impl<V> Node<V>
+where
+    V: Debug,
+{
+    fn fmt_entries(&self, key: &mut Vec<u8>, f: &mut DebugMap<'_, '_>) {
+        if let Some(value) = &self.value {
+            // SAFETY: Keys are only split at valid boundaries.
+            let key = unchecked!(RawOsStr::from_encoded_bytes_unchecked(key));
+            let _ = f.entry(&key, value);
+        }
+        for (unit, child) in &self.children {
+            let length = key.len();
+            key.extend_from_slice(unit);
+            child.fmt_entries(key, f);
+            key.truncate(length);
+        }
+    }
+}
+
+/// A map keyed by platform strings, supporting lookup of the longest key
+/// that prefixes a string.
+///
+/// Keys are stored using their encoded bytes, but they are only divided at
+/// [valid boundaries]. Thus, a key is only considered to be a prefix of
+/// another string if [`OsStrBytesExt::strip_prefix`] would also match it,
+/// which prevents matching part of a character.
+///
+/// [valid boundaries]: super::OsStrBytesExt#indices
+/// [`OsStrBytesExt::strip_prefix`]: super::OsStrBytesExt::strip_prefix
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::collections::OsStrTrieMap;
+///
+/// let mut map = OsStrTrieMap::new();
+/// let _ = map.insert("foo", 1);
+/// assert_eq!(Some(&1), map.get("foo"));
+/// assert_eq!(
+///     Some((OsStr::new("foo"), &1)),
+///     map.longest_prefix_of(OsStr::new("foobar")),
+/// );
+/// ```
+pub struct OsStrTrieMap<V> {
+    root: Node<V>,
+    len: usize,
+}
+
+impl<V> OsStrTrieMap<V> {
+    /// Creates an empty map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let map = OsStrTrieMap::<()>::new();
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            root: Node::new(),
+            len: 0,
+        }
+    }
+
+    fn node<K>(&self, key: &K) -> Option<&Node<V>>
+    where
+        K: AsRef<OsStr> + ?Sized,
+    {
+        let key = key.as_ref();
+        let mut string = key.as_encoded_bytes();
+        let mut node = &self.root;
+        for length in units(key) {
+            let unit;
+            (unit, string) = string.split_at(length);
+            node = node.children.get(unit)?;
+        }
+        Some(node)
+    }
+
+    /// Returns `true` if the map contains a value for a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let mut map = OsStrTrieMap::new();
+    /// let _ = map.insert("foo", 1);
+    /// assert!(map.contains_key("foo"));
+    /// assert!(!map.contains_key("fo"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_key<K>(&self, key: &K) -> bool
+    where
+        K: AsRef<OsStr> + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns the value for a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let mut map = OsStrTrieMap::new();
+    /// let _ = map.insert("foo", 1);
+    /// assert_eq!(Some(&1), map.get("foo"));
+    /// assert_eq!(None, map.get("bar"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get<K>(&self, key: &K) -> Option<&V>
+    where
+        K: AsRef<OsStr> + ?Sized,
+    {
+        self.node(key)?.value.as_ref()
+    }
+
+    /// Inserts a value for a key, returning the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let mut map = OsStrTrieMap::new();
+    /// assert_eq!(None, map.insert("foo", 1));
+    /// assert_eq!(Some(1), map.insert("foo", 2));
+    /// assert_eq!(Some(&2), map.get("foo"));
+    /// ```
+    pub fn insert<K>(&mut self, key: &K, value: V) -> Option<V>
+    where
+        K: AsRef<OsStr> + ?Sized,
+    {
+        let key = key.as_ref();
+        let mut string = key.as_encoded_bytes();
+        let mut node = &mut self.root;
+        for length in units(key) {
+            let unit;
+            (unit, string) = string.split_at(length);
+            node = node.children.entry(unit.into()).or_insert_with(Node::new);
+        }
+
+        let value = node.value.replace(value);
+        if value.is_none() {
+            self.len += 1;
+        }
+        value
+    }
+
+    /// Returns `true` if the map contains no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let mut map = OsStrTrieMap::new();
+    /// assert!(map.is_empty());
+    /// let _ = map.insert("foo", 1);
+    /// assert!(!map.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of values in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let mut map = OsStrTrieMap::new();
+    /// let _ = map.insert("foo", 1);
+    /// let _ = map.insert("foobar", 2);
+    /// assert_eq!(2, map.len());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the longest key that is a prefix of a string, with its value.
+    ///
+    /// The returned key is a substring of the argument. Keys are only
+    /// matched if they end at a [valid boundary] of the string.
+    ///
+    /// [valid boundary]: super::OsStrBytesExt#indices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let mut map = OsStrTrieMap::new();
+    /// let _ = map.insert("", 0);
+    /// let _ = map.insert("foo", 1);
+    /// let _ = map.insert("foobar", 2);
+    ///
+    /// assert_eq!(
+    ///     Some((OsStr::new("foo"), &1)),
+    ///     map.longest_prefix_of(OsStr::new("foobaz")),
+    /// );
+    /// assert_eq!(
+    ///     Some((OsStr::new(""), &0)),
+    ///     map.longest_prefix_of(OsStr::new("baz")),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn longest_prefix_of<'a>(
+        &self,
+        string: &'a OsStr,
+    ) -> Option<(&'a OsStr, &V)> {
+        let mut bytes = string.as_encoded_bytes();
+        let mut node = &self.root;
+        let mut index = 0;
+        let mut result = node.value.as_ref().map(|x| (index, x));
+        for length in units(string) {
+            let unit;
+            (unit, bytes) = bytes.split_at(length);
+            let Some(child) = node.children.get(unit) else {
+                break;
+            };
+            node = child;
+            index += length;
+            if let Some(value) = &node.value {
+                result = Some((index, value));
+            }
+        }
+
+        result.map(|(index, value)| {
+            // SAFETY: The index is a valid boundary of the string.
+            let prefix =
+                unchecked!(ext::os_str(&string.as_encoded_bytes()[..index]));
+            (prefix, value)
+        })
+    }
+
+    /// Removes the value for a key, returning it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::collections::OsStrTrieMap;
+    ///
+    /// let mut map = OsStrTrieMap::new();
+    /// let _ = map.insert("foo", 1);
+    /// assert_eq!(Some(1), map.remove("foo"));
+    /// assert_eq!(None, map.remove("foo"));
+    /// ```
+    pub fn remove<K>(&mut self, key: &K) -> Option<V>
+    where
+        K: AsRef<OsStr> + ?Sized,
+    {
+        let key = key.as_ref();
+        let value = self.root.remove(key.as_encoded_bytes(), units(key));
+        if value.is_some() {
+            self.len -= 1;
+        }
+        value
+    }
+}
+
+impl<V> Debug for OsStrTrieMap<V>
+where
+    V: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_map();
+        self.root.fmt_entries(&mut Vec::new(), &mut f);
+        f.finish()
+    }
+}
+
+impl<V> Default for OsStrTrieMap<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
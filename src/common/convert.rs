@@ -11,6 +11,16 @@ pub(crate) type EncodingError = Infallible;
 
 pub(crate) type Result<T> = result::Result<T, EncodingError>;
 
+if_checked_conversions! {
+    pub(crate) fn complete_length(string: &[u8]) -> Result<usize> {
+        Ok(string.len())
+    }
+
+    pub(crate) fn concat(string: &[u8], other: &[u8]) -> Result<Vec<u8>> {
+        Ok([string, other].concat())
+    }
+}
+
 pub(crate) fn os_str_from_bytes(string: &[u8]) -> Result<Cow<'_, OsStr>> {
     Ok(Cow::Borrowed(OsStr::from_bytes(string)))
 }
@@ -26,3 +36,7 @@ pub(crate) fn os_string_from_vec(string: Vec<u8>) -> Result<OsString> {
 pub(crate) fn os_string_into_vec(string: OsString) -> Vec<u8> {
     string.into_vec()
 }
+
+pub(crate) fn valid_length(string: &[u8]) -> usize {
+    string.len()
+}
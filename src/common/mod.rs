@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
 #[cfg(all(target_vendor = "fortanix", target_env = "sgx"))]
 use std::os::fortanix_sgx as os;
 #[cfg(target_os = "hermit")]
@@ -15,8 +17,12 @@ pub(super) mod convert_io;
 
 if_conversions! {
     pub(super) mod convert;
+}
+
+if_raw_str! {
+    pub(super) mod raw;
 
-    if_raw_str! {
-        pub(super) mod raw;
-    }
+    pub(crate) use os::ffi::OsStrExt;
+    #[cfg(feature = "forbid_unsafe")]
+    pub(crate) use os::ffi::OsStringExt;
 }
@@ -1,7 +1,89 @@
-pub(crate) fn ends_with(string: &[u8], suffix: &[u8]) -> bool {
-    string.ends_with(suffix)
+use std::ffi::OsStr;
+#[cfg(feature = "forbid_unsafe")]
+use std::ffi::OsString;
+use std::ops::Range;
+#[cfg(all(
+    feature = "forbid_unsafe",
+    target_family = "wasm",
+    target_os = "unknown",
+))]
+use std::str;
+
+use crate::ext;
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use super::OsStrExt;
+#[cfg(all(
+    feature = "forbid_unsafe",
+    not(all(target_family = "wasm", target_os = "unknown")),
+))]
+use super::OsStringExt;
+
+// Returns the bytes of a string, as guaranteed by the standard library where
+// possible.
+pub(crate) fn canonical_bytes(
+    string: &OsStr,
+) -> impl '_ + Iterator<Item = u8> {
+    // No other access to the bytes is provided on this platform.
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    let string = string.as_encoded_bytes();
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    let string = string.as_bytes();
+    string.iter().copied()
+}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(crate) fn is_valid(string: &[u8]) -> bool {
+    os_str(string).is_some()
+}
+
+// Returns the string with the given internal encoding, or [None] if the bytes
+// do not use it.
+#[cfg(feature = "forbid_unsafe")]
+pub(crate) fn os_str(string: &[u8]) -> Option<&OsStr> {
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    {
+        str::from_utf8(string).ok().map(OsStr::new)
+    }
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    {
+        Some(OsStr::from_bytes(string))
+    }
 }
 
-pub(crate) fn starts_with(string: &[u8], prefix: &[u8]) -> bool {
-    string.starts_with(prefix)
+#[cfg(feature = "forbid_unsafe")]
+pub(crate) fn os_string(string: Vec<u8>) -> Option<OsString> {
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    {
+        String::from_utf8(string).ok().map(OsString::from)
+    }
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    {
+        Some(OsString::from_vec(string))
+    }
+}
+
+pub(crate) fn contains(string: &[u8], pat: &[u8]) -> bool {
+    ext::find(string, pat).is_some()
+}
+
+pub(crate) fn find_os(
+    string: &[u8],
+    pat: &[u8],
+    start: usize,
+) -> Option<Range<usize>> {
+    ext::find(&string[start..], pat).map(|x| {
+        let x = start + x;
+        x..x + pat.len()
+    })
+}
+
+if_conversions! {
+    pub(crate) fn ends_with(string: &[u8], suffix: &[u8]) -> bool {
+        string.ends_with(suffix)
+    }
+
+    pub(crate) fn starts_with(string: &[u8], prefix: &[u8]) -> bool {
+        string.starts_with(prefix)
+    }
 }
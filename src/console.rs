@@ -0,0 +1,127 @@
+//! Lossless output to terminals.
+//!
+//! Consoles on Windows expect UTF-16, so writing bytes to them requires
+//! replacing unpaired surrogates. The [`write_lossless`] function avoids that
+//! conversion by writing directly to the console when one is detected.
+//! Otherwise, it writes the same bytes as [`OsStrBytes::to_io_bytes_lossy`].
+//!
+//! For more complete support, including formatting, use crate [print\_bytes].
+//!
+//! [print\_bytes]: https://crates.io/crates/print_bytes
+
+#![cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "console")))]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::io::Stderr;
+use std::io::StderrLock;
+use std::io::Stdout;
+use std::io::StdoutLock;
+use std::io::Write;
+
+#[cfg(all(windows, not(feature = "forbid_unsafe")))]
+use std::os::windows::ffi::OsStrExt;
+#[cfg(windows)]
+use std::os::windows::io::AsRawHandle;
+#[cfg(windows)]
+use std::os::windows::io::RawHandle;
+
+#[cfg(all(windows, not(feature = "forbid_unsafe")))]
+use super::imp::console;
+use super::private;
+use super::OsStrBytes;
+
+/// A writer that [`write_lossless`] can detect consoles for.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait WriteLossless: private::Sealed + Write {
+    #[cfg(windows)]
+    #[doc(hidden)]
+    fn __as_raw_handle(&self) -> Option<RawHandle>;
+}
+
+macro_rules! r#impl {
+    ( $($type:ty),+ ) => {
+    $(
+        impl WriteLossless for $type {
+            #[cfg(windows)]
+            fn __as_raw_handle(&self) -> Option<RawHandle> {
+                Some(self.as_raw_handle())
+            }
+        }
+    )+
+    };
+}
+r#impl!(File, Stderr, StderrLock<'_>, Stdout, StdoutLock<'_>);
+
+impl<W> WriteLossless for BufWriter<W>
+where
+    W: WriteLossless,
+{
+    #[cfg(windows)]
+    fn __as_raw_handle(&self) -> Option<RawHandle> {
+        self.get_ref().__as_raw_handle()
+    }
+}
+
+impl WriteLossless for Vec<u8> {
+    #[cfg(windows)]
+    fn __as_raw_handle(&self) -> Option<RawHandle> {
+        None
+    }
+}
+
+impl<W> WriteLossless for &mut W
+where
+    W: ?Sized + WriteLossless,
+{
+    #[cfg(windows)]
+    fn __as_raw_handle(&self) -> Option<RawHandle> {
+        (**self).__as_raw_handle()
+    }
+}
+
+/// Writes a platform string to a stream without losing information when
+/// possible.
+///
+/// On Windows, if the writer refers to a console, the string is flushed and
+/// written as UTF-16, which preserves unpaired surrogates. In all other cases,
+/// the result of [`OsStrBytes::to_io_bytes_lossy`] is written, which is
+/// lossless on Unix. Consoles are never detected when the "forbid\_unsafe"
+/// feature is enabled.
+///
+/// # Errors
+///
+/// Returns an error if writing or flushing fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::env;
+/// use std::io;
+///
+/// use os_str_bytes::console;
+///
+/// for arg in env::args_os() {
+///     console::write_lossless(&arg, io::stdout())?;
+/// }
+/// #
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_lossless<W>(string: &OsStr, mut writer: W) -> io::Result<()>
+where
+    W: WriteLossless,
+{
+    #[cfg(all(windows, not(feature = "forbid_unsafe")))]
+    if let Some(handle) = writer.__as_raw_handle() {
+        if console::is_console(handle) {
+            writer.flush()?;
+            let string: Vec<_> = string.encode_wide().collect();
+            return console::write_wide(handle, &string);
+        }
+    }
+    writer.write_all(&string.to_io_bytes_lossy())
+}
@@ -0,0 +1,120 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::ffi::OsString;
+use std::mem;
+
+use super::convert;
+use super::EncodingError;
+use super::Result;
+
+/// A decoder for byte strings in the [unspecified encoding] that are received
+/// in chunks.
+///
+/// Sequences can be split between chunks arbitrarily. Bytes that cannot be
+/// decoded yet are stored until the next chunk is received, so strings can be
+/// read from a stream without buffering them completely. Concatenating the
+/// strings returned by this decoder gives the same result as decoding all
+/// chunks at once.
+///
+/// [unspecified encoding]: super#encoding-conversions
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::RawDecoder;
+///
+/// let mut decoder = RawDecoder::new();
+/// let mut os_string = decoder.feed(b"foo\xF0\x9F")?;
+/// os_string.push(decoder.feed(b"\x92\xA9bar")?);
+/// os_string.push(decoder.finish()?);
+/// assert_eq!("foo\u{1F4A9}bar", os_string);
+/// #
+/// # Ok::<_, os_str_bytes::EncodingError>(())
+/// ```
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "checked_conversions")))]
+#[derive(Clone, Debug, Default)]
+pub struct RawDecoder {
+    buffer: Vec<u8>,
+}
+
+impl RawDecoder {
+    /// Creates a decoder with no stored bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawDecoder;
+    ///
+    /// let decoder = RawDecoder::new();
+    /// assert_eq!(Ok(Default::default()), decoder.finish());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decodes the next chunk of a byte string.
+    ///
+    /// The returned string contains every character that could be decoded so
+    /// far. It may be empty, if the chunk ends with an incomplete sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk cannot be part of a valid byte string.
+    /// The state of this decoder is unchanged in that case. For more
+    /// information, see [`EncodingError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawDecoder;
+    ///
+    /// let mut decoder = RawDecoder::new();
+    /// assert_eq!("foo", decoder.feed(b"foo")?);
+    /// assert_eq!("bar", decoder.feed(b"bar")?);
+    /// #
+    /// # Ok::<_, os_str_bytes::EncodingError>(())
+    /// ```
+    pub fn feed(&mut self, string: &[u8]) -> Result<OsString> {
+        let stored_length = self.buffer.len();
+        self.buffer.extend_from_slice(string);
+
+        let length = match convert::complete_length(&self.buffer) {
+            Ok(length) => length,
+            Err(error) => {
+                self.buffer.truncate(stored_length);
+                return Err(EncodingError(error));
+            }
+        };
+        let remainder = self.buffer.split_off(length);
+        let string = mem::replace(&mut self.buffer, remainder);
+        Ok(expect_encoded!(convert::os_string_from_vec(string)))
+    }
+
+    /// Decodes the bytes stored by this decoder, which must end the byte
+    /// string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored bytes are an incomplete sequence. For
+    /// more information, see [`EncodingError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawDecoder;
+    ///
+    /// let mut decoder = RawDecoder::new();
+    /// let _ = decoder.feed(b"foo\xF0\x9F")?;
+    /// if cfg!(windows) {
+    ///     assert!(decoder.finish().is_err());
+    /// }
+    /// #
+    /// # Ok::<_, os_str_bytes::EncodingError>(())
+    /// ```
+    #[inline]
+    pub fn finish(self) -> Result<OsString> {
+        convert::os_string_from_vec(self.buffer).map_err(EncodingError)
+    }
+}
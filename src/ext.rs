@@ -1,5 +1,15 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::array;
+use std::borrow::Cow;
+use std::error::Error;
 use std::ffi::OsStr;
 use std::ffi::OsString;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Write;
+use std::hint;
 use std::iter;
 use std::mem;
 use std::ops::Range;
@@ -8,24 +18,55 @@ use std::ops::RangeFull;
 use std::ops::RangeInclusive;
 use std::ops::RangeTo;
 use std::ops::RangeToInclusive;
+use std::path;
 use std::str;
 
+use super::iter::ChunkBySize;
+use super::iter::CodePoints;
+use super::iter::ComponentsNaive;
+use super::iter::IntoUtf8Chunks;
+use super::iter::MatchIndicesOs;
 use super::iter::RSplit;
 use super::iter::Split;
+use super::iter::SplitIndices;
+use super::iter::SplitKeep;
+use super::iter::SplitWith;
+use super::iter::Units;
 use super::iter::Utf8Chunks;
 use super::pattern::Encoded as EncodedPattern;
+use super::private;
+use super::unchecked;
+pub(super) use super::unchecked::os_str;
 use super::util;
+use super::util::BYTE_SHIFT;
+use super::util::CONT_MASK;
+use super::util::CONT_TAG;
 use super::util::MAX_UTF8_LENGTH;
+use super::IoEncodingError;
 use super::OsStrBytes;
+use super::OsStringBytes;
 use super::Pattern;
 
+use super::imp::raw;
+
 if_conversions! {
-    use super::imp::raw;
+    use std::cmp::Ordering;
+}
+
+if_windows_ext! {
+    use super::imp::SURROGATE_LENGTH;
+    use super::iter::SurrogatePositions;
 }
 
-fn is_boundary(string: &OsStr, index: usize) -> bool {
+if_unix_ext! {
+    use super::imp::OsStrExt;
+    use super::iter::SplitBytes;
+    use super::pattern::BytePattern;
+}
+
+pub(super) fn is_boundary(string: &OsStr, index: usize) -> bool {
     let string = string.as_encoded_bytes();
-    debug_assert!(index < string.len());
+    internal_assert!(index < string.len());
 
     if index == 0 {
         return true;
@@ -50,35 +91,259 @@ fn is_boundary(string: &OsStr, index: usize) -> bool {
         .is_some_and(|x| str::from_utf8(&string[x..index]).is_ok())
 }
 
+// Returns the first character or the length of the invalid sequence that
+// precedes it. Consecutive invalid sequences are grouped like they are by
+// [Utf8Chunks].
+fn first_unit(string: &[u8]) -> Option<Result<char, usize>> {
+    if string.is_empty() {
+        return None;
+    }
+
+    let mut invalid_length = 0;
+    while invalid_length < string.len() {
+        let substring = &string[invalid_length..];
+        let substring = &substring[..substring.len().min(MAX_UTF8_LENGTH)];
+        let error = match str::from_utf8(substring) {
+            Ok(_) => break,
+            Err(error) if error.valid_up_to() != 0 => break,
+            Err(error) => error,
+        };
+        invalid_length += error.error_len().unwrap_or(substring.len());
+    }
+    if invalid_length != 0 {
+        return Some(Err(invalid_length));
+    }
+
+    let substring = &string[..string.len().min(MAX_UTF8_LENGTH)];
+    let valid_length = str::from_utf8(substring)
+        .map_or_else(|x| x.valid_up_to(), |x| x.len());
+    // SAFETY: This slice was validated to be UTF-8.
+    let substring = unchecked!(unchecked::utf8(&substring[..valid_length]));
+    substring.chars().next().map(Ok)
+}
+
+fn first_char(string: &[u8]) -> Option<char> {
+    let substring = &string[..string.len().min(MAX_UTF8_LENGTH)];
+    let valid_length = str::from_utf8(substring)
+        .map_or_else(|x| x.valid_up_to(), |x| x.len());
+    // SAFETY: This slice was validated to be UTF-8.
+    let substring = unchecked!(unchecked::utf8(&substring[..valid_length]));
+    substring.chars().next()
+}
+
+fn last_char(string: &[u8]) -> Option<char> {
+    let index = (0..string.len())
+        .rev()
+        .take(MAX_UTF8_LENGTH)
+        .find(|&x| !util::is_continuation(string[x]))?;
+    let mut chars = str::from_utf8(&string[index..]).ok()?.chars();
+    chars.next_back().filter(|_| chars.next().is_none())
+}
+
+fn last_unit(string: &[u8]) -> Option<Result<char, usize>> {
+    if let Some(substring) = (0..string.len())
+        .rev()
+        .take(MAX_UTF8_LENGTH)
+        .find(|&x| !util::is_continuation(string[x]))
+        .and_then(|x| str::from_utf8(&string[x..]).ok())
+    {
+        let mut chars = substring.chars();
+        if let (Some(ch), None) = (chars.next_back(), chars.next()) {
+            return Some(Ok(ch));
+        }
+    }
+
+    // ASCII bytes are never part of invalid sequences, so decoding can begin
+    // after the last one.
+    let mut index = string
+        .iter()
+        .rposition(u8::is_ascii)
+        .map(|x| x + 1)
+        .unwrap_or(0);
+    loop {
+        let unit = first_unit(&string[index..])?;
+        let length = unit.map_or_else(|x| x, char::len_utf8);
+        if index + length == string.len() {
+            return Some(unit);
+        }
+        index += length;
+    }
+}
+
+// Returns the range of the code point containing an index that is not a
+// valid boundary, if it can be determined.
+fn code_point_range(string: &[u8], index: usize) -> Option<Range<usize>> {
+    let start = (index.saturating_sub(MAX_UTF8_LENGTH - 1)..index)
+        .rev()
+        .find(|&x| !util::is_continuation(string[x]))?;
+    let length = match first_unit(&string[start..])? {
+        Ok(ch) => ch.len_utf8(),
+        // Only surrogate code points can be invalid in WTF-8.
+        Err(_) if cfg!(any(target_os = "uefi", windows)) => 3,
+        Err(_) => return None,
+    };
+    let end = start + length;
+    (end > index).then_some(start..end)
+}
+
+// Out-of-bounds indices are accepted, since slicing will reject them.
+fn is_bound(string: &OsStr, index: usize) -> bool {
+    index >= string.as_encoded_bytes().len() || is_boundary(string, index)
+}
+
 #[track_caller]
 pub(super) fn check_bound(string: &OsStr, index: usize) {
     assert!(
-        index >= string.as_encoded_bytes().len() || is_boundary(string, index),
-        "byte index {} is not a valid boundary",
-        index,
+        is_bound(string, index),
+        "{}",
+        BoundaryError::new(string, index),
     );
 }
 
-macro_rules! r#impl {
-    ( $($name:ident),+ ) => {
-    $(
-        #[cfg(feature = "memchr")]
-        use memchr::memmem::$name;
+#[cfg(feature = "memchr")]
+use memchr::memmem;
 
-        #[cfg(not(feature = "memchr"))]
-        fn $name(string: &[u8], pat: &[u8]) -> Option<usize> {
-            (pat.len()..=string.len())
-                .$name(|&x| string[..x].ends_with(pat))
-                .map(|x| x - pat.len())
+#[cfg(not(feature = "memchr"))]
+use super::two_way as memmem;
+
+// Arguments are often shorter than the patterns searched for in them, so
+// those searches return early. Single bytes are also common patterns, and
+// they can be found without preprocessing.
+pub(super) fn find(string: &[u8], pat: &[u8]) -> Option<usize> {
+    match *pat {
+        _ if pat.len() > string.len() => None,
+        [byte] => {
+            #[cfg(feature = "memchr")]
+            {
+                memchr::memchr(byte, string)
+            }
+            #[cfg(not(feature = "memchr"))]
+            {
+                string.iter().position(|&x| x == byte)
+            }
         }
-    )+
-    };
+        _ => memmem::find(string, pat),
+    }
+}
+
+pub(super) fn rfind(string: &[u8], pat: &[u8]) -> Option<usize> {
+    match *pat {
+        _ if pat.len() > string.len() => None,
+        [byte] => {
+            #[cfg(feature = "memchr")]
+            {
+                memchr::memrchr(byte, string)
+            }
+            #[cfg(not(feature = "memchr"))]
+            {
+                string.iter().rposition(|&x| x == byte)
+            }
+        }
+        _ => memmem::rfind(string, pat),
+    }
+}
+
+#[track_caller]
+fn check_ascii_set(bytes: &[u8]) {
+    if let Some(&byte) = bytes.iter().find(|x| !x.is_ascii()) {
+        panic!("byte is not ASCII: {:#04x}", byte);
+    }
+}
+
+// The number of patterns compared together by [matches_any_end], which is
+// limited by the size of the mask tracking them.
+const ANY_GROUP_SIZE: usize = u64::BITS as usize;
+
+// Returns `true` if any pattern matches an end of a string. For each group of
+// patterns, the bytes at that end are read once, while a mask records the
+// patterns that have matched so far. The index function converts an offset
+// from the end to an index into a slice with the given length.
+fn matches_any_end<P>(
+    string: &[u8],
+    pats: &[P],
+    index: fn(usize, usize) -> usize,
+) -> bool
+where
+    P: Clone + Pattern,
+{
+    pats.chunks(ANY_GROUP_SIZE).any(|group| {
+        let encoded: [_; ANY_GROUP_SIZE] =
+            array::from_fn(|i| group.get(i).map(|x| x.clone().__encode()));
+        let pats: [_; ANY_GROUP_SIZE] = array::from_fn(|i| {
+            encoded[i]
+                .as_ref()
+                .map_or(&[][..], EncodedPattern::__as_bytes)
+        });
+
+        let mut candidates = (0..group.len())
+            .filter(|&i| pats[i].len() <= string.len())
+            .fold(0_u64, |candidates, i| candidates | (1 << i));
+        let matching = |candidates: u64| {
+            (0..ANY_GROUP_SIZE).filter(move |&i| candidates & (1 << i) != 0)
+        };
+
+        let mut offset = 0;
+        while candidates != 0 {
+            if matching(candidates).any(|i| pats[i].len() == offset) {
+                return true;
+            }
+
+            // All remaining patterns are longer than the offset, so it must
+            // also be in bounds for the string.
+            let byte = string[index(string.len(), offset)];
+            for i in matching(candidates) {
+                let pat = pats[i];
+                if pat[index(pat.len(), offset)] != byte {
+                    candidates &= !(1 << i);
+                }
+            }
+            offset += 1;
+        }
+        false
+    })
+}
+
+fn parse_hex(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
 }
-r#impl!(find, rfind);
 
-pub(super) unsafe fn os_str(string: &[u8]) -> &OsStr {
-    // SAFETY: This function has equivalent safety requirements.
-    unsafe { OsStr::from_encoded_bytes_unchecked(string) }
+// Appends the code point or byte represented by an escape sequence, if it is
+// valid in the internal encoding.
+fn push_unescaped(string: &mut OsString, unit: Result<u32, u8>) -> bool {
+    let mut buffer = [0; MAX_UTF8_LENGTH];
+    let unit = match unit {
+        Ok(code_point) => {
+            if let Some(ch) = char::from_u32(code_point) {
+                OsStr::new(ch.encode_utf8(&mut buffer))
+            } else if cfg!(any(target_os = "uefi", windows))
+                && (0xD800..=0xDFFF).contains(&code_point)
+            {
+                let buffer = &mut buffer[..3];
+                buffer[0] = 0xE0 | (code_point >> (2 * BYTE_SHIFT)) as u8;
+                buffer[1] =
+                    CONT_TAG | ((code_point >> BYTE_SHIFT) as u8 & CONT_MASK);
+                buffer[2] = CONT_TAG | (code_point as u8 & CONT_MASK);
+                // SAFETY: On these platforms, the internal encoding is WTF-8,
+                // which can represent lone surrogates.
+                unchecked!(os_str(buffer))
+            } else {
+                return false;
+            }
+        }
+        Err(byte) => {
+            buffer[0] = byte;
+            let Some(unit) = OsStr::from_io_bytes(&buffer[..1]) else {
+                return false;
+            };
+            unit
+        }
+    };
+    // Adjacent surrogates are combined by this method.
+    string.push(unit);
+    true
 }
 
 fn split_once<'a, 'b, P>(
@@ -96,35 +361,54 @@ where
     let prefix = &string[..index];
     let suffix = &string[index + pat.len()..];
     // SAFETY: These substrings were separated by a UTF-8 string.
-    Some(unsafe { (os_str(prefix), os_str(suffix)) })
+    Some(unchecked!((os_str(prefix), os_str(suffix))))
+}
+
+fn trim_separators<'a>(string: &'a OsStr, separators: &[char]) -> &'a OsStr {
+    let mut trimmed = string;
+    while let Some(substring) =
+        separators.iter().find_map(|&x| trimmed.strip_suffix(x))
+    {
+        trimmed = substring;
+    }
+    if trimmed.is_empty() {
+        if let Some((CharOrInvalid::Char(_), suffix)) = string.split_first() {
+            let length = string.len() - suffix.len();
+            // SAFETY: This prefix ends after the first character.
+            return unchecked!(os_str(&string.as_encoded_bytes()[..length]));
+        }
+    }
+    trimmed
 }
 
 fn trim_matches<'a, 'b, P>(
     mut string: &'a OsStr,
     pat: &'b P,
     strip_fn: for<'c> fn(&'c OsStr, &'b str) -> Option<&'c OsStr>,
-) -> &'a OsStr
+) -> (&'a OsStr, usize)
 where
     P: EncodedPattern,
 {
     let pat = pat.__as_str();
 
+    let mut count = 0;
     if !pat.is_empty() {
         while let Some(substring) = strip_fn(string, pat) {
             string = substring;
+            count += 1;
         }
     }
-    string
+    (string, count)
 }
 
-fn trim_end_matches<'a, P>(string: &'a OsStr, pat: &P) -> &'a OsStr
+fn trim_end_matches<'a, P>(string: &'a OsStr, pat: &P) -> (&'a OsStr, usize)
 where
     P: EncodedPattern,
 {
     trim_matches(string, pat, OsStrBytesExt::strip_suffix)
 }
 
-fn trim_start_matches<'a, P>(string: &'a OsStr, pat: &P) -> &'a OsStr
+fn trim_start_matches<'a, P>(string: &'a OsStr, pat: &P) -> (&'a OsStr, usize)
 where
     P: EncodedPattern,
 {
@@ -148,15 +432,255 @@ where
 ///
 /// # Complexity
 ///
-/// All searching methods have worst-case multiplicative time complexity (i.e.,
-/// `O(self.len() * pat.len())`). Enabling the "memchr" feature allows these
-/// methods to instead run in linear time in the worst case (documented for
-/// [`memchr::memmem::find`][memchr_complexity]).
+/// All searching methods run in linear time in the worst case (i.e.,
+/// `O(self.len() + pat.len())`). When the "memchr" feature is enabled, they
+/// use [`memchr::memmem::find`][memchr_complexity], which is usually faster.
+/// Otherwise, an implementation of the Two-Way algorithm is used, which
+/// requires no additional dependencies or memory. Whether the feature is
+/// enabled can be checked using [`uses_memchr`].
+///
+/// The worst-case time complexity of each method is guaranteed as follows,
+/// where `n` is the length of this string and `m` is the total length of the
+/// other arguments. Methods returning iterators are measured by the total
+/// time needed to exhaust the iterator, and closures are assumed to run in
+/// constant time.
+/// - `O(1)`: [`byte_len`], [`first_char`], [`get_range`], [`get_unchecked`],
+///   [`index`], [`last_char`], [`split_at`], [`split_at_checked`],
+///   [`starts_with_alphabetic`], [`starts_with_ascii_digit`], [`strip_bom`],
+///   [`trim_quotes`], [`validate_boundary`]
+/// - `O(m)`: [`ends_with`], [`ends_with_any`], [`starts_with`],
+///   [`starts_with_any`], [`strip_circumfix`], [`strip_prefix`],
+///   [`strip_suffix`]
+/// - `O(n)`: [`ceil_char_boundary`], [`chunk_by_size`], [`code_points`],
+///   [`eq_constant_time`], [`escape_ascii`], [`find_map_chunks`],
+///   [`fingerprint`], [`floor_char_boundary`], [`has_invalid_sequences`],
+///   [`is_ascii`], [`is_unicode`], [`len_chars`], [`len_wide`],
+///   [`map_utf8`], [`normalize_newlines`], [`slice_from`], [`slice_up_to`],
+///   [`split_first`], [`split_last`], [`split_leading_digits`],
+///   [`split_once_ascii`], [`split_trailing_digits`], [`truncate_to_limit`],
+///   [`units`], [`utf8_chunks`]
+/// - `O(n + m)`: [`cross_platform_cmp`], [`ends_with_os`], [`ensure_prefix`],
+///   [`ensure_suffix`], [`has_extension`], [`nt_case_eq`], [`replace_prefix`],
+///   [`starts_with_os`], [`trim_end_matches`], [`trim_end_matches_counted`],
+///   [`trim_matches`], [`trim_matches_counted`], [`trim_start_matches`],
+///   [`trim_start_matches_counted`]
+/// - `O(n * m)`: [`components_naive`] and
+///   [`eq_os_ignoring_trailing_separators`] (where `m` is the number of
+///   separators), [`find_any_of`] and [`rfind_any_of`] (where `m` is the
+///   number of bytes), [`repeat`] (where `m` is the number of repetitions),
+///   [`split_once_any`]
+/// - Searching methods, as described above: [`contains`], [`contains_os`],
+///   [`find`], [`match_indices_os`], [`rfind`], [`rsplit`], [`rsplit_once`],
+///   [`split`], [`split_indices`], [`split_keep`], [`split_nul`],
+///   [`split_once`], [`split_with`], [`try_rsplit`], [`try_split`]
 ///
+/// [`byte_len`]: Self::byte_len
+/// [`ceil_char_boundary`]: Self::ceil_char_boundary
+/// [`chunk_by_size`]: Self::chunk_by_size
+/// [`code_points`]: Self::code_points
+/// [`components_naive`]: Self::components_naive
+/// [`contains`]: Self::contains
+/// [`contains_os`]: Self::contains_os
+/// [`cross_platform_cmp`]: Self::cross_platform_cmp
+/// [`ends_with`]: Self::ends_with
+/// [`ends_with_any`]: Self::ends_with_any
+/// [`ends_with_os`]: Self::ends_with_os
+/// [`ensure_prefix`]: Self::ensure_prefix
+/// [`ensure_suffix`]: Self::ensure_suffix
+/// [`eq_constant_time`]: Self::eq_constant_time
+/// [`eq_os_ignoring_trailing_separators`]: Self::eq_os_ignoring_trailing_separators
+/// [`escape_ascii`]: Self::escape_ascii
+/// [`find`]: Self::find
+/// [`find_any_of`]: Self::find_any_of
+/// [`find_map_chunks`]: Self::find_map_chunks
+/// [`fingerprint`]: Self::fingerprint
+/// [`floor_char_boundary`]: Self::floor_char_boundary
+/// [`first_char`]: Self::first_char
+/// [`get_range`]: Self::get_range
+/// [`get_unchecked`]: Self::get_unchecked
+/// [`has_extension`]: Self::has_extension
+/// [`has_invalid_sequences`]: Self::has_invalid_sequences
+/// [`index`]: Self::index
+/// [`is_ascii`]: Self::is_ascii
+/// [`is_unicode`]: Self::is_unicode
+/// [`last_char`]: Self::last_char
+/// [`len_chars`]: Self::len_chars
+/// [`len_wide`]: Self::len_wide
+/// [`map_utf8`]: Self::map_utf8
+/// [`match_indices_os`]: Self::match_indices_os
 /// [memchr_complexity]: ::memchr::memmem::find#complexity
+/// [`normalize_newlines`]: Self::normalize_newlines
+/// [`nt_case_eq`]: Self::nt_case_eq
+/// [`repeat`]: Self::repeat
+/// [`replace_prefix`]: Self::replace_prefix
+/// [`rfind`]: Self::rfind
+/// [`rfind_any_of`]: Self::rfind_any_of
+/// [`rsplit`]: Self::rsplit
+/// [`rsplit_once`]: Self::rsplit_once
+/// [`slice_from`]: Self::slice_from
+/// [`slice_up_to`]: Self::slice_up_to
+/// [`split`]: Self::split
+/// [`split_at`]: Self::split_at
+/// [`split_at_checked`]: Self::split_at_checked
+/// [`split_first`]: Self::split_first
+/// [`split_indices`]: Self::split_indices
+/// [`split_keep`]: Self::split_keep
+/// [`split_last`]: Self::split_last
+/// [`split_leading_digits`]: Self::split_leading_digits
+/// [`split_nul`]: Self::split_nul
+/// [`split_once`]: Self::split_once
+/// [`split_once_any`]: Self::split_once_any
+/// [`split_once_ascii`]: Self::split_once_ascii
+/// [`split_trailing_digits`]: Self::split_trailing_digits
+/// [`split_with`]: Self::split_with
+/// [`starts_with`]: Self::starts_with
+/// [`starts_with_alphabetic`]: Self::starts_with_alphabetic
+/// [`starts_with_any`]: Self::starts_with_any
+/// [`starts_with_ascii_digit`]: Self::starts_with_ascii_digit
+/// [`starts_with_os`]: Self::starts_with_os
+/// [`strip_bom`]: Self::strip_bom
+/// [`strip_circumfix`]: Self::strip_circumfix
+/// [`strip_prefix`]: Self::strip_prefix
+/// [`strip_suffix`]: Self::strip_suffix
+/// [`trim_end_matches`]: Self::trim_end_matches
+/// [`trim_end_matches_counted`]: Self::trim_end_matches_counted
+/// [`trim_matches`]: Self::trim_matches
+/// [`trim_matches_counted`]: Self::trim_matches_counted
+/// [`trim_quotes`]: Self::trim_quotes
+/// [`trim_start_matches`]: Self::trim_start_matches
+/// [`trim_start_matches_counted`]: Self::trim_start_matches_counted
+/// [`truncate_to_limit`]: Self::truncate_to_limit
+/// [`try_rsplit`]: Self::try_rsplit
+/// [`try_split`]: Self::try_split
+/// [`units`]: Self::units
+/// [`uses_memchr`]: super::uses_memchr
+/// [`utf8_chunks`]: Self::utf8_chunks
+/// [`validate_boundary`]: Self::validate_boundary
 #[cfg_attr(not(feature = "conversions"), allow(private_bounds))]
 #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
 pub trait OsStrBytesExt: OsStrBytes {
+    /// Returns the length of this string in its encoded bytes.
+    ///
+    /// Unlike [`OsStr::len`], this method is guaranteed to return the length
+    /// of [`OsStr::as_encoded_bytes`]. That is the unit used for all indices
+    /// accepted and returned by this trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}\u{F6}");
+    /// assert_eq!(5, os_string.byte_len());
+    /// ```
+    #[must_use]
+    fn byte_len(&self) -> usize;
+
+    /// Equivalent to [`str::ceil_char_boundary`], but accepts any [valid
+    /// boundary].
+    ///
+    /// Returns the length of this string if the index is greater than it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}\u{F6}");
+    /// assert_eq!(1, os_string.ceil_char_boundary(1));
+    /// assert_eq!(3, os_string.ceil_char_boundary(2));
+    /// assert_eq!(5, os_string.ceil_char_boundary(6));
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    #[must_use]
+    fn ceil_char_boundary(&self, index: usize) -> usize;
+
+    /// Returns an iterator over substrings of this string with at most the
+    /// given length.
+    ///
+    /// Each substring ends at a [valid boundary], so it will usually be
+    /// shorter than the limit. However, if there is no such boundary within
+    /// the limit, the shortest possible substring will be returned, which is
+    /// longer than the limit. That substring will be a single character or,
+    /// on some platforms, an invalid sequence of any length. Concatenating the
+    /// substrings reconstructs the original string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("fo\u{F6}bar");
+    /// assert!(os_string.chunk_by_size(3).eq(["fo", "\u{F6}b", "ar"]));
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    #[track_caller]
+    fn chunk_by_size(&self, size: usize) -> ChunkBySize<'_>;
+
+    /// Returns an iterator over the code points of this string.
+    ///
+    /// This method is similar to [`str::chars`], but it does not require the
+    /// string to be valid Unicode. On platforms where strings are represented
+    /// using potentially ill-formed UTF-16, lone surrogates will be returned
+    /// as code points. On other platforms, each byte that is not part of a valid
+    /// UTF-8 sequence will be returned as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}\u{1F4A9}");
+    /// assert!(os_string.code_points().eq([Ok(0x66), Ok(0xF6), Ok(0x1F4A9)]));
+    /// ```
+    fn code_points(&self) -> CodePoints<'_>;
+
+    /// Returns an iterator over the components of a path, split naively by
+    /// any of the separators.
+    ///
+    /// Unlike [`Path::components`], no platform-specific parsing is done.
+    /// Prefixes, root directories, and "." components have no special
+    /// meaning, so the result only depends on the separators given. Leading,
+    /// trailing, and consecutive separators are ignored, which is the same as
+    /// splitting using [`split_with`] with [`SplitOptions::skip_empty`]. This
+    /// method can be useful on platforms such as UEFI, where [`Path`] does not
+    /// know the conventions used by the firmware.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new(r"\EFI//BOOT\BOOTX64.EFI\");
+    /// assert!(os_string
+    ///     .components_naive(&['/', '\\'])
+    ///     .eq(["EFI", "BOOT", "BOOTX64.EFI"]));
+    /// ```
+    ///
+    /// [`Path`]: ::std::path::Path
+    /// [`Path::components`]: ::std::path::Path::components
+    /// [`split_with`]: Self::split_with
+    fn components_naive<'a>(
+        &'a self,
+        separators: &'a [char],
+    ) -> ComponentsNaive<'a>;
+
     /// Equivalent to [`str::contains`].
     ///
     /// # Examples
@@ -175,6 +699,69 @@ pub trait OsStrBytesExt: OsStrBytes {
     where
         P: Pattern;
 
+    /// Equivalent to [`str::contains`] but accepts this type for the pattern.
+    ///
+    /// Unlike [`ends_with_os`] and [`starts_with_os`], this method does not
+    /// require the "conversions" feature. It searches the internal encoding
+    /// directly and only inspects the ends of each match separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert!(os_string.contains_os(OsStr::new("oo")));
+    /// assert!(!os_string.contains_os(OsStr::new("of")));
+    /// ```
+    ///
+    /// [`ends_with_os`]: Self::ends_with_os
+    /// [`starts_with_os`]: Self::starts_with_os
+    #[must_use]
+    fn contains_os(&self, pat: &Self) -> bool;
+
+    if_conversions! {
+        /// Compares this string with another by their code points, producing
+        /// the same order on all platforms.
+        ///
+        /// The order of [`Ord`] for [`OsStr`] depends on the platform
+        /// encoding, so it cannot be used for output that must be
+        /// reproducible on other platforms. This method instead orders
+        /// strings lexicographically by [`code_points`], where each code
+        /// point is less than any byte that cannot be decoded. Therefore,
+        /// Unicode strings are always ordered the same way as the equivalent
+        /// [`prim@str`] values.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::cmp::Ordering;
+        /// use std::ffi::OsStr;
+        ///
+        /// use os_str_bytes::OsStrBytesExt;
+        ///
+        /// let os_string = OsStr::new("foo\u{E9}");
+        /// assert_eq!(
+        ///     Ordering::Less,
+        ///     os_string.cross_platform_cmp(OsStr::new("foo\u{1F4A9}")),
+        /// );
+        /// assert_eq!(
+        ///     Ordering::Greater,
+        ///     os_string.cross_platform_cmp(OsStr::new("foo")),
+        /// );
+        /// ```
+        ///
+        /// [`code_points`]: Self::code_points
+        #[cfg_attr(
+            os_str_bytes_docs_rs,
+            doc(cfg(feature = "conversions"))
+        )]
+        #[must_use]
+        fn cross_platform_cmp(&self, other: &Self) -> Ordering;
+    }
+
     /// Equivalent to [`str::ends_with`].
     ///
     /// # Examples
@@ -193,6 +780,25 @@ pub trait OsStrBytesExt: OsStrBytes {
     where
         P: Pattern;
 
+    /// Equivalent to [`str::ends_with`] but returns `true` if any of the
+    /// patterns match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo.tar.gz");
+    /// assert!(os_string.ends_with_any(&[".tar.gz", ".tgz"]));
+    /// assert!(!os_string.ends_with_any(&[".zip", ".tar"]));
+    /// ```
+    #[must_use]
+    fn ends_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Clone + Pattern;
+
     if_conversions! {
         /// Equivalent to [`str::ends_with`] but accepts this type for the
         /// pattern.
@@ -216,54 +822,67 @@ pub trait OsStrBytesExt: OsStrBytes {
         fn ends_with_os(&self, pat: &Self) -> bool;
     }
 
-    /// Equivalent to [`str::find`].
+    /// Prepends a pattern to this string, unless it already starts with the
+    /// pattern.
+    ///
+    /// The string is only copied when the pattern needs to be added.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::borrow::Cow;
     /// use std::ffi::OsStr;
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foobar");
-    /// assert_eq!(Some(1), os_string.find("o"));
-    /// assert_eq!(None, os_string.find("of"));
+    /// let os_string = OsStr::new("foo");
+    /// assert_eq!("/foo", &*os_string.ensure_prefix("/"));
+    /// assert!(matches!(
+    ///     OsStr::new("/foo").ensure_prefix("/"),
+    ///     Cow::Borrowed(_),
+    /// ));
     /// ```
     #[must_use]
-    fn find<P>(&self, pat: P) -> Option<usize>
+    fn ensure_prefix<P>(&self, pat: P) -> Cow<'_, Self>
     where
         P: Pattern;
 
-    /// Equivalent to [`str::get_unchecked`].
+    /// Appends a pattern to this string, unless it already ends with the
+    /// pattern.
     ///
-    /// # Safety
-    ///
-    /// The index must be a [valid boundary].
+    /// The string is only copied when the pattern needs to be added.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::borrow::Cow;
     /// use std::ffi::OsStr;
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foobar");
-    /// assert_eq!("foo", unsafe { os_string.get_unchecked(..3) });
-    /// assert_eq!("bar", unsafe { os_string.get_unchecked(3..) });
+    /// let os_string = OsStr::new("foo");
+    /// assert_eq!("foo/", &*os_string.ensure_suffix("/"));
+    /// assert!(matches!(
+    ///     OsStr::new("foo/").ensure_suffix("/"),
+    ///     Cow::Borrowed(_),
+    /// ));
     /// ```
-    ///
-    /// [valid boundary]: #indices
     #[must_use]
-    #[track_caller]
-    unsafe fn get_unchecked<I>(&self, index: I) -> &Self
+    fn ensure_suffix<P>(&self, pat: P) -> Cow<'_, Self>
     where
-        I: SliceIndex;
+        P: Pattern;
 
-    /// Equivalent to the [`Index::index`] implementation for [`prim@str`].
+    /// Returns `true` if this string is equal to another, using a comparison
+    /// whose timing does not depend on their content.
     ///
-    /// # Panics
+    /// This method is intended for comparing secrets, such as tokens passed
+    /// using environment variables. Every byte is compared, even after a
+    /// difference is found. If the lengths of the strings differ, the
+    /// comparison still covers the length of the longer string, so the time
+    /// taken only depends on that length.
     ///
-    /// Panics if the index is not a [valid boundary].
+    /// The comparison is only protected against compiler optimizations on a
+    /// best-effort basis.
     ///
     /// # Examples
     ///
@@ -272,20 +891,64 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foobar");
-    /// assert_eq!("foo", os_string.index(..3));
-    /// assert_eq!("bar", os_string.index(3..));
+    /// let os_string = OsStr::new("secret");
+    /// assert!(os_string.eq_constant_time(OsStr::new("secret")));
+    /// assert!(!os_string.eq_constant_time(OsStr::new("Secret")));
+    /// assert!(!os_string.eq_constant_time(OsStr::new("secret\0")));
     /// ```
+    #[must_use]
+    fn eq_constant_time(&self, other: &Self) -> bool;
+
+    /// Returns `true` if this string is equal to another after removing
+    /// trailing separators from both.
     ///
-    /// [`Index::index`]: ::std::ops::Index::index
-    /// [valid boundary]: #indices
+    /// This method can be used to compare directories specified by users,
+    /// which may or may not end with a separator. However, a string
+    /// containing only separators keeps the first one, so that a root
+    /// directory is never equal to an empty string. Separators elsewhere
+    /// must match exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let separators = ['/', '\\'];
+    ///
+    /// let os_string = OsStr::new("foo/bar");
+    /// assert!(os_string.eq_os_ignoring_trailing_separators(
+    ///     OsStr::new("foo/bar\\/"),
+    ///     &separators,
+    /// ));
+    /// assert!(!os_string.eq_os_ignoring_trailing_separators(
+    ///     OsStr::new("foo\\bar"),
+    ///     &separators,
+    /// ));
+    ///
+    /// assert!(!OsStr::new("/")
+    ///     .eq_os_ignoring_trailing_separators(OsStr::new(""), &separators));
+    /// ```
     #[must_use]
-    #[track_caller]
-    fn index<I>(&self, index: I) -> &Self
-    where
-        I: SliceIndex;
+    fn eq_os_ignoring_trailing_separators(
+        &self,
+        other: &Self,
+        separators: &[char],
+    ) -> bool;
 
-    /// Equivalent to [`str::repeat`].
+    /// Escapes this string, so that it can be represented using only
+    /// printable ASCII characters.
+    ///
+    /// Printable ASCII characters other than backslashes are returned
+    /// unchanged, and backslashes are doubled. Other ASCII characters are
+    /// written as `\xNN`, and other code points are written as `\u{NNNN}`
+    /// using lowercase hexadecimal digits. On platforms where strings can
+    /// contain arbitrary bytes, each byte that is not part of a valid UTF-8
+    /// sequence is also written as `\xNN`.
+    ///
+    /// The result is deterministic and can be converted back to an
+    /// equivalent string using [`OsStringBytesExt::unescape_ascii`].
     ///
     /// # Examples
     ///
@@ -294,13 +957,13 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foo");
-    /// assert_eq!("foofoofoo", os_string.repeat(3));
+    /// let os_string = OsStr::new("f\\\u{F6}\n");
+    /// assert_eq!(r"f\\\u{f6}\x0a", os_string.escape_ascii());
     /// ```
     #[must_use]
-    fn repeat(&self, n: usize) -> Self::Owned;
+    fn escape_ascii(&self) -> String;
 
-    /// Equivalent to [`str::rfind`].
+    /// Equivalent to [`str::find`].
     ///
     /// # Examples
     ///
@@ -310,34 +973,88 @@ pub trait OsStrBytesExt: OsStrBytes {
     /// use os_str_bytes::OsStrBytesExt;
     ///
     /// let os_string = OsStr::new("foobar");
-    /// assert_eq!(Some(2), os_string.rfind("o"));
-    /// assert_eq!(None, os_string.rfind("of"));
+    /// assert_eq!(Some(1), os_string.find("o"));
+    /// assert_eq!(None, os_string.find("of"));
     /// ```
     #[must_use]
-    fn rfind<P>(&self, pat: P) -> Option<usize>
+    fn find<P>(&self, pat: P) -> Option<usize>
     where
         P: Pattern;
 
-    /// Equivalent to [`str::rsplit`], but empty patterns are not accepted.
+    /// Returns the byte index of the first occurrence of any of the given
+    /// ASCII bytes.
+    ///
+    /// Since ASCII bytes never occur within multi-byte sequences, the index
+    /// is always a [valid boundary]. When the "memchr" feature is enabled,
+    /// sets of up to three bytes are searched using the fastest routines
+    /// available from that crate. Thus, this method is useful for finding
+    /// separators in paths.
     ///
     /// # Panics
     ///
-    /// Panics if the pattern is empty.
+    /// Panics if any of the bytes are not ASCII.
     ///
     /// # Examples
     ///
     /// ```
-    /// use os_str_bytes::RawOsStr;
+    /// use std::ffi::OsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert!(raw.rsplit("o").eq(["bar", "", "f"]));
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo\\bar/baz");
+    /// assert_eq!(Some(3), os_string.find_any_of(b"/\\"));
+    /// assert_eq!(None, os_string.find_any_of(b":"));
     /// ```
-    #[track_caller]
-    fn rsplit<P>(&self, pat: P) -> RSplit<'_, P>
+    ///
+    /// [valid boundary]: #indices
+    #[must_use]
+    #[track_caller]
+    fn find_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize>;
+
+    /// Calls a closure for each chunk returned by [`utf8_chunks`], until it
+    /// returns [`Some`].
+    ///
+    /// The closure is also given the byte index of the chunk in this string,
+    /// which can be used to report the location of an error or to slice the
+    /// string. Iteration stops at the first result that is not [`None`], and
+    /// that result is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// fn find_control(os_string: &OsStr) -> Option<usize> {
+    ///     os_string.find_map_chunks(|index, invalid, string| {
+    ///         string
+    ///             .find(char::is_control)
+    ///             .map(|x| index + invalid.as_os_str().len() + x)
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(Some(3), find_control(OsStr::new("foo\nbar")));
+    /// assert_eq!(None, find_control(OsStr::new("foobar")));
+    /// ```
+    ///
+    /// [`utf8_chunks`]: Self::utf8_chunks
+    fn find_map_chunks<F, T>(&self, f: F) -> Option<T>
     where
-        P: Pattern;
+        F: FnMut(usize, &NonUnicodeOsStr, &str) -> Option<T>;
 
-    /// Equivalent to [`str::rsplit_once`].
+    /// Returns a 64-bit fingerprint of this string, which can be persisted.
+    ///
+    /// Unlike [`Hash`], the result does not depend on the process, the
+    /// version of Rust, or the platform. It is the 64-bit FNV-1a hash of the
+    /// string encoded as UTF-8, where unpaired surrogates are encoded as in
+    /// WTF-8 and bytes that are not part of valid sequences are retained.
+    /// Thus, equal Unicode strings always have the same fingerprint. This
+    /// definition will not change without a major version, so it can be used
+    /// to key caches stored on disk.
+    ///
+    /// The fingerprint is not cryptographically secure, so it should not be
+    /// used where collisions can be chosen by an attacker.
     ///
     /// # Examples
     ///
@@ -346,42 +1063,70 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foobar");
-    /// assert_eq!(
-    ///     Some((OsStr::new("fo"), OsStr::new("bar"))),
-    ///     os_string.rsplit_once("o"),
-    /// );
-    /// assert_eq!(None, os_string.rsplit_once("of"));
+    /// assert_eq!(0xDCB27518FED9D577, OsStr::new("foo").fingerprint());
+    /// assert_eq!(0xCBF29CE484222325, OsStr::new("").fingerprint());
     /// ```
+    ///
+    /// [`Hash`]: ::std::hash::Hash
     #[must_use]
-    fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
-    where
-        P: Pattern;
+    fn fingerprint(&self) -> u64;
 
-    /// Equivalent to [`str::split`], but empty patterns are not accepted.
+    /// Returns `true` if the final component of this path has the given
+    /// extension.
     ///
-    /// # Panics
+    /// A leading dot in the extension is ignored, and it can contain multiple
+    /// parts, such as "tar.gz". Trailing separators are ignored when finding
+    /// the final component, in the same way as [`Path::file_name`]. Names
+    /// that begin with a dot, such as ".bashrc", must have another dot before
+    /// the extension, and "." and ".." never have one.
     ///
-    /// Panics if the pattern is empty.
+    /// When `case_insensitive` is `true`, ASCII letters are compared without
+    /// regard to case. Passing [`cfg!(windows)`] follows the convention of
+    /// the current platform.
     ///
     /// # Examples
     ///
     /// ```
-    /// use os_str_bytes::RawOsStr;
+    /// use std::ffi::OsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert!(raw.split("o").eq(["f", "", "bar"]));
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo.d/bar.TAR.gz");
+    /// assert!(os_string.has_extension("gz", false));
+    /// assert!(os_string.has_extension(".tar.gz", true));
+    /// assert!(!os_string.has_extension("tar.gz", false));
+    /// assert!(!os_string.has_extension("d", false));
+    /// assert!(!OsStr::new(".gz").has_extension("gz", false));
     /// ```
-    #[track_caller]
-    fn split<P>(&self, pat: P) -> Split<'_, P>
-    where
-        P: Pattern;
+    ///
+    /// [`cfg!(windows)`]: cfg
+    /// [`Path::file_name`]: ::std::path::Path::file_name
+    #[must_use]
+    fn has_extension(&self, ext: &str, case_insensitive: bool) -> bool;
 
-    /// Equivalent to [`str::split_at`].
+    /// Returns `true` if this string contains any sequences that are not
+    /// valid Unicode.
     ///
-    /// # Panics
+    /// This method is equivalent to negating [`is_unicode`].
     ///
-    /// Panics if the index is not a [valid boundary].
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// assert!(!OsStr::new("foobar").has_invalid_sequences());
+    /// ```
+    ///
+    /// [`is_unicode`]: Self::is_unicode
+    #[must_use]
+    fn has_invalid_sequences(&self) -> bool;
+
+    /// Equivalent to [`str::floor_char_boundary`], but accepts any [valid
+    /// boundary].
+    ///
+    /// Returns the length of this string if the index is greater than it.
     ///
     /// # Examples
     ///
@@ -390,19 +1135,21 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foobar");
-    /// assert_eq!(
-    ///     (OsStr::new("fo"), OsStr::new("obar")),
-    ///     os_string.split_at(2),
-    /// );
+    /// let os_string = OsStr::new("f\u{F6}\u{F6}");
+    /// assert_eq!(1, os_string.floor_char_boundary(1));
+    /// assert_eq!(1, os_string.floor_char_boundary(2));
+    /// assert_eq!(5, os_string.floor_char_boundary(6));
     /// ```
     ///
     /// [valid boundary]: #indices
     #[must_use]
-    #[track_caller]
-    fn split_at(&self, mid: usize) -> (&Self, &Self);
+    fn floor_char_boundary(&self, index: usize) -> usize;
 
-    /// Equivalent to [`str::split_once`].
+    /// Returns the first character of this string, if it is a valid Unicode
+    /// character.
+    ///
+    /// Only the bytes of the first character are examined, so this method is
+    /// much cheaper than iterating over the string.
     ///
     /// # Examples
     ///
@@ -411,19 +1158,16 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foobar");
-    /// assert_eq!(
-    ///     Some((OsStr::new("f"), OsStr::new("obar"))),
-    ///     os_string.split_once("o"),
-    /// );
-    /// assert_eq!(None, os_string.split_once("of"));
+    /// assert_eq!(Some('-'), OsStr::new("-9").first_char());
+    /// assert_eq!(None, OsStr::new("").first_char());
     /// ```
     #[must_use]
-    fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
-    where
-        P: Pattern;
+    fn first_char(&self) -> Option<char>;
 
-    /// Equivalent to [`str::starts_with`].
+    /// Equivalent to [`str::get`].
+    ///
+    /// Returns [`None`] if the index is out of bounds or is not a [valid
+    /// boundary].
     ///
     /// # Examples
     ///
@@ -432,18 +1176,24 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("foobar");
-    /// assert!(os_string.starts_with("foo"));
-    /// assert!(!os_string.starts_with("bar"));
+    /// let os_string = OsStr::new("f\u{F6}o");
+    /// assert_eq!(Some(OsStr::new("f\u{F6}")), os_string.get_range(..3));
+    /// assert_eq!(None, os_string.get_range(..2));
+    /// assert_eq!(None, os_string.get_range(..5));
     /// ```
+    ///
+    /// [valid boundary]: #indices
     #[must_use]
-    fn starts_with<P>(&self, pat: P) -> bool
+    fn get_range<I>(&self, index: I) -> Option<&Self>
     where
-        P: Pattern;
+        I: SliceIndex;
 
-    if_conversions! {
-        /// Equivalent to [`str::starts_with`] but accepts this type for the
-        /// pattern.
+    unchecked_fn! {
+        /// Equivalent to [`str::get_unchecked`].
+        ///
+        /// # Safety
+        ///
+        /// The index must be a [valid boundary].
         ///
         /// # Examples
         ///
@@ -453,18 +1203,23 @@ pub trait OsStrBytesExt: OsStrBytes {
         /// use os_str_bytes::OsStrBytesExt;
         ///
         /// let os_string = OsStr::new("foobar");
-        /// assert!(os_string.starts_with_os(OsStr::new("foo")));
-        /// assert!(!os_string.starts_with_os(OsStr::new("bar")));
+        /// assert_eq!("foo", unsafe { os_string.get_unchecked(..3) });
+        /// assert_eq!("bar", unsafe { os_string.get_unchecked(3..) });
         /// ```
-        #[cfg_attr(
-            os_str_bytes_docs_rs,
-            doc(cfg(feature = "conversions"))
-        )]
+        ///
+        /// [valid boundary]: #indices
         #[must_use]
-        fn starts_with_os(&self, pat: &Self) -> bool;
+        #[track_caller]
+        unsafe fn get_unchecked<I>(&self, index: I) -> &Self
+        where
+            I: SliceIndex;
     }
 
-    /// Equivalent to [`str::strip_prefix`].
+    /// Equivalent to the [`Index::index`] implementation for [`prim@str`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not a [valid boundary].
     ///
     /// # Examples
     ///
@@ -473,19 +1228,24 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("111foo1bar111");
-    /// assert_eq!(
-    ///     Some(OsStr::new("11foo1bar111")),
-    ///     os_string.strip_prefix("1"),
-    /// );
-    /// assert_eq!(None, os_string.strip_prefix("o"));
+    /// let os_string = OsStr::new("foobar");
+    /// assert_eq!("foo", os_string.index(..3));
+    /// assert_eq!("bar", os_string.index(3..));
     /// ```
+    ///
+    /// [`Index::index`]: ::std::ops::Index::index
+    /// [valid boundary]: #indices
     #[must_use]
-    fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
+    #[track_caller]
+    fn index<I>(&self, index: I) -> &Self
     where
-        P: Pattern;
+        I: SliceIndex;
 
-    /// Equivalent to [`str::strip_suffix`].
+    /// Equivalent to [`str::is_ascii`].
+    ///
+    /// The inherent method [`OsStr::is_ascii`] takes precedence when using
+    /// method call syntax, so this method must be called using fully
+    /// qualified syntax, as in the examples.
     ///
     /// # Examples
     ///
@@ -494,19 +1254,16 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("111foo1bar111");
-    /// assert_eq!(
-    ///     Some(OsStr::new("111foo1bar11")),
-    ///     os_string.strip_suffix("1"),
-    /// );
-    /// assert_eq!(None, os_string.strip_suffix("o"));
+    /// assert!(OsStrBytesExt::is_ascii(OsStr::new("foobar")));
+    /// assert!(!OsStrBytesExt::is_ascii(OsStr::new("f\u{F6}\u{F6}bar")));
     /// ```
     #[must_use]
-    fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
-    where
-        P: Pattern;
+    fn is_ascii(&self) -> bool;
 
-    /// Equivalent to [`str::trim_end_matches`].
+    /// Returns `true` if this string is valid Unicode.
+    ///
+    /// This method is equivalent to checking that [`OsStr::to_str`] returns
+    /// [`Some`], but the result is not constructed.
     ///
     /// # Examples
     ///
@@ -515,16 +1272,16 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("111foo1bar111");
-    /// assert_eq!("111foo1bar", os_string.trim_end_matches("1"));
-    /// assert_eq!("111foo1bar111", os_string.trim_end_matches("o"));
+    /// assert!(OsStr::new("f\u{F6}\u{F6}bar").is_unicode());
     /// ```
     #[must_use]
-    fn trim_end_matches<P>(&self, pat: P) -> &Self
-    where
-        P: Pattern;
+    fn is_unicode(&self) -> bool;
 
-    /// Equivalent to [`str::trim_matches`].
+    /// Returns the last character of this string, if it is a valid Unicode
+    /// character.
+    ///
+    /// Only the bytes of the last character are examined, so this method is
+    /// much cheaper than iterating over the string.
     ///
     /// # Examples
     ///
@@ -533,16 +1290,18 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("111foo1bar111");
-    /// assert_eq!("foo1bar", os_string.trim_matches("1"));
-    /// assert_eq!("111foo1bar111", os_string.trim_matches("o"));
+    /// assert_eq!(Some('\u{F6}'), OsStr::new("f\u{F6}\u{F6}").last_char());
+    /// assert_eq!(None, OsStr::new("").last_char());
     /// ```
     #[must_use]
-    fn trim_matches<P>(&self, pat: P) -> &Self
-    where
-        P: Pattern;
+    fn last_char(&self) -> Option<char>;
 
-    /// Equivalent to [`str::trim_start_matches`].
+    /// Returns the number of code points in this string.
+    ///
+    /// Each item returned by [`code_points`] is counted once. Thus, this
+    /// method is equivalent to calling [`Iterator::count`] on that
+    /// iterator, and it returns the same value on all platforms for
+    /// Unicode strings.
     ///
     /// # Examples
     ///
@@ -551,295 +1310,3168 @@ pub trait OsStrBytesExt: OsStrBytes {
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// let os_string = OsStr::new("111foo1bar111");
-    /// assert_eq!("foo1bar111", os_string.trim_start_matches("1"));
-    /// assert_eq!("111foo1bar111", os_string.trim_start_matches("o"));
+    /// let os_string = OsStr::new("foo\u{1F4A9}");
+    /// assert_eq!(4, os_string.len_chars());
     /// ```
+    ///
+    /// [`code_points`]: Self::code_points
     #[must_use]
-    fn trim_start_matches<P>(&self, pat: P) -> &Self
-    where
-        P: Pattern;
+    fn len_chars(&self) -> usize;
 
-    /// Splits this string into platform and UTF-8 substrings.
+    if_conversions! {
+        /// Returns the number of UTF-16 code units needed to encode this
+        /// string.
+        ///
+        /// On Windows, the result is the length of the string returned by
+        /// [`OsStrExt::encode_wide`], which is the unit used for limits such
+        /// as [`MAX_PATH`]. The same value is computed on other platforms,
+        /// where each byte that cannot be decoded is counted as a single
+        /// unit.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        ///
+        /// use os_str_bytes::OsStrBytesExt;
+        ///
+        /// let os_string = OsStr::new("foo\u{1F4A9}");
+        /// assert_eq!(5, os_string.len_wide());
+        /// ```
+        ///
+        /// [`MAX_PATH`]: https://learn.microsoft.com/en-us/windows/win32/fileio/maximum-file-path-limitation
+        /// [`OsStrExt::encode_wide`]: https://doc.rust-lang.org/std/os/windows/ffi/trait.OsStrExt.html#tymethod.encode_wide
+        #[cfg_attr(
+            os_str_bytes_docs_rs,
+            doc(cfg(feature = "conversions"))
+        )]
+        #[must_use]
+        fn len_wide(&self) -> usize;
+    }
+
+    /// Applies a function to each Unicode substring of this string.
     ///
-    /// The iterator returned by this method is very similar to
-    /// [`str::Utf8Chunks`]. However, the [`OsStr`] portion of each chunk
-    /// precedes the [`prim@str`] portion and has no length restrictions.
+    /// The substrings are those yielded by [`utf8_chunks`], and invalid
+    /// sequences between them are left unchanged. This method allows
+    /// replacing, normalizing, or case-folding the text of a string, without
+    /// losing the rest of its content.
     ///
-    /// The [`OsStr`] portion of each chunk can be empty only at the start of a
-    /// string, and the [`prim@str`] portion at the end of a string. They will
-    /// never be empty simultaneously.
+    /// The string is only copied when the function changes a substring.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::borrow::Cow;
     /// use std::ffi::OsStr;
     ///
     /// use os_str_bytes::OsStrBytesExt;
     ///
-    /// fn to_str_lossy<F>(os_string: &OsStr, mut push: F)
-    /// where
-    ///     F: FnMut(&str),
-    /// {
-    ///     for (invalid, string) in os_string.utf8_chunks() {
-    ///         if !invalid.as_os_str().is_empty() {
-    ///             push("\u{FFFD}");
-    ///         }
-    ///
-    ///         push(string);
-    ///     }
-    /// }
+    /// let os_string = OsStr::new("foo bar");
+    /// let uppercase = os_string.map_utf8(|x| x.to_uppercase().into());
+    /// assert_eq!("FOO BAR", &*uppercase);
+    /// assert!(matches!(
+    ///     OsStr::new("foo").map_utf8(|x| x.replace(' ', "_").into()),
+    ///     Cow::Borrowed(_),
+    /// ));
     /// ```
-    fn utf8_chunks(&self) -> Utf8Chunks<'_>;
-}
-
-impl OsStrBytesExt for OsStr {
-    #[inline]
-    fn contains<P>(&self, pat: P) -> bool
+    ///
+    /// [`utf8_chunks`]: Self::utf8_chunks
+    #[must_use]
+    fn map_utf8<F>(&self, f: F) -> Cow<'_, Self>
     where
-        P: Pattern,
-    {
-        self.find(pat).is_some()
-    }
+        F: FnMut(&str) -> Cow<'_, str>;
 
-    #[inline]
-    fn ends_with<P>(&self, pat: P) -> bool
-    where
-        P: Pattern,
-    {
-        let pat = pat.__encode();
-        let pat = pat.__as_bytes();
+    /// Equivalent to [`str::match_indices`] but accepts this type for the
+    /// pattern.
+    ///
+    /// Like [`contains_os`], this method searches the internal encoding
+    /// directly, so it does not require the "conversions" feature. Matches
+    /// are non-overlapping by default, but [`MatchIndicesOs::overlapping`]
+    /// can be used to find all occurrences of the pattern, such as when
+    /// auditing arguments for suspicious sequences.
+    ///
+    /// The pattern can match part of a character, such as a partial UTF-8
+    /// sequence on Unix or half of a supplementary character on Windows. The
+    /// yielded substring is then widened to include the entire character, so
+    /// that it can always be returned as a slice of this string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("../foo/../bar");
+    /// assert!(os_string
+    ///     .match_indices_os(OsStr::new("../"))
+    ///     .eq([(0, OsStr::new("../")), (7, OsStr::new("../"))]));
+    /// ```
+    ///
+    /// [`contains_os`]: Self::contains_os
+    fn match_indices_os<'a>(&'a self, pat: &'a Self) -> MatchIndicesOs<'a>;
 
-        self.as_encoded_bytes().ends_with(pat)
-    }
+    /// Replaces each occurrence of `"\r\n"` with `"\n"`.
+    ///
+    /// Lone carriage returns are left unchanged. The string is only copied
+    /// when it contains a replaced sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo\r\nbar\r");
+    /// assert_eq!("foo\nbar\r", &*os_string.normalize_newlines());
+    /// assert!(matches!(
+    ///     OsStr::new("foo\nbar").normalize_newlines(),
+    ///     Cow::Borrowed(_),
+    /// ));
+    /// ```
+    #[must_use]
+    fn normalize_newlines(&self) -> Cow<'_, Self>;
 
     if_conversions! {
-        #[inline]
-        fn ends_with_os(&self, pat: &Self) -> bool {
-            raw::ends_with(&self.to_raw_bytes(), &pat.to_raw_bytes())
-        }
-    }
-
-    #[inline]
-    fn find<P>(&self, pat: P) -> Option<usize>
-    where
-        P: Pattern,
-    {
-        let pat = pat.__encode();
+        /// Returns `true` if this string would be considered equal to another
+        /// by case-insensitive filesystems on Windows.
+        ///
+        /// NTFS compares names by converting each UTF-16 code unit to
+        /// uppercase using a fixed table. This method approximates that
+        /// behavior using simple case mappings, where characters are only
+        /// converted if their uppercase form is a single character in the
+        /// Basic Multilingual Plane. Other code points and bytes that cannot
+        /// be decoded must match exactly. Since the comparison does not
+        /// depend on the platform, it can be used to predict collisions
+        /// between file names on any host.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        ///
+        /// use os_str_bytes::OsStrBytesExt;
+        ///
+        /// let os_string = OsStr::new("Stra\u{DF}e.txt");
+        /// assert!(os_string.nt_case_eq(OsStr::new("STRA\u{DF}E.TXT")));
+        /// assert!(!os_string.nt_case_eq(OsStr::new("STRASSE.TXT")));
+        /// ```
+        #[cfg_attr(
+            os_str_bytes_docs_rs,
+            doc(cfg(feature = "conversions"))
+        )]
+        #[must_use]
+        fn nt_case_eq(&self, other: &Self) -> bool;
+    }
+
+    /// Equivalent to [`str::repeat`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo");
+    /// assert_eq!("foofoofoo", os_string.repeat(3));
+    /// ```
+    #[must_use]
+    fn repeat(&self, n: usize) -> Self::Owned;
+
+    /// Replaces a prefix of this string, if it is present.
+    ///
+    /// The prefix is compared using encoded bytes, but it only matches if it
+    /// ends at a [valid boundary] of this string. This string is borrowed if
+    /// the prefix does not match, which avoids allocating when rebasing paths
+    /// that are outside of the original root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let from = OsStr::new("/src/");
+    /// let to = OsStr::new("/dst/");
+    /// assert_eq!(
+    ///     "/dst/foo.rs",
+    ///     &*OsStr::new("/src/foo.rs").replace_prefix(from, to),
+    /// );
+    /// assert_eq!(
+    ///     "/lib/foo.rs",
+    ///     &*OsStr::new("/lib/foo.rs").replace_prefix(from, to),
+    /// );
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    #[must_use]
+    fn replace_prefix(&self, from: &Self, to: &Self) -> Cow<'_, Self>;
+
+    /// Equivalent to [`str::rfind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert_eq!(Some(2), os_string.rfind("o"));
+    /// assert_eq!(None, os_string.rfind("of"));
+    /// ```
+    #[must_use]
+    fn rfind<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`find_any_of`], but returns the byte index of the last
+    /// occurrence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the bytes are not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo\\bar/baz");
+    /// assert_eq!(Some(7), os_string.rfind_any_of(b"/\\"));
+    /// assert_eq!(None, os_string.rfind_any_of(b":"));
+    /// ```
+    ///
+    /// [`find_any_of`]: Self::find_any_of
+    #[must_use]
+    #[track_caller]
+    fn rfind_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize>;
+
+    /// Equivalent to [`str::rsplit`], but empty patterns are not accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    /// [`try_rsplit`] can be used to handle this case without panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.rsplit("o").eq(["bar", "", "f"]));
+    /// ```
+    ///
+    /// [`try_rsplit`]: Self::try_rsplit
+    #[track_caller]
+    fn rsplit<P>(&self, pat: P) -> RSplit<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`str::rsplit_once`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert_eq!(
+    ///     Some((OsStr::new("fo"), OsStr::new("bar"))),
+    ///     os_string.rsplit_once("o"),
+    /// );
+    /// assert_eq!(None, os_string.rsplit_once("of"));
+    /// ```
+    #[must_use]
+    fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern;
+
+    /// Returns the suffix of this string starting at an index, or at the
+    /// next [valid boundary] after it.
+    ///
+    /// Unlike indexing, this method does not panic for invalid indices. If the
+    /// index is within a character, that character is excluded from the
+    /// result, so the index is snapped upward. If it is greater than the
+    /// length of this string, an empty string is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}o");
+    /// assert_eq!("\u{F6}o", os_string.slice_from(1));
+    /// assert_eq!("o", os_string.slice_from(2));
+    /// assert_eq!("", os_string.slice_from(5));
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    #[must_use]
+    fn slice_from(&self, index: usize) -> &Self;
+
+    /// Returns the prefix of this string ending at an index, or at the
+    /// previous [valid boundary] before it.
+    ///
+    /// Unlike indexing, this method does not panic for invalid indices. If the
+    /// index is within a character, that character is excluded from the
+    /// result, so the index is snapped downward. If it is greater than the
+    /// length of this string, the entire string is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}o");
+    /// assert_eq!("f\u{F6}", os_string.slice_up_to(3));
+    /// assert_eq!("f", os_string.slice_up_to(2));
+    /// assert_eq!("f\u{F6}o", os_string.slice_up_to(5));
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    #[must_use]
+    fn slice_up_to(&self, index: usize) -> &Self;
+
+    /// Equivalent to [`str::split`], but empty patterns are not accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    /// [`try_split`] can be used to handle this case without panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.split("o").eq(["f", "", "bar"]));
+    /// ```
+    ///
+    /// [`try_split`]: Self::try_split
+    #[track_caller]
+    fn split<P>(&self, pat: P) -> Split<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`str::split_at`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not a [valid boundary].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert_eq!(
+    ///     (OsStr::new("fo"), OsStr::new("obar")),
+    ///     os_string.split_at(2),
+    /// );
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    #[must_use]
+    #[track_caller]
+    fn split_at(&self, mid: usize) -> (&Self, &Self);
+
+    /// Equivalent to [`str::split_at_checked`].
+    ///
+    /// [`None`] is returned if the index is not a [valid boundary].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}o");
+    /// assert_eq!(
+    ///     Some((OsStr::new("f"), OsStr::new("\u{F6}o"))),
+    ///     os_string.split_at_checked(1),
+    /// );
+    /// assert_eq!(None, os_string.split_at_checked(2));
+    /// assert_eq!(None, os_string.split_at_checked(5));
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    #[must_use]
+    fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)>;
+
+    /// Equivalent to [`slice::split_first`] but returns the first character or
+    /// non-Unicode sequence.
+    ///
+    /// Consecutive non-Unicode sequences are returned together, in the same
+    /// way as they are by [`utf8_chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::CharOrInvalid;
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("\u{F6}foo");
+    /// assert_eq!(
+    ///     Some((CharOrInvalid::Char('\u{F6}'), OsStr::new("foo"))),
+    ///     os_string.split_first(),
+    /// );
+    /// assert_eq!(None, OsStr::new("").split_first());
+    /// ```
+    ///
+    /// [`utf8_chunks`]: Self::utf8_chunks
+    #[must_use]
+    fn split_first(&self) -> Option<(CharOrInvalid<'_>, &Self)>;
+
+    /// Equivalent to [`str::split`], but each substring is returned with its
+    /// starting index.
+    ///
+    /// The indices can be used to slice the original string later, without
+    /// storing the substrings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo,bar,baz");
+    /// let mut split = os_string.split_indices(",");
+    /// assert_eq!(Some((0, OsStr::new("foo"))), split.next());
+    /// assert_eq!(Some((4, OsStr::new("bar"))), split.next());
+    /// assert_eq!(Some((8, OsStr::new("baz"))), split.next());
+    /// assert_eq!(None, split.next());
+    /// ```
+    #[track_caller]
+    fn split_indices<P>(&self, pat: P) -> SplitIndices<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`split`], but the separators are also returned.
+    ///
+    /// Fields and separators are returned alternately, starting and ending
+    /// with a field. Thus, concatenating the items reconstructs the original
+    /// string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::iter::SplitItem;
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo=bar");
+    /// assert!(os_string.split_keep("=").eq([
+    ///     SplitItem::Field(OsStr::new("foo")),
+    ///     SplitItem::Separator("="),
+    ///     SplitItem::Field(OsStr::new("bar")),
+    /// ]));
+    /// ```
+    ///
+    /// [`split`]: Self::split
+    #[track_caller]
+    fn split_keep<P>(&self, pat: P) -> SplitKeep<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`slice::split_last`] but returns the last character or
+    /// non-Unicode sequence.
+    ///
+    /// Consecutive non-Unicode sequences are returned together, in the same
+    /// way as they are by [`utf8_chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::CharOrInvalid;
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo\u{F6}");
+    /// assert_eq!(
+    ///     Some((CharOrInvalid::Char('\u{F6}'), OsStr::new("foo"))),
+    ///     os_string.split_last(),
+    /// );
+    /// assert_eq!(None, OsStr::new("").split_last());
+    /// ```
+    ///
+    /// [`utf8_chunks`]: Self::utf8_chunks
+    #[must_use]
+    fn split_last(&self) -> Option<(CharOrInvalid<'_>, &Self)>;
+
+    /// Divides this string after its leading ASCII digits, according to
+    /// [`char::is_ascii_digit`].
+    ///
+    /// The digits are returned as a [`prim@str`], so they can be parsed into
+    /// any integer type without checking boundaries. If this string does not
+    /// start with a digit, [`None`] is returned with the entire string.
+    ///
+    /// This method can be used to parse arguments such as `-j8` or sizes such
+    /// as `8k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("64k");
+    /// assert_eq!(
+    ///     (Some("64"), OsStr::new("k")),
+    ///     os_string.split_leading_digits(),
+    /// );
+    ///
+    /// let os_string = OsStr::new("j8");
+    /// assert_eq!((None, os_string), os_string.split_leading_digits());
+    /// ```
+    #[must_use]
+    fn split_leading_digits(&self) -> (Option<&str>, &Self);
+
+    /// Splits this string into records terminated by NUL characters, such as
+    /// the output of `find -print0`.
+    ///
+    /// A trailing terminator does not produce an empty record, but empty
+    /// records elsewhere are returned. This method is equivalent to
+    /// [`split_with`] using [`SplitOptions::skip_trailing_empty`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo\0bar\0");
+    /// assert!(os_string.split_nul().eq(["foo", "bar"]));
+    /// ```
+    ///
+    /// [`split_with`]: Self::split_with
+    fn split_nul(&self) -> SplitWith<'_, char>;
+
+    /// Equivalent to [`str::split_once`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert_eq!(
+    ///     Some((OsStr::new("f"), OsStr::new("obar"))),
+    ///     os_string.split_once("o"),
+    /// );
+    /// assert_eq!(None, os_string.split_once("of"));
+    /// ```
+    #[must_use]
+    fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`split_once`], but splits on the first occurrence of
+    /// any of the patterns.
+    ///
+    /// The index of the pattern that matched is also returned. If multiple
+    /// patterns match at the same position, the first in the slice is used.
+    /// The string is only scanned once, regardless of the number of patterns,
+    /// and this method does not allocate unless cloning a pattern does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("key:value=1");
+    /// assert_eq!(
+    ///     Some((OsStr::new("key"), OsStr::new("value=1"), 1)),
+    ///     os_string.split_once_any(&['=', ':']),
+    /// );
+    /// assert_eq!(None, os_string.split_once_any(&[',', ';']));
+    /// ```
+    ///
+    /// [`split_once`]: Self::split_once
+    #[must_use]
+    fn split_once_any<P>(&self, pats: &[P]) -> Option<(&Self, &Self, usize)>
+    where
+        P: Clone + Pattern;
+
+    /// Equivalent to [`split_once`], but only accepts an ASCII byte as the
+    /// separator.
+    ///
+    /// Since ASCII bytes never occur within multi-byte sequences, this method
+    /// can split at the first matching byte without any validation. It never
+    /// allocates and is faster than [`split_once`] for this common case, such
+    /// as when separating command line options from their values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("--color=always");
+    /// assert_eq!(
+    ///     Some((OsStr::new("--color"), OsStr::new("always"))),
+    ///     os_string.split_once_ascii(b'='),
+    /// );
+    /// assert_eq!(None, os_string.split_once_ascii(b':'));
+    /// ```
+    ///
+    /// [`split_once`]: Self::split_once
+    #[must_use]
+    #[track_caller]
+    fn split_once_ascii(&self, byte: u8) -> Option<(&Self, &Self)>;
+
+    /// Divides this string before its trailing ASCII digits, according to
+    /// [`char::is_ascii_digit`].
+    ///
+    /// This method is equivalent to [`split_leading_digits`] but searches
+    /// from the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("-j8");
+    /// assert_eq!(
+    ///     (OsStr::new("-j"), Some("8")),
+    ///     os_string.split_trailing_digits(),
+    /// );
+    ///
+    /// let os_string = OsStr::new("8k");
+    /// assert_eq!((os_string, None), os_string.split_trailing_digits());
+    /// ```
+    ///
+    /// [`split_leading_digits`]: Self::split_leading_digits
+    #[must_use]
+    fn split_trailing_digits(&self) -> (&Self, Option<&str>);
+
+    /// Equivalent to [`split`], but empty substrings can be skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    /// use os_str_bytes::SplitOptions;
+    ///
+    /// let options = SplitOptions::new().skip_trailing_empty(true);
+    /// let os_string = OsStr::new("a/b/c/");
+    /// assert!(os_string.split_with('/', options).eq(["a", "b", "c"]));
+    /// ```
+    ///
+    /// [`split`]: Self::split
+    #[track_caller]
+    fn split_with<P>(&self, pat: P, options: SplitOptions) -> SplitWith<'_, P>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`str::starts_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert!(os_string.starts_with("foo"));
+    /// assert!(!os_string.starts_with("bar"));
+    /// ```
+    #[must_use]
+    fn starts_with<P>(&self, pat: P) -> bool
+    where
+        P: Pattern;
+
+    /// Returns `true` if the first character of this string is alphabetic,
+    /// according to [`char::is_alphabetic`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// assert!(OsStr::new("\u{F6}").starts_with_alphabetic());
+    /// assert!(!OsStr::new("-f").starts_with_alphabetic());
+    /// ```
+    #[must_use]
+    fn starts_with_alphabetic(&self) -> bool;
+
+    /// Equivalent to [`str::starts_with`] but returns `true` if any of the
+    /// patterns match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("--foo");
+    /// assert!(os_string.starts_with_any(&['-', '+']));
+    /// assert!(!os_string.starts_with_any(&['/', '+']));
+    /// ```
+    #[must_use]
+    fn starts_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Clone + Pattern;
+
+    /// Returns `true` if the first character of this string is an ASCII
+    /// digit, according to [`char::is_ascii_digit`].
+    ///
+    /// This method can be used to distinguish negative numbers from short
+    /// options when parsing arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let (_, number) = OsStr::new("-9").split_at(1);
+    /// assert!(number.starts_with_ascii_digit());
+    ///
+    /// let (_, option) = OsStr::new("-f").split_at(1);
+    /// assert!(!option.starts_with_ascii_digit());
+    /// ```
+    #[must_use]
+    fn starts_with_ascii_digit(&self) -> bool;
+
+    if_conversions! {
+        /// Equivalent to [`str::starts_with`] but accepts this type for the
+        /// pattern.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        ///
+        /// use os_str_bytes::OsStrBytesExt;
+        ///
+        /// let os_string = OsStr::new("foobar");
+        /// assert!(os_string.starts_with_os(OsStr::new("foo")));
+        /// assert!(!os_string.starts_with_os(OsStr::new("bar")));
+        /// ```
+        #[cfg_attr(
+            os_str_bytes_docs_rs,
+            doc(cfg(feature = "conversions"))
+        )]
+        #[must_use]
+        fn starts_with_os(&self, pat: &Self) -> bool;
+    }
+
+    /// Removes a leading byte order mark (U+FEFF) from this string, if one is
+    /// present.
+    ///
+    /// Only one mark is removed, since a second would be part of the text.
+    /// For byte strings that have not been converted yet,
+    /// [`detect_text_encoding`] can be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// assert_eq!("foo", OsStr::new("\u{FEFF}foo").strip_bom());
+    /// assert_eq!("foo", OsStr::new("foo").strip_bom());
+    /// ```
+    ///
+    /// [`detect_text_encoding`]: super::detect_text_encoding
+    #[must_use]
+    fn strip_bom(&self) -> &Self;
+
+    /// Removes a prefix and a suffix from this string, if both are present.
+    ///
+    /// The prefix and suffix cannot overlap, so a string consisting of a
+    /// single quote will not be considered quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("[foo]");
+    /// assert_eq!(
+    ///     Some(OsStr::new("foo")),
+    ///     os_string.strip_circumfix('[', ']'),
+    /// );
+    /// assert_eq!(None, os_string.strip_circumfix('[', ')'));
+    /// assert_eq!(None, OsStr::new("\"").strip_circumfix('"', '"'));
+    /// ```
+    #[must_use]
+    fn strip_circumfix<P, S>(&self, prefix: P, suffix: S) -> Option<&Self>
+    where
+        P: Pattern,
+        S: Pattern;
+
+    /// Equivalent to [`str::strip_prefix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("111foo1bar111");
+    /// assert_eq!(
+    ///     Some(OsStr::new("11foo1bar111")),
+    ///     os_string.strip_prefix("1"),
+    /// );
+    /// assert_eq!(None, os_string.strip_prefix("o"));
+    /// ```
+    #[must_use]
+    fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`str::strip_suffix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("111foo1bar111");
+    /// assert_eq!(
+    ///     Some(OsStr::new("111foo1bar11")),
+    ///     os_string.strip_suffix("1"),
+    /// );
+    /// assert_eq!(None, os_string.strip_suffix("o"));
+    /// ```
+    #[must_use]
+    fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`str::trim_end_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("111foo1bar111");
+    /// assert_eq!("111foo1bar", os_string.trim_end_matches("1"));
+    /// assert_eq!("111foo1bar111", os_string.trim_end_matches("o"));
+    /// ```
+    #[must_use]
+    fn trim_end_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern;
+
+    /// Equivalent to [`trim_end_matches`] but also returns the number of
+    /// matches removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("111foo1bar111");
+    /// assert_eq!(
+    ///     (OsStr::new("111foo1bar"), 3),
+    ///     os_string.trim_end_matches_counted("1"),
+    /// );
+    /// assert_eq!(
+    ///     (OsStr::new("111foo1bar111"), 0),
+    ///     os_string.trim_end_matches_counted("o"),
+    /// );
+    /// ```
+    ///
+    /// [`trim_end_matches`]: Self::trim_end_matches
+    #[must_use]
+    fn trim_end_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern;
+
+    /// Equivalent to [`str::trim_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("111foo1bar111");
+    /// assert_eq!("foo1bar", os_string.trim_matches("1"));
+    /// assert_eq!("111foo1bar111", os_string.trim_matches("o"));
+    /// ```
+    #[must_use]
+    fn trim_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern;
+
+    /// Equivalent to [`trim_matches`] but also returns the number of matches
+    /// removed from the start and end of this string, respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("--foo-");
+    /// assert_eq!(
+    ///     (OsStr::new("foo"), 2, 1),
+    ///     os_string.trim_matches_counted('-'),
+    /// );
+    /// ```
+    ///
+    /// [`trim_matches`]: Self::trim_matches
+    #[must_use]
+    fn trim_matches_counted<P>(&self, pat: P) -> (&Self, usize, usize)
+    where
+        P: Pattern;
+
+    /// Removes one pair of matching double or single quotes surrounding
+    /// this string.
+    ///
+    /// The string is returned unchanged if it is not quoted. Only the
+    /// outermost quotes are removed, and escape sequences are not processed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// assert_eq!("foo", OsStr::new("\"foo\"").trim_quotes());
+    /// assert_eq!("\"foo\"", OsStr::new("'\"foo\"'").trim_quotes());
+    /// assert_eq!("\"foo'", OsStr::new("\"foo'").trim_quotes());
+    /// assert_eq!("", OsStr::new("''").trim_quotes());
+    /// assert_eq!("'", OsStr::new("'").trim_quotes());
+    /// ```
+    #[must_use]
+    fn trim_quotes(&self) -> &Self;
+
+    /// Equivalent to [`str::trim_start_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("111foo1bar111");
+    /// assert_eq!("foo1bar111", os_string.trim_start_matches("1"));
+    /// assert_eq!("111foo1bar111", os_string.trim_start_matches("o"));
+    /// ```
+    #[must_use]
+    fn trim_start_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern;
+
+    /// Equivalent to [`trim_start_matches`] but also returns the number of
+    /// matches removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("--foo");
+    /// assert_eq!(
+    ///     (OsStr::new("foo"), 2),
+    ///     os_string.trim_start_matches_counted('-'),
+    /// );
+    /// assert_eq!(
+    ///     (OsStr::new("--foo"), 0),
+    ///     os_string.trim_start_matches_counted('+'),
+    /// );
+    /// ```
+    ///
+    /// [`trim_start_matches`]: Self::trim_start_matches
+    #[must_use]
+    fn trim_start_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern;
+
+    /// Returns the longest prefix of this string that does not exceed a
+    /// length limit.
+    ///
+    /// The length is measured in the given unit, so that limits of
+    /// filesystems using UTF-8 or UTF-16 can both be enforced on any
+    /// platform. The prefix always ends at a [valid boundary], so characters
+    /// and surrogate pairs are never split. For UTF-16, each byte that cannot
+    /// be decoded is counted as a single unit, like by [`len_wide`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::LimitUnit;
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foo\u{1F4A9}bar");
+    /// assert_eq!("foo", os_string.truncate_to_limit(6, LimitUnit::Utf8));
+    /// assert_eq!(
+    ///     "foo\u{1F4A9}",
+    ///     os_string.truncate_to_limit(5, LimitUnit::Utf16),
+    /// );
+    /// ```
+    ///
+    /// [`len_wide`]: Self::len_wide
+    /// [valid boundary]: #indices
+    #[must_use]
+    fn truncate_to_limit(&self, max_units: usize, unit: LimitUnit) -> &Self;
+
+    /// Equivalent to [`rsplit`], but returns an error for empty patterns
+    /// instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert!(os_string.try_rsplit("o")?.eq(["bar", "", "f"]));
+    /// assert!(os_string.try_rsplit("").is_err());
+    /// #
+    /// # Ok::<_, os_str_bytes::EmptyPatternError>(())
+    /// ```
+    ///
+    /// [`rsplit`]: Self::rsplit
+    fn try_rsplit<P>(
+        &self,
+        pat: P,
+    ) -> Result<RSplit<'_, P>, EmptyPatternError>
+    where
+        P: Pattern;
+
+    /// Equivalent to [`split`], but returns an error for empty patterns
+    /// instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("foobar");
+    /// assert!(os_string.try_split("o")?.eq(["f", "", "bar"]));
+    /// assert!(os_string.try_split("").is_err());
+    /// #
+    /// # Ok::<_, os_str_bytes::EmptyPatternError>(())
+    /// ```
+    ///
+    /// [`split`]: Self::split
+    fn try_split<P>(&self, pat: P) -> Result<Split<'_, P>, EmptyPatternError>
+    where
+        P: Pattern;
+
+    /// Returns an iterator over the units of this string.
+    ///
+    /// This method is similar to [`code_points`], but each item describes
+    /// what kind of unit it is. Since [`Unit`] has the same variants on all
+    /// platforms, algorithms can handle each variant without using
+    /// conditional compilation, even if some are never returned on the
+    /// current platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    /// use os_str_bytes::Unit;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}");
+    /// assert!(os_string
+    ///     .units()
+    ///     .eq([Unit::Ascii(b'f'), Unit::Unicode('\u{F6}')]));
+    /// ```
+    ///
+    /// [`code_points`]: Self::code_points
+    fn units(&self) -> Units<'_>;
+
+    /// Splits this string into platform and UTF-8 substrings.
+    ///
+    /// The iterator returned by this method is very similar to
+    /// [`str::Utf8Chunks`]. However, the [`OsStr`] portion of each chunk
+    /// precedes the [`prim@str`] portion and has no length restrictions.
+    ///
+    /// The [`OsStr`] portion of each chunk can be empty only at the start of a
+    /// string, and the [`prim@str`] portion at the end of a string. They will
+    /// never be empty simultaneously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// fn to_str_lossy<F>(os_string: &OsStr, mut push: F)
+    /// where
+    ///     F: FnMut(&str),
+    /// {
+    ///     for (invalid, string) in os_string.utf8_chunks() {
+    ///         if !invalid.as_os_str().is_empty() {
+    ///             push("\u{FFFD}");
+    ///         }
+    ///
+    ///         push(string);
+    ///     }
+    /// }
+    /// ```
+    fn utf8_chunks(&self) -> Utf8Chunks<'_>;
+
+    /// Checks whether an index is a [valid boundary] of this string.
+    ///
+    /// This method is similar to [`str::is_char_boundary`], but it returns
+    /// an error describing the problem for invalid indices. The error uses
+    /// the same message as panics for invalid boundaries, so it can be
+    /// propagated by code that should not panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\u{F6}o");
+    /// assert_eq!(Ok(()), os_string.validate_boundary(1));
+    ///
+    /// let error = os_string.validate_boundary(2).unwrap_err();
+    /// assert_eq!(2, error.index());
+    /// assert_eq!(Some(1..3), error.code_point_range());
+    /// ```
+    ///
+    /// [valid boundary]: #indices
+    fn validate_boundary(&self, index: usize) -> Result<(), BoundaryError>;
+}
+
+impl OsStrBytesExt for OsStr {
+    #[inline]
+    fn byte_len(&self) -> usize {
+        self.as_encoded_bytes().len()
+    }
+
+    #[inline]
+    fn ceil_char_boundary(&self, index: usize) -> usize {
+        let length = self.as_encoded_bytes().len();
+        (index..length)
+            .find(|&x| is_boundary(self, x))
+            .unwrap_or(length)
+    }
+
+    #[inline]
+    fn chunk_by_size(&self, size: usize) -> ChunkBySize<'_> {
+        ChunkBySize::new(self, size)
+    }
+
+    #[inline]
+    fn code_points(&self) -> CodePoints<'_> {
+        CodePoints::new(self)
+    }
+
+    #[inline]
+    fn components_naive<'a>(
+        &'a self,
+        separators: &'a [char],
+    ) -> ComponentsNaive<'a> {
+        ComponentsNaive::new(self, separators)
+    }
+
+    #[inline]
+    fn contains<P>(&self, pat: P) -> bool
+    where
+        P: Pattern,
+    {
+        self.find(pat).is_some()
+    }
+
+    #[inline]
+    fn contains_os(&self, pat: &Self) -> bool {
+        raw::contains(self.as_encoded_bytes(), pat.as_encoded_bytes())
+    }
+
+    if_conversions! {
+        #[inline]
+        fn cross_platform_cmp(&self, other: &Self) -> Ordering {
+            self.code_points().cmp(other.code_points())
+        }
+    }
+
+    #[inline]
+    fn ends_with<P>(&self, pat: P) -> bool
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let pat = pat.__as_bytes();
+
+        self.as_encoded_bytes().ends_with(pat)
+    }
+
+    #[inline]
+    fn ends_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Clone + Pattern,
+    {
+        matches_any_end(self.as_encoded_bytes(), pats, |length, offset| {
+            length - 1 - offset
+        })
+    }
+
+    if_conversions! {
+        #[inline]
+        fn ends_with_os(&self, pat: &Self) -> bool {
+            raw::ends_with(&self.to_raw_bytes(), &pat.to_raw_bytes())
+        }
+    }
+
+    #[inline]
+    fn ensure_prefix<P>(&self, pat: P) -> Cow<'_, Self>
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let pat = pat.__as_str();
+
+        if self.starts_with(pat) {
+            return Cow::Borrowed(self);
+        }
+        let mut string = OsString::with_capacity(pat.len() + self.len());
+        string.push(pat);
+        string.push(self);
+        Cow::Owned(string)
+    }
+
+    #[inline]
+    fn ensure_suffix<P>(&self, pat: P) -> Cow<'_, Self>
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let pat = pat.__as_str();
+
+        if self.ends_with(pat) {
+            return Cow::Borrowed(self);
+        }
+        let mut string = OsString::with_capacity(self.len() + pat.len());
+        string.push(self);
+        string.push(pat);
+        Cow::Owned(string)
+    }
+
+    #[inline]
+    fn eq_constant_time(&self, other: &Self) -> bool {
+        let string = self.as_encoded_bytes();
+        let other = other.as_encoded_bytes();
+
+        // The shorter string is padded, and the difference between the
+        // lengths is included in the result, so that unequal lengths do not
+        // end the comparison early.
+        let length = string.len().max(other.len());
+        let difference =
+            (0..length).fold(string.len() ^ other.len(), |difference, i| {
+                let x = string.get(i).copied().unwrap_or_default();
+                let y = other.get(i).copied().unwrap_or_default();
+                hint::black_box(difference | usize::from(x ^ y))
+            });
+        difference == 0
+    }
+
+    #[inline]
+    fn eq_os_ignoring_trailing_separators(
+        &self,
+        other: &Self,
+        separators: &[char],
+    ) -> bool {
+        trim_separators(self, separators) == trim_separators(other, separators)
+    }
+
+    fn escape_ascii(&self) -> String {
+        let mut escaped = String::with_capacity(self.len());
+        for code_point in self.code_points() {
+            let _ = match code_point {
+                Ok(0x5C) => write!(escaped, r"\\"),
+                Ok(x @ 0x20..=0x7E) => write!(escaped, "{}", x as u8 as char),
+                Ok(x @ ..=0x7F) => write!(escaped, r"\x{:02x}", x),
+                Ok(x) => write!(escaped, r"\u{{{:x}}}", x),
+                Err(x) => write!(escaped, r"\x{:02x}", x),
+            };
+        }
+        escaped
+    }
+
+    #[inline]
+    fn find<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let pat = pat.__as_bytes();
+
+        find(self.as_encoded_bytes(), pat)
+    }
+
+    #[inline]
+    fn find_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize> {
+        check_ascii_set(bytes);
+
+        let string = self.as_encoded_bytes();
+        #[cfg(feature = "memchr")]
+        match *bytes.as_slice() {
+            [] => return None,
+            [x] => return memchr::memchr(x, string),
+            [x, y] => return memchr::memchr2(x, y, string),
+            [x, y, z] => return memchr::memchr3(x, y, z, string),
+            _ => {}
+        }
+        string.iter().position(|x| bytes.contains(x))
+    }
+
+    #[inline]
+    fn find_map_chunks<F, T>(&self, mut f: F) -> Option<T>
+    where
+        F: FnMut(usize, &NonUnicodeOsStr, &str) -> Option<T>,
+    {
+        let mut index = 0;
+        self.utf8_chunks().find_map(|(invalid, string)| {
+            let chunk_index = index;
+            index += invalid.as_os_str().len() + string.len();
+            f(chunk_index, invalid, string)
+        })
+    }
+
+    #[inline]
+    fn fingerprint(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+        const PRIME: u64 = 0x100000001B3;
+
+        // The internal encoding is unspecified, so the representation
+        // described in the documentation is built from stable interfaces.
+        raw::canonical_bytes(self).fold(OFFSET_BASIS, |hash, x| {
+            (hash ^ u64::from(x)).wrapping_mul(PRIME)
+        })
+    }
+
+    #[inline]
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        let length = self.as_encoded_bytes().len();
+        if index >= length {
+            return length;
+        }
+        internal_expect!(
+            (0..=index).rev().find(|&x| is_boundary(self, x)),
+            0,
+            "missing boundary",
+        )
+    }
+
+    #[inline]
+    fn first_char(&self) -> Option<char> {
+        first_char(self.as_encoded_bytes())
+    }
+
+    #[inline]
+    fn get_range<I>(&self, index: I) -> Option<&Self>
+    where
+        I: SliceIndex,
+    {
+        index.__get(self)
+    }
+
+    unchecked_fn! {
+        #[inline]
+        unsafe fn get_unchecked<I>(&self, index: I) -> &Self
+        where
+            I: SliceIndex,
+        {
+            // SAFETY: This method has equivalent safety requirements.
+            unchecked!(index.__get_unchecked(self))
+        }
+    }
+
+    fn has_extension(&self, ext: &str, case_insensitive: bool) -> bool {
+        fn is_separator(byte: &u8) -> bool {
+            byte.is_ascii() && path::is_separator((*byte).into())
+        }
+
+        let ext = ext.strip_prefix('.').unwrap_or(ext).as_bytes();
+        let string = self.as_encoded_bytes();
+        let end = string
+            .iter()
+            .rposition(|x| !is_separator(x))
+            .map_or(0, |x| x + 1);
+        let name = string[..end]
+            .rsplit(is_separator)
+            .next()
+            .unwrap_or_default();
+        if matches!(name, b"." | b"..") || name.len() <= ext.len() + 1 {
+            return false;
+        }
+
+        let (stem, suffix) = name.split_at(name.len() - ext.len());
+        stem.ends_with(b".")
+            && if case_insensitive {
+                suffix.eq_ignore_ascii_case(ext)
+            } else {
+                suffix == ext
+            }
+    }
+
+    #[inline]
+    fn has_invalid_sequences(&self) -> bool {
+        !self.is_unicode()
+    }
+
+    #[inline]
+    fn index<I>(&self, index: I) -> &Self
+    where
+        I: SliceIndex,
+    {
+        index.__index(self)
+    }
+
+    #[inline]
+    fn is_ascii(&self) -> bool {
+        self.as_encoded_bytes().is_ascii()
+    }
+
+    #[inline]
+    fn is_unicode(&self) -> bool {
+        str::from_utf8(self.as_encoded_bytes()).is_ok()
+    }
+
+    #[inline]
+    fn last_char(&self) -> Option<char> {
+        last_char(self.as_encoded_bytes())
+    }
+
+    #[inline]
+    fn len_chars(&self) -> usize {
+        self.code_points().count()
+    }
+
+    if_conversions! {
+        #[inline]
+        fn len_wide(&self) -> usize {
+            self.code_points()
+                .map(|x| match x {
+                    Ok(0x10000..) => 2,
+                    _ => 1,
+                })
+                .sum()
+        }
+    }
+
+    if_conversions! {
+        #[inline]
+        fn nt_case_eq(&self, other: &Self) -> bool {
+            fn upcase(code_point: Result<u32, u8>) -> Result<u32, u8> {
+                code_point.map(|code_point| {
+                    char::from_u32(code_point)
+                        .filter(|_| code_point <= 0xFFFF)
+                        .and_then(|ch| {
+                            let mut upper = ch.to_uppercase();
+                            upper.next().filter(|_| upper.len() == 0)
+                        })
+                        .map(u32::from)
+                        .filter(|&x| x <= 0xFFFF)
+                        .unwrap_or(code_point)
+                })
+            }
+
+            self == other
+                || self
+                    .code_points()
+                    .map(upcase)
+                    .eq(other.code_points().map(upcase))
+        }
+    }
+
+    fn map_utf8<F>(&self, mut f: F) -> Cow<'_, Self>
+    where
+        F: FnMut(&str) -> Cow<'_, str>,
+    {
+        let mut result: Option<OsString> = None;
+        let mut index = 0;
+        for (invalid, string) in self.utf8_chunks() {
+            let invalid = invalid.as_os_str();
+            let mapped = f(string);
+            if let Some(result) = &mut result {
+                result.push(invalid);
+                result.push(&*mapped);
+            } else if mapped != string {
+                let mut owned = OsString::with_capacity(self.len());
+                owned.push(self.index(..index + invalid.len()));
+                owned.push(&*mapped);
+                result = Some(owned);
+            }
+            index += invalid.len() + string.len();
+        }
+        result.map_or(Cow::Borrowed(self), Cow::Owned)
+    }
+
+    #[inline]
+    fn match_indices_os<'a>(&'a self, pat: &'a Self) -> MatchIndicesOs<'a> {
+        MatchIndicesOs::new(self, pat)
+    }
+
+    fn normalize_newlines(&self) -> Cow<'_, Self> {
+        const CRLF: &str = "\r\n";
+
+        if !self.contains(CRLF) {
+            return Cow::Borrowed(self);
+        }
+        let mut string = OsString::with_capacity(self.len());
+        for (i, substring) in self.split(CRLF).enumerate() {
+            if i != 0 {
+                string.push("\n");
+            }
+            string.push(substring);
+        }
+        Cow::Owned(string)
+    }
+
+    #[inline]
+    fn repeat(&self, n: usize) -> Self::Owned {
+        let mut string = OsString::new();
+        string.extend(iter::repeat(self).take(n));
+        string
+    }
+
+    fn replace_prefix(&self, from: &Self, to: &Self) -> Cow<'_, Self> {
+        let Some(suffix) = self
+            .as_encoded_bytes()
+            .strip_prefix(from.as_encoded_bytes())
+        else {
+            return Cow::Borrowed(self);
+        };
+        if !suffix.is_empty() && !is_boundary(self, from.len()) {
+            return Cow::Borrowed(self);
+        }
+
+        let mut string = OsString::with_capacity(to.len() + suffix.len());
+        string.push(to);
+        // SAFETY: This substring starts at a valid boundary.
+        string.push(unchecked!(os_str(suffix)));
+        Cow::Owned(string)
+    }
+
+    #[inline]
+    fn rfind<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let pat = pat.__as_bytes();
+
+        rfind(self.as_encoded_bytes(), pat)
+    }
+
+    #[inline]
+    fn rfind_any_of<const N: usize>(&self, bytes: &[u8; N]) -> Option<usize> {
+        check_ascii_set(bytes);
+
+        let string = self.as_encoded_bytes();
+        #[cfg(feature = "memchr")]
+        match *bytes.as_slice() {
+            [] => return None,
+            [x] => return memchr::memrchr(x, string),
+            [x, y] => return memchr::memrchr2(x, y, string),
+            [x, y, z] => return memchr::memrchr3(x, y, z, string),
+            _ => {}
+        }
+        string.iter().rposition(|x| bytes.contains(x))
+    }
+
+    #[inline]
+    fn rsplit<P>(&self, pat: P) -> RSplit<'_, P>
+    where
+        P: Pattern,
+    {
+        RSplit::new(self, pat)
+    }
+
+    #[inline]
+    fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern,
+    {
+        split_once(self, &pat.__encode(), Self::rfind)
+    }
+
+    #[inline]
+    fn split<P>(&self, pat: P) -> Split<'_, P>
+    where
+        P: Pattern,
+    {
+        Split::new(self, pat)
+    }
+
+    #[inline]
+    fn slice_from(&self, index: usize) -> &Self {
+        self.split_at(self.ceil_char_boundary(index)).1
+    }
+
+    #[inline]
+    fn slice_up_to(&self, index: usize) -> &Self {
+        self.split_at(self.floor_char_boundary(index)).0
+    }
+
+    #[inline]
+    fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        check_bound(self, mid);
+
+        let (prefix, suffix) = self.as_encoded_bytes().split_at(mid);
+        // SAFETY: These substrings were separated by a valid boundary.
+        unchecked!((os_str(prefix), os_str(suffix)))
+    }
+
+    #[inline]
+    fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)> {
+        self.validate_boundary(mid).ok()?;
+
+        let (prefix, suffix) = self.as_encoded_bytes().split_at(mid);
+        // SAFETY: These substrings were separated by a valid boundary.
+        Some(unchecked!((os_str(prefix), os_str(suffix))))
+    }
+
+    #[inline]
+    fn split_first(&self) -> Option<(CharOrInvalid<'_>, &Self)> {
+        let string = self.as_encoded_bytes();
+        first_unit(string).map(|unit| {
+            let (prefix, suffix) =
+                string.split_at(unit.map_or_else(|x| x, char::len_utf8));
+            // SAFETY: These substrings were separated by a character or a
+            // complete invalid sequence.
+            unchecked!((CharOrInvalid::new(unit, prefix), os_str(suffix)))
+        })
+    }
+
+    #[inline]
+    fn split_indices<P>(&self, pat: P) -> SplitIndices<'_, P>
+    where
+        P: Pattern,
+    {
+        SplitIndices::new(self, pat)
+    }
+
+    #[inline]
+    fn split_keep<P>(&self, pat: P) -> SplitKeep<'_, P>
+    where
+        P: Pattern,
+    {
+        SplitKeep::new(self, pat)
+    }
+
+    #[inline]
+    fn split_last(&self) -> Option<(CharOrInvalid<'_>, &Self)> {
+        let string = self.as_encoded_bytes();
+        last_unit(string).map(|unit| {
+            let (prefix, suffix) = string.split_at(
+                string.len() - unit.map_or_else(|x| x, char::len_utf8),
+            );
+            // SAFETY: These substrings were separated by a character or a
+            // complete invalid sequence.
+            unchecked!((CharOrInvalid::new(unit, suffix), os_str(prefix)))
+        })
+    }
+
+    #[inline]
+    fn split_leading_digits(&self) -> (Option<&str>, &Self) {
+        let string = self.as_encoded_bytes();
+        let index = string
+            .iter()
+            .position(|x| !x.is_ascii_digit())
+            .unwrap_or(string.len());
+        if index == 0 {
+            return (None, self);
+        }
+
+        let (digits, suffix) = string.split_at(index);
+        // SAFETY: These substrings were separated by an ASCII digit.
+        unchecked!((Some(unchecked::utf8(digits)), os_str(suffix)))
+    }
+
+    #[inline]
+    fn split_nul(&self) -> SplitWith<'_, char> {
+        self.split_with('\0', SplitOptions::new().skip_trailing_empty(true))
+    }
+
+    #[inline]
+    fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern,
+    {
+        split_once(self, &pat.__encode(), Self::find)
+    }
+
+    #[inline]
+    fn split_once_any<P>(&self, pats: &[P]) -> Option<(&Self, &Self, usize)>
+    where
+        P: Clone + Pattern,
+    {
+        let string = self.as_encoded_bytes();
+        // Patterns are encoded again at each index instead of being
+        // collected, since encoding is cheap and collecting would allocate.
+        let (index, pat_index, pat_length) =
+            (0..=string.len()).find_map(|index| {
+                let suffix = &string[index..];
+                pats.iter().enumerate().find_map(|(pat_index, pat)| {
+                    let pat = pat.clone().__encode();
+                    let pat = pat.__as_bytes();
+                    suffix.starts_with(pat).then_some((
+                        index,
+                        pat_index,
+                        pat.len(),
+                    ))
+                })
+            })?;
+
+        let prefix = &string[..index];
+        let suffix = &string[index + pat_length..];
+        // SAFETY: These substrings were separated by a UTF-8 string.
+        Some(unchecked!((os_str(prefix), os_str(suffix), pat_index)))
+    }
+
+    #[inline]
+    fn split_once_ascii(&self, byte: u8) -> Option<(&Self, &Self)> {
+        assert!(byte.is_ascii(), "byte is not ASCII: {:#04x}", byte);
+
+        let string = self.as_encoded_bytes();
+        #[cfg(feature = "memchr")]
+        let index = memchr::memchr(byte, string)?;
+        #[cfg(not(feature = "memchr"))]
+        let index = string.iter().position(|&x| x == byte)?;
+
+        let prefix = &string[..index];
+        let suffix = &string[index + 1..];
+        // SAFETY: These substrings were separated by an ASCII byte.
+        Some(unchecked!((os_str(prefix), os_str(suffix))))
+    }
+
+    #[inline]
+    fn split_trailing_digits(&self) -> (&Self, Option<&str>) {
+        let string = self.as_encoded_bytes();
+        let index = string
+            .iter()
+            .rposition(|x| !x.is_ascii_digit())
+            .map_or(0, |x| x + 1);
+        if index == string.len() {
+            return (self, None);
+        }
+
+        let (prefix, digits) = string.split_at(index);
+        // SAFETY: These substrings were separated by an ASCII digit.
+        unchecked!((os_str(prefix), Some(unchecked::utf8(digits))))
+    }
+
+    #[inline]
+    fn split_with<P>(&self, pat: P, options: SplitOptions) -> SplitWith<'_, P>
+    where
+        P: Pattern,
+    {
+        SplitWith::new(self, pat, options)
+    }
+
+    #[inline]
+    fn starts_with<P>(&self, pat: P) -> bool
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let pat = pat.__as_bytes();
+
+        self.as_encoded_bytes().starts_with(pat)
+    }
+
+    #[inline]
+    fn starts_with_alphabetic(&self) -> bool {
+        self.first_char().is_some_and(char::is_alphabetic)
+    }
+
+    #[inline]
+    fn starts_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Clone + Pattern,
+    {
+        matches_any_end(self.as_encoded_bytes(), pats, |_, offset| offset)
+    }
+
+    #[inline]
+    fn starts_with_ascii_digit(&self) -> bool {
+        self.as_encoded_bytes()
+            .first()
+            .is_some_and(u8::is_ascii_digit)
+    }
+
+    if_conversions! {
+        #[inline]
+        fn starts_with_os(&self, pat: &Self) -> bool {
+            raw::starts_with(&self.to_raw_bytes(), &pat.to_raw_bytes())
+        }
+    }
+
+    #[inline]
+    fn strip_bom(&self) -> &Self {
+        self.strip_prefix('\u{FEFF}').unwrap_or(self)
+    }
+
+    #[inline]
+    fn strip_circumfix<P, S>(&self, prefix: P, suffix: S) -> Option<&Self>
+    where
+        P: Pattern,
+        S: Pattern,
+    {
+        self.strip_prefix(prefix)?.strip_suffix(suffix)
+    }
+
+    #[inline]
+    fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let pat = pat.__as_bytes();
+
+        // SAFETY: This substring was separated by a UTF-8 string.
+        self.as_encoded_bytes()
+            .strip_prefix(pat)
+            .map(|x| unchecked!(os_str(x)))
+    }
+
+    #[inline]
+    fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
         let pat = pat.__as_bytes();
 
-        find(self.as_encoded_bytes(), pat)
+        // SAFETY: This substring was separated by a UTF-8 string.
+        self.as_encoded_bytes()
+            .strip_suffix(pat)
+            .map(|x| unchecked!(os_str(x)))
+    }
+
+    #[inline]
+    fn trim_end_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        self.trim_end_matches_counted(pat).0
+    }
+
+    #[inline]
+    fn trim_end_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern,
+    {
+        trim_end_matches(self, &pat.__encode())
+    }
+
+    #[inline]
+    fn trim_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        self.trim_matches_counted(pat).0
+    }
+
+    #[inline]
+    fn trim_matches_counted<P>(&self, pat: P) -> (&Self, usize, usize)
+    where
+        P: Pattern,
+    {
+        let pat = pat.__encode();
+        let (string, start_count) = trim_start_matches(self, &pat);
+        let (string, end_count) = trim_end_matches(string, &pat);
+        (string, start_count, end_count)
+    }
+
+    #[inline]
+    fn trim_quotes(&self) -> &Self {
+        ['"', '\'']
+            .into_iter()
+            .find_map(|x| self.strip_circumfix(x, x))
+            .unwrap_or(self)
+    }
+
+    #[inline]
+    fn trim_start_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        self.trim_start_matches_counted(pat).0
+    }
+
+    #[inline]
+    fn trim_start_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern,
+    {
+        trim_start_matches(self, &pat.__encode())
+    }
+
+    #[inline]
+    fn truncate_to_limit(&self, max_units: usize, unit: LimitUnit) -> &Self {
+        let string = self.as_encoded_bytes();
+        let mut index = match unit {
+            LimitUnit::Utf8 => max_units.min(string.len()),
+            LimitUnit::Utf16 => {
+                let mut units = 0;
+                let mut index = 0;
+                for code_point in self.code_points() {
+                    let (code_point_units, length) = match code_point {
+                        Ok(..=0x7F) | Err(_) => (1, 1),
+                        Ok(..=0x7FF) => (1, 2),
+                        Ok(..=0xFFFF) => (1, 3),
+                        Ok(_) => (2, 4),
+                    };
+                    units += code_point_units;
+                    if units > max_units {
+                        break;
+                    }
+                    index += length;
+                }
+                index
+            }
+        };
+        while index < string.len() && !is_boundary(self, index) {
+            index -= 1;
+        }
+        // SAFETY: This index was validated to be a boundary.
+        unchecked!(os_str(&string[..index]))
+    }
+
+    #[inline]
+    fn try_rsplit<P>(&self, pat: P) -> Result<RSplit<'_, P>, EmptyPatternError>
+    where
+        P: Pattern,
+    {
+        RSplit::try_new(self, pat)
+    }
+
+    #[inline]
+    fn try_split<P>(&self, pat: P) -> Result<Split<'_, P>, EmptyPatternError>
+    where
+        P: Pattern,
+    {
+        Split::try_new(self, pat)
+    }
+
+    #[inline]
+    fn units(&self) -> Units<'_> {
+        Units::new(self)
+    }
+
+    #[inline]
+    fn utf8_chunks(&self) -> Utf8Chunks<'_> {
+        Utf8Chunks::new(self)
+    }
+
+    #[inline]
+    fn validate_boundary(&self, index: usize) -> Result<(), BoundaryError> {
+        let length = self.as_encoded_bytes().len();
+        if index == length || (index < length && is_boundary(self, index)) {
+            Ok(())
+        } else {
+            Err(BoundaryError::new(self, index))
+        }
+    }
+}
+
+unchecked_fn! {
+    unsafe fn modify_os_string<F>(string: &mut OsString, f: F)
+    where
+        F: FnOnce(&mut Vec<u8>),
+    {
+        let mut bytes = mem::take(string).into_encoded_bytes();
+        f(&mut bytes);
+        // SAFETY: This function has equivalent safety requirements.
+        *string = unchecked!(unchecked::os_string(bytes));
+    }
+}
+
+/// An extension trait providing additional methods to [`OsString`].
+///
+/// These methods modify strings in place, which avoids the allocations
+/// necessary to convert to and from [`RawOsString`].
+///
+/// [`RawOsString`]: super::RawOsString
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+pub trait OsStringBytesExt: OsStringBytes {
+    /// Removes all content if this string is not valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::OsStringBytesExt;
+    ///
+    /// let mut os_string = OsString::from("foobar");
+    /// os_string.clear_non_unicode();
+    /// assert_eq!("foobar", os_string);
+    /// ```
+    fn clear_non_unicode(&mut self);
+
+    /// Equivalent to [`OsStrBytesExt::utf8_chunks`], but takes ownership of
+    /// this string.
+    ///
+    /// Each chunk is copied when it is yielded, so the iterator can be sent
+    /// to another thread without keeping the original string alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    /// use std::thread;
+    ///
+    /// use os_str_bytes::OsStringBytesExt;
+    ///
+    /// let chunks = OsString::from("foobar").into_utf8_chunks();
+    /// let chunks: Vec<_> = thread::spawn(|| chunks.collect())
+    ///     .join()
+    ///     .unwrap();
+    /// assert_eq!([(OsString::new(), "foobar".to_owned())], *chunks);
+    /// ```
+    fn into_utf8_chunks(self) -> IntoUtf8Chunks;
+
+    /// Appends a byte string to this string, if it is [IO-safe].
+    ///
+    /// The byte string is validated and appended in place, without creating
+    /// a temporary [`OsString`]. On Unix, the string is never validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves this string unchanged if the byte string
+    /// is not IO-safe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::OsStringBytesExt;
+    ///
+    /// let mut os_string = OsString::from("foo");
+    /// os_string.push_io_bytes(b"bar")?;
+    /// assert_eq!("foobar", os_string);
+    /// #
+    /// # Ok::<_, os_str_bytes::IoEncodingError>(())
+    /// ```
+    ///
+    /// [IO-safe]: super#user-input
+    fn push_io_bytes(&mut self, string: &[u8]) -> Result<(), IoEncodingError>;
+
+    /// Removes all sequences that are not valid Unicode from this string.
+    ///
+    /// After calling this method, [`OsStr::to_str`] will always succeed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::OsStringBytesExt;
+    ///
+    /// let mut os_string = OsString::from("foobar");
+    /// os_string.retain_unicode();
+    /// assert_eq!("foobar", os_string);
+    /// ```
+    fn retain_unicode(&mut self);
+
+    /// Equivalent to [`String::truncate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not a [valid boundary].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::OsStringBytesExt;
+    ///
+    /// let mut os_string = OsString::from("foobar");
+    /// os_string.truncate_at_boundary(3);
+    /// assert_eq!("foo", os_string);
+    /// ```
+    ///
+    /// [valid boundary]: OsStrBytesExt#indices
+    #[track_caller]
+    fn truncate_at_boundary(&mut self, new_len: usize);
+
+    /// Reverses the escaping performed by [`OsStrBytesExt::escape_ascii`].
+    ///
+    /// Each backslash must begin one of the following escape sequences:
+    /// - `\\` - a backslash
+    /// - `\xNN` - an ASCII character, or on platforms where strings can
+    ///   contain arbitrary bytes, any byte
+    /// - `\u{N}` through `\u{NNNNNN}` - a Unicode scalar value, or on
+    ///   platforms where strings are represented using potentially ill-formed
+    ///   UTF-16, any surrogate code point
+    ///
+    /// Other characters are copied unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an escape sequence is invalid or cannot be
+    /// represented on this platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    /// use os_str_bytes::OsStringBytesExt;
+    ///
+    /// let os_string = OsStr::new("f\\\u{F6}\n");
+    /// let escaped = os_string.escape_ascii();
+    /// let unescaped = OsString::unescape_ascii(&escaped);
+    /// assert_eq!(Ok(os_string), unescaped.as_deref());
+    /// ```
+    fn unescape_ascii(string: &str) -> Result<Self, UnescapeError>;
+}
+
+impl OsStringBytesExt for OsString {
+    #[inline]
+    fn clear_non_unicode(&mut self) {
+        if self.to_str().is_none() {
+            self.clear();
+        }
+    }
+
+    #[inline]
+    fn into_utf8_chunks(self) -> IntoUtf8Chunks {
+        IntoUtf8Chunks::new(self)
+    }
+
+    #[inline]
+    fn push_io_bytes(&mut self, string: &[u8]) -> Result<(), IoEncodingError> {
+        let string =
+            OsStr::from_io_bytes(string).ok_or(IoEncodingError::new())?;
+        self.push(string);
+        Ok(())
+    }
+
+    fn retain_unicode(&mut self) {
+        if self.to_str().is_some() {
+            return;
+        }
+
+        // SAFETY: Only UTF-8 substrings are retained.
+        unchecked!(modify_os_string(self, |string| {
+            let mut length = 0;
+            let mut index = 0;
+            while index < string.len() {
+                let (valid_length, invalid_length) =
+                    match str::from_utf8(&string[index..]) {
+                        Ok(substring) => (substring.len(), 0),
+                        Err(error) => {
+                            let valid_length = error.valid_up_to();
+                            (
+                                valid_length,
+                                error.error_len().unwrap_or(
+                                    string.len() - index - valid_length,
+                                ),
+                            )
+                        }
+                    };
+                string.copy_within(index..index + valid_length, length);
+                length += valid_length;
+                index += valid_length + invalid_length;
+            }
+            string.truncate(length);
+        }));
+    }
+
+    #[inline]
+    fn truncate_at_boundary(&mut self, new_len: usize) {
+        check_bound(self, new_len);
+
+        // SAFETY: The index was validated to be a boundary.
+        unchecked!(modify_os_string(self, |string| string.truncate(new_len)));
+    }
+
+    fn unescape_ascii(string: &str) -> Result<Self, UnescapeError> {
+        let mut unescaped = Self::with_capacity(string.len());
+        let mut suffix = string;
+        while let Some(index) = suffix.find('\\') {
+            unescaped.push(&suffix[..index]);
+            let sequence = &suffix[index + 1..];
+            let error = || UnescapeError {
+                index: string.len() - sequence.len() - 1,
+            };
+
+            let (unit, length) = match sequence.as_bytes().first() {
+                Some(b'\\') => (Ok(0x5C), 1),
+                Some(b'x') => {
+                    let byte = sequence
+                        .get(1..3)
+                        .and_then(parse_hex)
+                        .ok_or_else(error)?;
+                    (Err(byte as u8), 3)
+                }
+                Some(b'u') => {
+                    let end = sequence
+                        .strip_prefix("u{")
+                        .and_then(|x| x.find('}'))
+                        .filter(|&x| x <= 6)
+                        .ok_or_else(error)?;
+                    let code_point =
+                        parse_hex(&sequence[2..2 + end]).ok_or_else(error)?;
+                    (Ok(code_point), end + 3)
+                }
+                _ => return Err(error()),
+            };
+            if !push_unescaped(&mut unescaped, unit) {
+                return Err(error());
+            }
+            suffix = &sequence[length..];
+        }
+        unescaped.push(suffix);
+        Ok(unescaped)
+    }
+}
+
+if_unix_ext! {
+    /// An extension trait providing methods that search for arbitrary bytes.
+    ///
+    /// On these platforms, [`OsStr`] can contain any byte sequence, so
+    /// splitting it at any index always produces valid strings. Thus, these
+    /// methods accept patterns that are not valid UTF-8, such as marker bytes
+    /// used by binary formats.
+    ///
+    /// This trait is not available on Windows, UEFI, or WebAssembly without
+    /// an operating system.
+    #[cfg_attr(
+        os_str_bytes_docs_rs,
+        doc(cfg(all(
+            feature = "raw_os_str",
+            not(any(
+                all(target_family = "wasm", target_os = "unknown"),
+                target_os = "uefi",
+                windows,
+            )),
+        )))
+    )]
+    pub trait OsStrBytesUnixExt: OsStrBytesExt {
+        /// Equivalent to [`OsStrBytesExt::contains`] but accepts byte
+        /// patterns.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"foo\xFFbar");
+        /// assert!(os_string.contains_bytes(b"o\xFF"));
+        /// assert!(!os_string.contains_bytes(b"\xFE"));
+        /// ```
+        #[must_use]
+        fn contains_bytes<P>(&self, pat: P) -> bool
+        where
+            P: BytePattern;
+
+        /// Returns the index of the first occurrence of a byte.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"foo\xFFbar");
+        /// assert_eq!(Some(3), os_string.find_byte(0xFF));
+        /// assert_eq!(None, os_string.find_byte(0xFE));
+        /// ```
+        #[must_use]
+        fn find_byte(&self, byte: u8) -> Option<usize>;
+
+        /// Equivalent to [`OsStrBytesExt::find`] but accepts byte patterns.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"foo\xFFbar\xFF");
+        /// assert_eq!(Some(3), os_string.find_bytes(b"\xFF"));
+        /// assert_eq!(None, os_string.find_bytes(b"\xFE"));
+        /// ```
+        #[must_use]
+        fn find_bytes<P>(&self, pat: P) -> Option<usize>
+        where
+            P: BytePattern;
+
+        /// Equivalent to [`OsStrBytesExt::rfind`] but accepts byte patterns.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"foo\xFFbar\xFF");
+        /// assert_eq!(Some(7), os_string.rfind_bytes(b"\xFF"));
+        /// assert_eq!(None, os_string.rfind_bytes(b"\xFE"));
+        /// ```
+        #[must_use]
+        fn rfind_bytes<P>(&self, pat: P) -> Option<usize>
+        where
+            P: BytePattern;
+
+        /// Splits this string by a byte.
+        ///
+        /// This method is a shorthand for calling [`split_bytes`] with a
+        /// single byte.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"foo\xFFbar");
+        /// assert!(os_string.split_byte(0xFF).eq(["foo", "bar"]));
+        /// ```
+        ///
+        /// [`split_bytes`]: Self::split_bytes
+        fn split_byte(&self, byte: u8) -> SplitBytes<'_, u8>;
+
+        /// Equivalent to [`OsStrBytesExt::split`] but accepts byte patterns.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the pattern is empty.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"foo\xFF\xFEbar");
+        /// assert!(os_string.split_bytes(b"\xFF\xFE").eq(["foo", "bar"]));
+        /// ```
+        #[track_caller]
+        fn split_bytes<P>(&self, pat: P) -> SplitBytes<'_, P>
+        where
+            P: BytePattern;
+
+        /// Equivalent to [`OsStrBytesExt::strip_prefix`] but accepts byte
+        /// patterns.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"\xFFfoo");
+        /// assert_eq!(
+        ///     Some(OsStr::new("foo")),
+        ///     os_string.strip_prefix_bytes(0xFF),
+        /// );
+        /// assert_eq!(None, os_string.strip_prefix_bytes(0xFE));
+        /// ```
+        #[must_use]
+        fn strip_prefix_bytes<P>(&self, pat: P) -> Option<&Self>
+        where
+            P: BytePattern;
+
+        /// Equivalent to [`OsStrBytesExt::strip_suffix`] but accepts byte
+        /// patterns.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::os::unix::ffi::OsStrExt;
+        ///
+        /// use os_str_bytes::OsStrBytesUnixExt;
+        ///
+        /// let os_string = OsStr::from_bytes(b"foo\xFF");
+        /// assert_eq!(
+        ///     Some(OsStr::new("foo")),
+        ///     os_string.strip_suffix_bytes(0xFF),
+        /// );
+        /// assert_eq!(None, os_string.strip_suffix_bytes(0xFE));
+        /// ```
+        #[must_use]
+        fn strip_suffix_bytes<P>(&self, pat: P) -> Option<&Self>
+        where
+            P: BytePattern;
+    }
+
+    impl OsStrBytesUnixExt for OsStr {
+        #[inline]
+        fn contains_bytes<P>(&self, pat: P) -> bool
+        where
+            P: BytePattern,
+        {
+            self.find_bytes(pat).is_some()
+        }
+
+        #[inline]
+        fn find_byte(&self, byte: u8) -> Option<usize> {
+            let string = self.as_encoded_bytes();
+
+            #[cfg(feature = "memchr")]
+            {
+                memchr::memchr(byte, string)
+            }
+            #[cfg(not(feature = "memchr"))]
+            {
+                string.iter().position(|&x| x == byte)
+            }
+        }
+
+        #[inline]
+        fn find_bytes<P>(&self, pat: P) -> Option<usize>
+        where
+            P: BytePattern,
+        {
+            find(self.as_encoded_bytes(), pat.__encode().as_ref())
+        }
+
+        #[inline]
+        fn rfind_bytes<P>(&self, pat: P) -> Option<usize>
+        where
+            P: BytePattern,
+        {
+            rfind(self.as_encoded_bytes(), pat.__encode().as_ref())
+        }
+
+        #[inline]
+        fn split_byte(&self, byte: u8) -> SplitBytes<'_, u8> {
+            SplitBytes::new(self, byte)
+        }
+
+        #[inline]
+        fn split_bytes<P>(&self, pat: P) -> SplitBytes<'_, P>
+        where
+            P: BytePattern,
+        {
+            SplitBytes::new(self, pat)
+        }
+
+        #[inline]
+        fn strip_prefix_bytes<P>(&self, pat: P) -> Option<&Self>
+        where
+            P: BytePattern,
+        {
+            self.as_encoded_bytes()
+                .strip_prefix(pat.__encode().as_ref())
+                .map(Self::from_bytes)
+        }
+
+        #[inline]
+        fn strip_suffix_bytes<P>(&self, pat: P) -> Option<&Self>
+        where
+            P: BytePattern,
+        {
+            self.as_encoded_bytes()
+                .strip_suffix(pat.__encode().as_ref())
+                .map(Self::from_bytes)
+        }
+    }
+}
+
+if_windows_ext! {
+    /// An extension trait providing methods that inspect unpaired surrogates.
+    ///
+    /// On these platforms, [`OsStr`] can contain ill-formed UTF-16, which
+    /// cannot be represented by many filesystems or formats. These methods
+    /// allow detecting and replacing the code units that cause such strings to
+    /// be invalid Unicode.
+    #[cfg_attr(
+        os_str_bytes_docs_rs,
+        doc(cfg(all(
+            feature = "raw_os_str",
+            any(target_os = "uefi", windows),
+        )))
+    )]
+    pub trait OsStrBytesWindowsExt: OsStrBytesExt {
+        /// Returns `true` if this string contains any unpaired surrogates.
+        ///
+        /// Since these are the only code units that can be invalid on these
+        /// platforms, this method is equivalent to checking whether
+        /// [`OsStr::to_str`] fails.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use std::ffi::OsString;
+        /// use std::os::windows::ffi::OsStringExt;
+        ///
+        /// use os_str_bytes::OsStrBytesWindowsExt;
+        ///
+        /// assert!(!OsStr::new("foo").has_unpaired_surrogates());
+        ///
+        /// let os_string = OsString::from_wide(&[0x66, 0xD83D, 0x6F]);
+        /// assert!(os_string.has_unpaired_surrogates());
+        /// ```
+        #[must_use]
+        fn has_unpaired_surrogates(&self) -> bool;
+
+        /// Replaces each unpaired surrogate in this string with a character.
+        ///
+        /// The string is only copied if it contains any unpaired surrogates.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsString;
+        /// use std::os::windows::ffi::OsStringExt;
+        ///
+        /// use os_str_bytes::OsStrBytesWindowsExt;
+        ///
+        /// let os_string = OsString::from_wide(&[0x66, 0xD83D, 0x6F]);
+        /// assert_eq!("f_o", &*os_string.replace_unpaired_surrogates('_'));
+        /// ```
+        #[must_use]
+        fn replace_unpaired_surrogates(&self, replacement: char)
+            -> Cow<'_, Self>;
+
+        /// Returns an iterator over the byte indices of unpaired surrogates in
+        /// this string.
+        ///
+        /// Each index is a [valid boundary], and the surrogate can be obtained
+        /// using [`OsStrBytesExt::split_first`] on the suffix starting at it.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsString;
+        /// use std::os::windows::ffi::OsStringExt;
+        ///
+        /// use os_str_bytes::OsStrBytesWindowsExt;
+        ///
+        /// let os_string = OsString::from_wide(&[0x66, 0xD83D, 0x6F, 0xDCA9]);
+        /// assert!(os_string.surrogate_positions().eq([1, 5]));
+        /// ```
+        ///
+        /// [valid boundary]: OsStrBytesExt#indices
+        fn surrogate_positions(&self) -> SurrogatePositions<'_>;
+    }
+
+    impl OsStrBytesWindowsExt for OsStr {
+        #[inline]
+        fn has_unpaired_surrogates(&self) -> bool {
+            self.to_str().is_none()
+        }
+
+        #[inline]
+        fn replace_unpaired_surrogates(
+            &self,
+            replacement: char,
+        ) -> Cow<'_, Self> {
+            if !self.has_unpaired_surrogates() {
+                return Cow::Borrowed(self);
+            }
+
+            let mut string = self.as_encoded_bytes();
+            let mut result = String::with_capacity(string.len());
+            loop {
+                match str::from_utf8(string) {
+                    Ok(suffix) => {
+                        result.push_str(suffix);
+                        break;
+                    }
+                    Err(error) => {
+                        let (valid, suffix) =
+                            string.split_at(error.valid_up_to());
+                        // SAFETY: This prefix was validated as UTF-8.
+                        result.push_str(unchecked!(unchecked::utf8(valid)));
+                        result.push(replacement);
+                        string = &suffix[SURROGATE_LENGTH..];
+                    }
+                }
+            }
+            Cow::Owned(result.into())
+        }
+
+        #[inline]
+        fn surrogate_positions(&self) -> SurrogatePositions<'_> {
+            SurrogatePositions::new(self)
+        }
+    }
+}
+
+/// Allows a type to be used for slicing by [`OsStrBytesExt`] and
+/// [`RawOsStr`].
+///
+/// This trait is very similar to [`std::slice::SliceIndex`], but its methods
+/// are private and it is implemented for different types. Each index must be
+/// a [valid boundary], so generic functions bounded by this trait will have
+/// the same requirements as the methods they call.
+///
+/// Ranges select the substring that they contain. A [`usize`] selects the
+/// single character or invalid sequence that begins at that index, like
+/// [`OsStrBytesExt::split_first`] would return it.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::OsStrBytesExt;
+/// use os_str_bytes::SliceIndex;
+///
+/// fn get_or_empty<I>(os_string: &OsStr, index: I) -> &OsStr
+/// where
+///     I: SliceIndex,
+/// {
+///     os_string.get_range(index).unwrap_or_default()
+/// }
+///
+/// let os_string = OsStr::new("f\u{F6}o");
+/// assert_eq!("\u{F6}", get_or_empty(os_string, 1));
+/// assert_eq!("\u{F6}o", get_or_empty(os_string, 1..));
+/// assert_eq!("", get_or_empty(os_string, 2..));
+/// ```
+///
+/// [`RawOsStr`]: super::RawOsStr
+/// [valid boundary]: OsStrBytesExt#indices
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+pub trait SliceIndex: private::Sealed {
+    #[doc(hidden)]
+    fn __get(self, string: &OsStr) -> Option<&OsStr>;
+
+    unchecked_fn! {
+        #[doc(hidden)]
+        unsafe fn __get_unchecked(self, string: &OsStr) -> &OsStr;
     }
 
-    #[inline]
-    unsafe fn get_unchecked<I>(&self, index: I) -> &Self
-    where
-        I: SliceIndex,
-    {
-        // SAFETY: This method has equivalent safety requirements.
-        unsafe { index.get_unchecked(self) }
+    #[doc(hidden)]
+    fn __index(self, string: &OsStr) -> &OsStr;
+
+    unchecked_fn! {
+        // The string must use the internal encoding of [OsStr].
+        #[doc(hidden)]
+        unsafe fn __index_mut(self, string: &mut [u8]) -> &mut [u8];
     }
+}
+
+macro_rules! r#impl {
+    ( $type:ty $(, $var:ident , $($bound:expr),+)? ) => {
+        impl SliceIndex for $type {
+            #[inline]
+            fn __get(self, string: &OsStr) -> Option<&OsStr> {
+                $(
+                    let $var = &self;
+                    if !($(is_bound(string, $bound))&&+) {
+                        return None;
+                    }
+                )?
+
+                let string = string.as_encoded_bytes().get(self)?;
+                // SAFETY: This substring is separated by valid boundaries.
+                Some(unchecked!(os_str(string)))
+            }
+
+            unchecked_fn! {
+                #[inline]
+                unsafe fn __get_unchecked(self, string: &OsStr) -> &OsStr {
+                    let string = string.as_encoded_bytes();
+                    #[cfg(feature = "forbid_unsafe")]
+                    let string = &string[self];
+                    // SAFETY: This method has equivalent safety requirements.
+                    #[cfg(not(feature = "forbid_unsafe"))]
+                    let string = unsafe { string.get_unchecked(self) };
+                    // SAFETY: This method has equivalent safety requirements.
+                    unchecked!(os_str(string))
+                }
+            }
+
+            #[inline]
+            fn __index(self, string: &OsStr) -> &OsStr {
+                $(
+                    let $var = &self;
+                    $(check_bound(string, $bound);)+
+                )?
+
+                // SAFETY: This substring is separated by valid boundaries.
+                unchecked!(os_str(&string.as_encoded_bytes()[self]))
+            }
+
+            unchecked_fn! {
+                #[inline]
+                unsafe fn __index_mut(self, string: &mut [u8]) -> &mut [u8] {
+                    $(
+                        let $var = &self;
+                        // SAFETY: This method has equivalent safety
+                        // requirements.
+                        let os_string = unchecked!(os_str(string));
+                        $(check_bound(os_string, $bound);)+
+                    )?
+
+                    &mut string[self]
+                }
+            }
+        }
+    };
+}
+r#impl!(Range<usize>, x, x.start, x.end);
+r#impl!(RangeFrom<usize>, x, x.start);
+r#impl!(RangeFull);
+// [usize::MAX] will always be a valid inclusive end index.
+#[rustfmt::skip]
+r#impl!(RangeInclusive<usize>, x, *x.start(), x.end().wrapping_add(1));
+r#impl!(RangeTo<usize>, x, x.end);
+r#impl!(RangeToInclusive<usize>, x, x.end.wrapping_add(1));
 
+// Returns the range of the unit that begins at a valid boundary.
+fn unit_range(string: &[u8], index: usize) -> Option<Range<usize>> {
+    let length =
+        first_unit(&string[index..])?.map_or_else(|x| x, char::len_utf8);
+    Some(index..index + length)
+}
+
+impl SliceIndex for usize {
     #[inline]
-    fn index<I>(&self, index: I) -> &Self
-    where
-        I: SliceIndex,
-    {
-        index.index(self)
+    fn __get(self, string: &OsStr) -> Option<&OsStr> {
+        if self >= string.len() || !is_boundary(string, self) {
+            return None;
+        }
+        unit_range(string.as_encoded_bytes(), self)?.__get(string)
     }
 
-    #[inline]
-    fn repeat(&self, n: usize) -> Self::Owned {
-        let mut string = OsString::new();
-        string.extend(iter::repeat(self).take(n));
-        string
+    unchecked_fn! {
+        #[inline]
+        unsafe fn __get_unchecked(self, string: &OsStr) -> &OsStr {
+            let range = unit_range(string.as_encoded_bytes(), self)
+                .expect("index out of bounds");
+            // SAFETY: This method has equivalent safety requirements.
+            unchecked!(range.__get_unchecked(string))
+        }
     }
 
     #[inline]
-    fn rfind<P>(&self, pat: P) -> Option<usize>
-    where
-        P: Pattern,
-    {
-        let pat = pat.__encode();
-        let pat = pat.__as_bytes();
+    #[track_caller]
+    fn __index(self, string: &OsStr) -> &OsStr {
+        check_bound(string, self);
+        let range = unit_range(string.as_encoded_bytes(), self)
+            .expect("index out of bounds");
+        range.__index(string)
+    }
 
-        rfind(self.as_encoded_bytes(), pat)
+    unchecked_fn! {
+        #[inline]
+        #[track_caller]
+        unsafe fn __index_mut(self, string: &mut [u8]) -> &mut [u8] {
+            // SAFETY: This method has equivalent safety requirements.
+            check_bound(unchecked!(os_str(string)), self);
+            let range =
+                unit_range(string, self).expect("index out of bounds");
+            // SAFETY: This method has equivalent safety requirements.
+            unchecked!(range.__index_mut(string))
+        }
     }
+}
 
-    #[inline]
-    fn rsplit<P>(&self, pat: P) -> RSplit<'_, P>
-    where
-        P: Pattern,
-    {
-        RSplit::new(self, pat)
+/// The error returned by [`OsStrBytesExt::validate_boundary`].
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoundaryError {
+    index: usize,
+    code_point_range: Option<Range<usize>>,
+}
+
+impl BoundaryError {
+    fn new(string: &OsStr, index: usize) -> Self {
+        let string = string.as_encoded_bytes();
+        Self {
+            index,
+            code_point_range: (index < string.len())
+                .then(|| code_point_range(string, index))
+                .flatten(),
+        }
     }
 
+    /// Returns the index that was not a valid boundary.
     #[inline]
-    fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
-    where
-        P: Pattern,
-    {
-        split_once(self, &pat.__encode(), Self::rfind)
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
     }
 
+    /// Returns the range of the code point that contains the index.
+    ///
+    /// [`None`] is returned when the index is out of bounds or is not part of
+    /// a valid code point, such as when it is inside a sequence of bytes that
+    /// cannot be decoded on Unix.
     #[inline]
-    fn split<P>(&self, pat: P) -> Split<'_, P>
-    where
-        P: Pattern,
-    {
-        Split::new(self, pat)
+    #[must_use]
+    pub fn code_point_range(&self) -> Option<Range<usize>> {
+        self.code_point_range.clone()
     }
+}
 
+impl Display for BoundaryError {
     #[inline]
-    fn split_at(&self, mid: usize) -> (&Self, &Self) {
-        check_bound(self, mid);
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "byte index {} is not a valid boundary", self.index)
+    }
+}
 
-        let (prefix, suffix) = self.as_encoded_bytes().split_at(mid);
-        // SAFETY: These substrings were separated by a valid boundary.
-        unsafe { (os_str(prefix), os_str(suffix)) }
+impl Error for BoundaryError {}
+
+/// The error returned by [`OsStrBytesExt::try_split`] and
+/// [`OsStrBytesExt::try_rsplit`] when the pattern is empty.
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EmptyPatternError(());
+
+impl EmptyPatternError {
+    pub(super) const fn new() -> Self {
+        Self(())
     }
+}
 
+impl Display for EmptyPatternError {
     #[inline]
-    fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
-    where
-        P: Pattern,
-    {
-        split_once(self, &pat.__encode(), Self::find)
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot split using an empty pattern")
     }
+}
 
-    #[inline]
-    fn starts_with<P>(&self, pat: P) -> bool
-    where
-        P: Pattern,
-    {
-        let pat = pat.__encode();
-        let pat = pat.__as_bytes();
+impl Error for EmptyPatternError {}
 
-        self.as_encoded_bytes().starts_with(pat)
-    }
+/// The error returned by [`OsStringBytesExt::unescape_ascii`].
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnescapeError {
+    index: usize,
+}
 
-    if_conversions! {
-        #[inline]
-        fn starts_with_os(&self, pat: &Self) -> bool {
-            raw::starts_with(&self.to_raw_bytes(), &pat.to_raw_bytes())
-        }
+impl UnescapeError {
+    /// Returns the byte index of the backslash that begins the invalid
+    /// escape sequence.
+    #[inline]
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
     }
+}
 
+impl Display for UnescapeError {
     #[inline]
-    fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
-    where
-        P: Pattern,
-    {
-        let pat = pat.__encode();
-        let pat = pat.__as_bytes();
-
-        // SAFETY: This substring was separated by a UTF-8 string.
-        self.as_encoded_bytes()
-            .strip_prefix(pat)
-            .map(|x| unsafe { os_str(x) })
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid escape sequence at byte index {}", self.index)
     }
+}
 
-    #[inline]
-    fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
-    where
-        P: Pattern,
-    {
-        let pat = pat.__encode();
-        let pat = pat.__as_bytes();
+impl Error for UnescapeError {}
 
-        // SAFETY: This substring was separated by a UTF-8 string.
-        self.as_encoded_bytes()
-            .strip_suffix(pat)
-            .map(|x| unsafe { os_str(x) })
-    }
+/// Options for [`OsStrBytesExt::split_with`].
+///
+/// By default, no substrings are skipped, so splitting behaves like
+/// [`OsStrBytesExt::split`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+/// use std::path::Path;
+///
+/// use os_str_bytes::OsStrBytesExt;
+/// use os_str_bytes::SplitOptions;
+///
+/// let path = "a/b//c/";
+/// let options = SplitOptions::new().skip_empty(true);
+/// assert_eq!(
+///     Path::new(path).components().count(),
+///     OsStr::new(path).split_with('/', options).count(),
+/// );
+/// ```
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[must_use]
+pub struct SplitOptions {
+    pub(super) skip_empty: bool,
+    pub(super) skip_leading_empty: bool,
+    pub(super) skip_trailing_empty: bool,
+}
 
+impl SplitOptions {
+    /// Creates options that do not skip any substrings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::SplitOptions;
+    ///
+    /// assert_eq!(SplitOptions::default(), SplitOptions::new());
+    /// ```
     #[inline]
-    fn trim_end_matches<P>(&self, pat: P) -> &Self
-    where
-        P: Pattern,
-    {
-        trim_end_matches(self, &pat.__encode())
+    pub const fn new() -> Self {
+        Self {
+            skip_empty: false,
+            skip_leading_empty: false,
+            skip_trailing_empty: false,
+        }
     }
 
+    /// Sets whether all empty substrings should be skipped.
+    ///
+    /// Consecutive separators are then treated as one, which is how
+    /// [`Path::components`] handles them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    /// use os_str_bytes::SplitOptions;
+    ///
+    /// let options = SplitOptions::new().skip_empty(true);
+    /// let os_string = OsStr::new("/foo//bar/");
+    /// assert!(os_string.split_with('/', options).eq(["foo", "bar"]));
+    /// ```
+    ///
+    /// [`Path::components`]: ::std::path::Path::components
     #[inline]
-    fn trim_matches<P>(&self, pat: P) -> &Self
-    where
-        P: Pattern,
-    {
-        let pat = pat.__encode();
-        trim_end_matches(trim_start_matches(self, &pat), &pat)
+    pub const fn skip_empty(mut self, skip_empty: bool) -> Self {
+        self.skip_empty = skip_empty;
+        self
     }
 
+    /// Sets whether the first substring should be skipped if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    /// use os_str_bytes::SplitOptions;
+    ///
+    /// let options = SplitOptions::new().skip_leading_empty(true);
+    /// let os_string = OsStr::new("/foo//bar/");
+    /// assert!(os_string.split_with('/', options).eq(["foo", "", "bar", ""]));
+    /// ```
     #[inline]
-    fn trim_start_matches<P>(&self, pat: P) -> &Self
-    where
-        P: Pattern,
-    {
-        trim_start_matches(self, &pat.__encode())
+    pub const fn skip_leading_empty(
+        mut self,
+        skip_leading_empty: bool,
+    ) -> Self {
+        self.skip_leading_empty = skip_leading_empty;
+        self
     }
 
+    /// Sets whether the last substring should be skipped if it is empty.
+    ///
+    /// This option makes splitting equivalent to [`str::split_terminator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    /// use os_str_bytes::SplitOptions;
+    ///
+    /// let options = SplitOptions::new().skip_trailing_empty(true);
+    /// let os_string = OsStr::new("/foo//bar/");
+    /// assert!(os_string.split_with('/', options).eq(["", "foo", "", "bar"]));
+    /// ```
     #[inline]
-    fn utf8_chunks(&self) -> Utf8Chunks<'_> {
-        Utf8Chunks::new(self)
+    pub const fn skip_trailing_empty(
+        mut self,
+        skip_trailing_empty: bool,
+    ) -> Self {
+        self.skip_trailing_empty = skip_trailing_empty;
+        self
     }
 }
 
-pub trait SliceIndex {
-    unsafe fn get_unchecked(self, string: &OsStr) -> &OsStr;
+/// The unit used by [`OsStrBytesExt::truncate_to_limit`] to measure length.
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LimitUnit {
+    /// Bytes in the UTF-8 encoding.
+    ///
+    /// On Windows and UEFI, unpaired surrogates are counted as 3 bytes, as
+    /// in WTF-8. On other platforms, the length of the string is always used.
+    Utf8,
 
-    fn index(self, string: &OsStr) -> &OsStr;
+    /// Code units in the UTF-16 encoding.
+    Utf16,
 }
 
-macro_rules! r#impl {
-    ( $type:ty $(, $var:ident , $($bound:expr),+)? ) => {
-        impl SliceIndex for $type {
-            #[inline]
-            unsafe fn get_unchecked(self, string: &OsStr) -> &OsStr {
-                // SAFETY: This method has equivalent safety requirements.
-                unsafe {
-                    os_str(string.as_encoded_bytes().get_unchecked(self))
-                }
-            }
-
-            #[inline]
-            fn index(self, string: &OsStr) -> &OsStr {
-                $(
-                    let $var = &self;
-                    $(check_bound(string, $bound);)+
-                )?
+/// A character or a non-Unicode sequence of a platform string.
+///
+/// Instances are returned by [`OsStrBytesExt::split_first`] and similar
+/// methods.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+pub enum CharOrInvalid<'a> {
+    /// A Unicode character.
+    Char(char),
+    /// A sequence containing no Unicode characters.
+    Invalid(&'a NonUnicodeOsStr),
+}
 
-                // SAFETY: This substring is separated by valid boundaries.
-                unsafe { os_str(&string.as_encoded_bytes()[self]) }
+impl<'a> CharOrInvalid<'a> {
+    unchecked_fn! {
+        unsafe fn new(unit: Result<char, usize>, string: &'a [u8]) -> Self {
+            match unit {
+                Ok(ch) => Self::Char(ch),
+                // SAFETY: This method has equivalent safety requirements.
+                Err(_) => Self::Invalid(unchecked!(
+                    NonUnicodeOsStr::new_unchecked(string)
+                )),
             }
         }
-    };
+    }
+}
+
+/// A unit of a platform string.
+///
+/// Instances are returned by [`OsStrBytesExt::units`]. Every variant exists
+/// on all platforms, but [`Invalid`] is only returned on Unix and other
+/// platforms where strings can contain arbitrary bytes, and [`Surrogate`] is
+/// only returned on Windows and UEFI.
+///
+/// [`Invalid`]: Self::Invalid
+/// [`Surrogate`]: Self::Surrogate
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+pub enum Unit<'a> {
+    /// An ASCII character.
+    Ascii(u8),
+    /// A Unicode character that is not ASCII.
+    Unicode(char),
+    /// A sequence of bytes that is not valid UTF-8.
+    ///
+    /// Each sequence is as long as [`Utf8Error::error_len`], so
+    /// [`String::from_utf8_lossy`] would replace it with a single
+    /// replacement character.
+    ///
+    /// [`Utf8Error::error_len`]: str::Utf8Error::error_len
+    Invalid(&'a [u8]),
+    /// An unpaired surrogate.
+    Surrogate(u16),
 }
-r#impl!(Range<usize>, x, x.start, x.end);
-r#impl!(RangeFrom<usize>, x, x.start);
-r#impl!(RangeFull);
-// [usize::MAX] will always be a valid inclusive end index.
-#[rustfmt::skip]
-r#impl!(RangeInclusive<usize>, x, *x.start(), x.end().wrapping_add(1));
-r#impl!(RangeTo<usize>, x, x.end);
-r#impl!(RangeToInclusive<usize>, x, x.end.wrapping_add(1));
 
 /// A container for platform strings containing no unicode characters.
 ///
-/// Instances can only be constructed using [`Utf8Chunks`].
-#[derive(Debug)]
+/// Instances can only be constructed using [`Utf8Chunks`] and similar
+/// iterators.
+#[derive(Debug, Eq, PartialEq)]
 #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
 #[repr(transparent)]
 pub struct NonUnicodeOsStr(OsStr);
 
 impl NonUnicodeOsStr {
-    unsafe fn from_inner(string: &OsStr) -> &Self {
-        // SAFETY: This struct has a layout that makes this operation safe.
-        unsafe { mem::transmute(string) }
-    }
-
-    pub(super) unsafe fn new_unchecked(string: &[u8]) -> &Self {
-        // SAFETY: This method has stricter safety requirements.
-        unsafe { Self::from_inner(os_str(string)) }
+    unchecked_fn! {
+        pub(super) unsafe fn new_unchecked(string: &[u8]) -> &Self {
+            // SAFETY: This method has equivalent safety requirements.
+            unchecked::non_unicode_os_str(unchecked!(os_str(string)))
+        }
     }
 
     /// Converts this representation back to a platform-native string, without
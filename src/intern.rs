@@ -0,0 +1,291 @@
+//! Interning of platform strings.
+//!
+//! Programs that process many paths, such as directory walkers, often see
+//! the same names repeatedly. An [`OsStrInterner`] stores a single copy of
+//! each distinct string and returns a [`Symbol`] for it, which can be cloned
+//! and compared in constant time.
+//!
+//! # Examples
+//!
+//! ```
+//! use os_str_bytes::intern::OsStrInterner;
+//!
+//! let mut interner = OsStrInterner::new();
+//! let foo = interner.intern("foo");
+//! let bar = interner.intern("bar");
+//!
+//! assert_ne!(foo, bar);
+//! assert_eq!(foo, interner.intern("foo"));
+//! assert_eq!(2, interner.len());
+//! ```
+
+#![cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use super::RawOsStr;
+
+// Unlike [Symbol], this type compares strings by content, which allows
+// looking up entries using a borrowed string.
+#[derive(Eq, Hash, PartialEq)]
+struct Entry(Arc<RawOsStr>);
+
+impl Borrow<RawOsStr> for Entry {
+    fn borrow(&self) -> &RawOsStr {
+        &self.0
+    }
+}
+
+/// A string stored by an [`OsStrInterner`].
+///
+/// Symbols are compared and hashed by identity, so these operations do not
+/// depend on the length of the string. Symbols created by different
+/// interners are never equal, even if their strings are.
+///
+/// The string can be accessed using dereferencing.
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::intern::OsStrInterner;
+///
+/// let mut interner = OsStrInterner::new();
+/// let symbol = interner.intern("foo");
+/// assert_eq!("foo", symbol.as_raw_str());
+/// ```
+#[derive(Clone)]
+pub struct Symbol(Arc<RawOsStr>);
+
+impl Symbol {
+    /// Returns the interned string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::intern::OsStrInterner;
+    ///
+    /// let mut interner = OsStrInterner::new();
+    /// let symbol = interner.intern("foo");
+    /// assert_eq!(OsStr::new("foo"), symbol.as_os_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_os_str(&self) -> &OsStr {
+        self.0.as_os_str()
+    }
+
+    /// Returns the interned string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::intern::OsStrInterner;
+    ///
+    /// let mut interner = OsStrInterner::new();
+    /// let symbol = interner.intern("foo");
+    /// assert_eq!("foo", symbol.as_raw_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_raw_str(&self) -> &RawOsStr {
+        &self.0
+    }
+}
+
+impl AsRef<OsStr> for Symbol {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl AsRef<RawOsStr> for Symbol {
+    #[inline]
+    fn as_ref(&self) -> &RawOsStr {
+        self.as_raw_str()
+    }
+}
+
+impl Debug for Symbol {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Symbol").field(&self.as_raw_str()).finish()
+    }
+}
+
+impl Deref for Symbol {
+    type Target = RawOsStr;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_raw_str()
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        Arc::as_ptr(&self.0).cast::<u8>().hash(state);
+    }
+}
+
+impl PartialEq for Symbol {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A pool of deduplicated platform strings.
+///
+/// Each distinct string is allocated once, when it is first interned. Later
+/// calls to [`intern`] for the same string do not allocate.
+///
+/// [`intern`]: Self::intern
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::intern::OsStrInterner;
+///
+/// let mut interner = OsStrInterner::new();
+/// for name in ["foo.rs", "bar.rs", "foo.rs"] {
+///     let _ = interner.intern(name);
+/// }
+/// assert_eq!(2, interner.len());
+/// ```
+#[derive(Default)]
+pub struct OsStrInterner {
+    strings: HashSet<Entry>,
+}
+
+impl OsStrInterner {
+    /// Creates an empty interner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::intern::OsStrInterner;
+    ///
+    /// let interner = OsStrInterner::new();
+    /// assert!(interner.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the symbol for a string, if it has been interned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::intern::OsStrInterner;
+    ///
+    /// let mut interner = OsStrInterner::new();
+    /// let symbol = interner.intern("foo");
+    /// assert_eq!(Some(symbol), interner.get("foo"));
+    /// assert_eq!(None, interner.get("bar"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get<S>(&self, string: &S) -> Option<Symbol>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        self.strings
+            .get(RawOsStr::new(string))
+            .map(|x| Symbol(Arc::clone(&x.0)))
+    }
+
+    /// Returns the symbol for a string, interning it if necessary.
+    ///
+    /// The string is only copied if it has not already been interned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::intern::OsStrInterner;
+    ///
+    /// let mut interner = OsStrInterner::new();
+    /// let symbol = interner.intern("foo");
+    /// assert_eq!(symbol, interner.intern("foo"));
+    /// ```
+    #[must_use]
+    pub fn intern<S>(&mut self, string: &S) -> Symbol
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        if let Some(symbol) = self.get(string) {
+            return symbol;
+        }
+
+        let string: Arc<RawOsStr> =
+            RawOsStr::new(string).to_owned().into_box().into();
+        let _ = self.strings.insert(Entry(Arc::clone(&string)));
+        Symbol(string)
+    }
+
+    /// Returns `true` if no strings have been interned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::intern::OsStrInterner;
+    ///
+    /// let mut interner = OsStrInterner::new();
+    /// assert!(interner.is_empty());
+    /// let _ = interner.intern("foo");
+    /// assert!(!interner.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns the number of distinct strings that have been interned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::intern::OsStrInterner;
+    ///
+    /// let mut interner = OsStrInterner::new();
+    /// let _ = interner.intern("foo");
+    /// let _ = interner.intern("foo");
+    /// assert_eq!(1, interner.len());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+}
+
+impl Debug for OsStrInterner {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.strings.iter().map(|x| &*x.0))
+            .finish()
+    }
+}
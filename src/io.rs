@@ -0,0 +1,87 @@
+//! Reading platform strings from streams.
+//!
+//! Records are converted using [`OsStringBytes::from_io_vec`], so they must
+//! be [IO-safe].
+//!
+//! [IO-safe]: super#user-input
+
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::ffi::OsString;
+use std::io;
+use std::io::BufRead;
+use std::io::ErrorKind;
+
+use super::IoEncodingError;
+use super::OsStringBytes;
+
+/// The iterator returned by [`read_nul_delimited`].
+#[derive(Debug)]
+#[must_use]
+pub struct ReadNulDelimited<R> {
+    reader: R,
+}
+
+impl<R> ReadNulDelimited<R> {
+    /// Returns the underlying reader.
+    ///
+    /// Bytes of the next record may already have been read into its buffer.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> Iterator for ReadNulDelimited<R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<OsString>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = Vec::new();
+        match self.reader.read_until(b'\0', &mut record) {
+            Ok(0) => None,
+            Ok(_) => {
+                if record.last() == Some(&b'\0') {
+                    let _ = record.pop();
+                }
+                Some(OsString::from_io_vec(record).ok_or_else(|| {
+                    io::Error::new(
+                        ErrorKind::InvalidData,
+                        IoEncodingError::new(),
+                    )
+                }))
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+/// Returns an iterator over records terminated by NUL characters, such as the
+/// output of `find -print0`.
+///
+/// The final record does not need to be terminated. Errors are returned for
+/// records that are not IO-safe, but iteration can continue after them, in
+/// the same way as for [`BufRead::split`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+///
+/// use os_str_bytes::io::read_nul_delimited;
+///
+/// let records: Vec<_> =
+///     read_nul_delimited(&b"foo\0bar\0"[..]).collect::<io::Result<_>>()?;
+/// assert_eq!(["foo", "bar"], &*records);
+/// #
+/// # Ok::<_, io::Error>(())
+/// ```
+#[inline]
+pub fn read_nul_delimited<R>(reader: R) -> ReadNulDelimited<R>
+where
+    R: BufRead,
+{
+    ReadNulDelimited { reader }
+}
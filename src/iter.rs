@@ -1,21 +1,36 @@
 //! Iterators provided by this crate.
 
 #![cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
 
 use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::iter::FusedIterator;
 use std::mem;
 use std::str;
+use std::str::Utf8Error;
 
 use super::ext;
+use super::imp::raw;
 use super::pattern::Encoded;
+use super::unchecked;
+use super::util::MAX_UTF8_LENGTH;
+use super::BoundaryError;
+use super::CharOrInvalid;
+use super::EmptyPatternError;
 use super::NonUnicodeOsStr;
 use super::OsStrBytesExt;
 use super::Pattern;
 use super::RawOsStr;
+use super::SplitOptions;
+use super::Unit;
+
+if_windows_ext! {
+    use super::imp::SURROGATE_LENGTH;
+}
 
 macro_rules! r#impl {
     (
@@ -23,6 +38,7 @@ macro_rules! r#impl {
         $(#[ $raw_attr:meta ])* $raw_name:ident ,
         $split_method:ident ,
         $reverse:expr ,
+        $side:literal ,
     ) => {
         // [memchr::memmem::FindIter] would make this struct self-referential.
         #[must_use]
@@ -33,6 +49,7 @@ macro_rules! r#impl {
         {
             string: Option<&'a OsStr>,
             pat: P::__Encoded,
+            offset: usize,
         }
 
         impl<'a, P> $name<'a, P>
@@ -41,15 +58,91 @@ macro_rules! r#impl {
         {
             #[track_caller]
             pub(super) fn new(string: &'a OsStr, pat: P) -> Self {
+                match Self::try_new(string, pat) {
+                    Ok(split) => split,
+                    Err(error) => panic!("{}", error),
+                }
+            }
+
+            pub(super) fn try_new(
+                string: &'a OsStr,
+                pat: P,
+            ) -> Result<Self, EmptyPatternError> {
                 let pat = pat.__encode();
-                assert!(
-                    !pat.__as_str().is_empty(),
-                    "cannot split using an empty pattern",
-                );
-                Self {
+                if pat.__as_str().is_empty() {
+                    return Err(EmptyPatternError::new());
+                }
+                Ok(Self {
                     string: Some(string),
                     pat,
-                }
+                    offset: if $reverse { string.len() } else { 0 },
+                })
+            }
+
+            #[doc = concat!(
+                "Creates an iterator over the portion of a string ",
+                $side,
+                " an index.",
+            )]
+            ///
+            /// The iterator is equivalent to splitting that portion, but
+            /// [`offset`] will continue to return indices into the full
+            /// string. Therefore, iteration can be resumed from a previously
+            /// returned offset without keeping the iterator.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `offset` is not a [valid boundary].
+            ///
+            /// # Panics
+            ///
+            /// Panics if the pattern is empty.
+            ///
+            /// [`offset`]: Self::offset
+            /// [valid boundary]: super::OsStrBytesExt#indices
+            #[inline]
+            #[track_caller]
+            pub fn with_offset(
+                string: &'a OsStr,
+                pat: P,
+                offset: usize,
+            ) -> Result<Self, BoundaryError> {
+                string.validate_boundary(offset)?;
+                let string = if $reverse {
+                    string.index(..offset)
+                } else {
+                    string.index(offset..)
+                };
+                let mut split = Self::new(string, pat);
+                split.offset = offset;
+                Ok(split)
+            }
+
+            /// Returns the portion of the string that has not been yielded.
+            ///
+            /// Once the iterator is exhausted, an empty string is returned.
+            #[inline]
+            #[must_use]
+            pub fn as_os_str(&self) -> &'a OsStr {
+                self.string.unwrap_or_default()
+            }
+
+            #[doc = concat!(
+                "Returns the index in the string ",
+                $side,
+                " which nothing has been yielded.",
+            )]
+            ///
+            /// This index is the boundary of the string returned by
+            /// [`as_os_str`], so it can be passed to [`with_offset`] to
+            /// resume iteration.
+            ///
+            /// [`as_os_str`]: Self::as_os_str
+            /// [`with_offset`]: Self::with_offset
+            #[inline]
+            #[must_use]
+            pub fn offset(&self) -> usize {
+                self.offset
             }
         }
 
@@ -62,6 +155,7 @@ macro_rules! r#impl {
                 Self {
                     string: self.string,
                     pat: self.pat.clone(),
+                    offset: self.offset,
                 }
             }
         }
@@ -75,6 +169,7 @@ macro_rules! r#impl {
                 f.debug_struct(stringify!($name))
                     .field("string", &self.string)
                     .field("pat", &self.pat)
+                    .field("offset", &self.offset)
                     .finish()
             }
         }
@@ -89,7 +184,9 @@ macro_rules! r#impl {
 
             #[inline]
             fn next(&mut self) -> Option<Self::Item> {
-                self.string?
+                let length = self.string?.len();
+                let substring = self
+                    .string?
                     .$split_method(self.pat.__as_str())
                     .map(|(mut substring, mut string)| {
                         if $reverse {
@@ -98,7 +195,15 @@ macro_rules! r#impl {
                         self.string = Some(string);
                         substring
                     })
-                    .or_else(|| self.string.take())
+                    .or_else(|| self.string.take());
+
+                let consumed = length - self.as_os_str().len();
+                if $reverse {
+                    self.offset -= consumed;
+                } else {
+                    self.offset += consumed;
+                }
+                substring
             }
         }
 
@@ -116,6 +221,50 @@ macro_rules! r#impl {
             pub(super) fn new(string: &'a RawOsStr, pat: P) -> Self {
                 Self($name::new(string.as_os_str(), pat))
             }
+
+            pub(super) fn try_new(
+                string: &'a RawOsStr,
+                pat: P,
+            ) -> Result<Self, EmptyPatternError> {
+                $name::try_new(string.as_os_str(), pat).map(Self)
+            }
+
+            #[doc = concat!(
+                "Equivalent to [`",
+                stringify!($name),
+                "::with_offset`].",
+            )]
+            #[inline]
+            #[track_caller]
+            pub fn with_offset(
+                string: &'a RawOsStr,
+                pat: P,
+                offset: usize,
+            ) -> Result<Self, BoundaryError> {
+                $name::with_offset(string.as_os_str(), pat, offset).map(Self)
+            }
+
+            #[doc = concat!(
+                "Equivalent to [`",
+                stringify!($name),
+                "::as_os_str`].",
+            )]
+            #[inline]
+            #[must_use]
+            pub fn as_raw_str(&self) -> &'a RawOsStr {
+                RawOsStr::new(self.0.as_os_str())
+            }
+
+            #[doc = concat!(
+                "Equivalent to [`",
+                stringify!($name),
+                "::offset`].",
+            )]
+            #[inline]
+            #[must_use]
+            pub fn offset(&self) -> usize {
+                self.0.offset()
+            }
         }
 
         impl<P> Clone for $raw_name<'_, P>
@@ -160,6 +309,7 @@ r#impl!(
     RawSplit,
     split_once,
     false,
+    "after",
 );
 r#impl!(
     /// The iterator returned by [`OsStrBytesExt::rsplit`].
@@ -168,12 +318,1032 @@ r#impl!(
     RawRSplit,
     rsplit_once,
     true,
+    "before",
 );
 
+/// The iterator returned by [`OsStrBytesExt::split_indices`].
+///
+/// [`OsStrBytesExt::split_indices`]: super::OsStrBytesExt::split_indices
+#[must_use]
+pub struct SplitIndices<'a, P>
+where
+    P: Pattern,
+{
+    inner: Split<'a, P>,
+}
+
+impl<'a, P> SplitIndices<'a, P>
+where
+    P: Pattern,
+{
+    #[track_caller]
+    pub(super) fn new(string: &'a OsStr, pat: P) -> Self {
+        Self {
+            inner: Split::new(string, pat),
+        }
+    }
+
+    /// Equivalent to [`Split::with_offset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::iter::SplitIndices;
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("a=1;b=2;c=3");
+    /// let mut split = os_string.split_indices(";");
+    /// assert_eq!(Some((0, OsStr::new("a=1"))), split.next());
+    /// let offset = split.offset();
+    ///
+    /// let split = SplitIndices::with_offset(os_string, ";", offset)?;
+    /// assert!(split.eq([(4, OsStr::new("b=2")), (8, OsStr::new("c=3"))]));
+    /// #
+    /// # Ok::<_, os_str_bytes::BoundaryError>(())
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn with_offset(
+        string: &'a OsStr,
+        pat: P,
+        offset: usize,
+    ) -> Result<Self, BoundaryError> {
+        Split::with_offset(string, pat, offset).map(|inner| Self { inner })
+    }
+
+    /// Equivalent to [`Split::as_os_str`].
+    #[inline]
+    #[must_use]
+    pub fn as_os_str(&self) -> &'a OsStr {
+        self.inner.as_os_str()
+    }
+
+    /// Equivalent to [`Split::offset`].
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.inner.offset()
+    }
+}
+
+impl<P> Clone for SplitIndices<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<P> Debug for SplitIndices<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitIndices")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<P> FusedIterator for SplitIndices<'_, P> where P: Pattern {}
+
+impl<'a, P> Iterator for SplitIndices<'a, P>
+where
+    P: Pattern,
+{
+    type Item = (usize, &'a OsStr);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.inner.offset();
+        self.inner.next().map(|substring| (index, substring))
+    }
+}
+
+/// The iterator returned by [`RawOsStr::split_indices`].
+#[must_use]
+pub struct RawSplitIndices<'a, P>(SplitIndices<'a, P>)
+where
+    P: Pattern;
+
+impl<'a, P> RawSplitIndices<'a, P>
+where
+    P: Pattern,
+{
+    #[track_caller]
+    pub(super) fn new(string: &'a RawOsStr, pat: P) -> Self {
+        Self(SplitIndices::new(string.as_os_str(), pat))
+    }
+
+    /// Equivalent to [`SplitIndices::with_offset`].
+    #[inline]
+    #[track_caller]
+    pub fn with_offset(
+        string: &'a RawOsStr,
+        pat: P,
+        offset: usize,
+    ) -> Result<Self, BoundaryError> {
+        SplitIndices::with_offset(string.as_os_str(), pat, offset).map(Self)
+    }
+
+    /// Equivalent to [`SplitIndices::as_os_str`].
+    #[inline]
+    #[must_use]
+    pub fn as_raw_str(&self) -> &'a RawOsStr {
+        RawOsStr::new(self.0.as_os_str())
+    }
+
+    /// Equivalent to [`SplitIndices::offset`].
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.0.offset()
+    }
+}
+
+impl<P> Clone for RawSplitIndices<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<P> Debug for RawSplitIndices<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawSplitIndices").field(&self.0).finish()
+    }
+}
+
+impl<P> FusedIterator for RawSplitIndices<'_, P> where P: Pattern {}
+
+impl<'a, P> Iterator for RawSplitIndices<'a, P>
+where
+    P: Pattern,
+{
+    type Item = (usize, &'a RawOsStr);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(index, x)| (index, RawOsStr::new(x)))
+    }
+}
+
+/// The iterator returned by [`OsStrBytesExt::split_with`].
+///
+/// [`OsStrBytesExt::split_with`]: super::OsStrBytesExt::split_with
+#[must_use]
+pub struct SplitWith<'a, P>
+where
+    P: Pattern,
+{
+    inner: Split<'a, P>,
+    options: SplitOptions,
+    first: bool,
+}
+
+impl<'a, P> SplitWith<'a, P>
+where
+    P: Pattern,
+{
+    #[track_caller]
+    pub(super) fn new(
+        string: &'a OsStr,
+        pat: P,
+        options: SplitOptions,
+    ) -> Self {
+        Self {
+            inner: Split::new(string, pat),
+            options,
+            first: true,
+        }
+    }
+
+    /// Equivalent to [`Split::as_os_str`].
+    #[inline]
+    #[must_use]
+    pub fn as_os_str(&self) -> &'a OsStr {
+        self.inner.as_os_str()
+    }
+}
+
+impl<P> Clone for SplitWith<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            options: self.options,
+            first: self.first,
+        }
+    }
+}
+
+impl<P> Debug for SplitWith<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitWith")
+            .field("inner", &self.inner)
+            .field("options", &self.options)
+            .field("first", &self.first)
+            .finish()
+    }
+}
+
+impl<P> FusedIterator for SplitWith<'_, P> where P: Pattern {}
+
+impl<'a, P> Iterator for SplitWith<'a, P>
+where
+    P: Pattern,
+{
+    type Item = &'a OsStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let first = mem::replace(&mut self.first, false);
+            let substring = self.inner.next()?;
+            if !substring.is_empty()
+                || !(self.options.skip_empty
+                    || (first && self.options.skip_leading_empty)
+                    || (self.inner.string.is_none()
+                        && self.options.skip_trailing_empty))
+            {
+                return Some(substring);
+            }
+        }
+    }
+}
+
+/// The iterator returned by [`RawOsStr::split_with`].
+#[must_use]
+pub struct RawSplitWith<'a, P>(SplitWith<'a, P>)
+where
+    P: Pattern;
+
+impl<'a, P> RawSplitWith<'a, P>
+where
+    P: Pattern,
+{
+    #[track_caller]
+    pub(super) fn new(
+        string: &'a RawOsStr,
+        pat: P,
+        options: SplitOptions,
+    ) -> Self {
+        Self(SplitWith::new(string.as_os_str(), pat, options))
+    }
+
+    /// Equivalent to [`SplitWith::as_os_str`].
+    #[inline]
+    #[must_use]
+    pub fn as_raw_str(&self) -> &'a RawOsStr {
+        RawOsStr::new(self.0.as_os_str())
+    }
+}
+
+impl<P> Clone for RawSplitWith<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<P> Debug for RawSplitWith<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawSplitWith").field(&self.0).finish()
+    }
+}
+
+impl<P> FusedIterator for RawSplitWith<'_, P> where P: Pattern {}
+
+impl<'a, P> Iterator for RawSplitWith<'a, P>
+where
+    P: Pattern,
+{
+    type Item = &'a RawOsStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(RawOsStr::new)
+    }
+}
+
+/// An item returned by [`SplitKeep`] and [`RawSplitKeep`].
+///
+/// The type parameter is the type of fields, which is [`OsStr`] for
+/// [`SplitKeep`] and [`RawOsStr`] for [`RawSplitKeep`].
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub enum SplitItem<'a, S = OsStr>
+where
+    S: ?Sized,
+{
+    /// A substring between separators.
+    Field(&'a S),
+
+    /// A substring that matched the pattern.
+    Separator(&'a str),
+}
+
+impl<S> Clone for SplitItem<'_, S>
+where
+    S: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for SplitItem<'_, S> where S: ?Sized {}
+
+/// The iterator returned by [`OsStrBytesExt::split_keep`].
+///
+/// [`OsStrBytesExt::split_keep`]: super::OsStrBytesExt::split_keep
+#[must_use]
+pub struct SplitKeep<'a, P>
+where
+    P: Pattern,
+{
+    inner: SplitIndices<'a, P>,
+    string: &'a OsStr,
+    separator: Option<&'a str>,
+}
+
+impl<'a, P> SplitKeep<'a, P>
+where
+    P: Pattern,
+{
+    #[track_caller]
+    pub(super) fn new(string: &'a OsStr, pat: P) -> Self {
+        Self {
+            inner: SplitIndices::new(string, pat),
+            string,
+            separator: None,
+        }
+    }
+}
+
+impl<P> Clone for SplitKeep<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            string: self.string,
+            separator: self.separator,
+        }
+    }
+}
+
+impl<P> Debug for SplitKeep<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitKeep")
+            .field("inner", &self.inner)
+            .field("separator", &self.separator)
+            .finish()
+    }
+}
+
+impl<P> FusedIterator for SplitKeep<'_, P> where P: Pattern {}
+
+impl<'a, P> Iterator for SplitKeep<'a, P>
+where
+    P: Pattern,
+{
+    type Item = SplitItem<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(separator) = self.separator.take() {
+            return Some(SplitItem::Separator(separator));
+        }
+
+        let (index, field) = self.inner.next()?;
+        if self.inner.inner.string.is_some() {
+            let start = index + field.len();
+            let end = start + self.inner.inner.pat.__as_str().len();
+            let separator = &self.string.as_encoded_bytes()[start..end];
+            // SAFETY: This substring matched the pattern, which is UTF-8.
+            self.separator = Some(unchecked!(unchecked::utf8(separator)));
+        }
+        Some(SplitItem::Field(field))
+    }
+}
+
+/// The iterator returned by [`RawOsStr::split_keep`].
+#[must_use]
+pub struct RawSplitKeep<'a, P>(SplitKeep<'a, P>)
+where
+    P: Pattern;
+
+impl<'a, P> RawSplitKeep<'a, P>
+where
+    P: Pattern,
+{
+    #[track_caller]
+    pub(super) fn new(string: &'a RawOsStr, pat: P) -> Self {
+        Self(SplitKeep::new(string.as_os_str(), pat))
+    }
+}
+
+impl<P> Clone for RawSplitKeep<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<P> Debug for RawSplitKeep<'_, P>
+where
+    P: Pattern,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawSplitKeep").field(&self.0).finish()
+    }
+}
+
+impl<P> FusedIterator for RawSplitKeep<'_, P> where P: Pattern {}
+
+impl<'a, P> Iterator for RawSplitKeep<'a, P>
+where
+    P: Pattern,
+{
+    type Item = SplitItem<'a, RawOsStr>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|x| match x {
+            SplitItem::Field(field) => SplitItem::Field(RawOsStr::new(field)),
+            SplitItem::Separator(separator) => SplitItem::Separator(separator),
+        })
+    }
+}
+
+/// The iterator returned by [`OsStrBytesExt::chunk_by_size`].
+///
+/// [`OsStrBytesExt::chunk_by_size`]: super::OsStrBytesExt::chunk_by_size
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ChunkBySize<'a> {
+    string: &'a OsStr,
+    size: usize,
+}
+
+impl<'a> ChunkBySize<'a> {
+    #[track_caller]
+    pub(super) fn new(string: &'a OsStr, size: usize) -> Self {
+        assert_ne!(0, size, "chunk size must be nonzero");
+        Self { string, size }
+    }
+
+    /// Returns the portion of the string that has not been yielded.
+    ///
+    /// Once the iterator is exhausted, an empty string is returned.
+    #[inline]
+    #[must_use]
+    pub fn as_os_str(&self) -> &'a OsStr {
+        self.string
+    }
+}
+
+impl FusedIterator for ChunkBySize<'_> {}
+
+impl<'a> Iterator for ChunkBySize<'a> {
+    type Item = &'a OsStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.string.is_empty() {
+            return None;
+        }
+
+        let mut index = self.string.floor_char_boundary(self.size);
+        if index == 0 {
+            index = self.string.ceil_char_boundary(1);
+        }
+        let (chunk, string) = self.string.split_at(index);
+        self.string = string;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A single chunk can be arbitrarily long, so only its existence is
+        // certain.
+        let length = self.string.len();
+        (usize::from(length != 0), Some(length))
+    }
+}
+
+/// The iterator returned by [`RawOsStr::chunk_by_size`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct RawChunkBySize<'a>(ChunkBySize<'a>);
+
+impl<'a> RawChunkBySize<'a> {
+    #[track_caller]
+    pub(super) fn new(string: &'a RawOsStr, size: usize) -> Self {
+        Self(ChunkBySize::new(string.as_os_str(), size))
+    }
+
+    /// Equivalent to [`ChunkBySize::as_os_str`].
+    #[inline]
+    #[must_use]
+    pub fn as_raw_str(&self) -> &'a RawOsStr {
+        RawOsStr::new(self.0.as_os_str())
+    }
+}
+
+impl FusedIterator for RawChunkBySize<'_> {}
+
+impl<'a> Iterator for RawChunkBySize<'a> {
+    type Item = &'a RawOsStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(RawOsStr::new)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// The iterator returned by [`OsStrBytesExt::components_naive`].
+///
+/// [`OsStrBytesExt::components_naive`]: super::OsStrBytesExt::components_naive
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ComponentsNaive<'a> {
+    string: &'a OsStr,
+    separators: &'a [char],
+}
+
+impl<'a> ComponentsNaive<'a> {
+    pub(super) fn new(string: &'a OsStr, separators: &'a [char]) -> Self {
+        Self { string, separators }
+    }
+
+    fn is_separator(&self, unit: CharOrInvalid<'_>) -> bool {
+        matches!(
+            unit,
+            CharOrInvalid::Char(ch) if self.separators.contains(&ch),
+        )
+    }
+
+    /// Returns the portion of the string that has not been yielded.
+    ///
+    /// Once the iterator is exhausted, an empty string is returned.
+    #[inline]
+    #[must_use]
+    pub fn as_os_str(&self) -> &'a OsStr {
+        self.string
+    }
+}
+
+impl FusedIterator for ComponentsNaive<'_> {}
+
+impl<'a> Iterator for ComponentsNaive<'a> {
+    type Item = &'a OsStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (unit, string) = self.string.split_first()?;
+            if !self.is_separator(unit) {
+                break;
+            }
+            self.string = string;
+        }
+
+        let mut suffix = self.string;
+        while let Some((unit, string)) = suffix.split_first() {
+            if self.is_separator(unit) {
+                break;
+            }
+            suffix = string;
+        }
+        let index = self.string.len() - suffix.len();
+        let (component, string) = self.string.split_at(index);
+        self.string = string;
+        Some(component)
+    }
+}
+
+/// The iterator returned by [`RawOsStr::components_naive`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct RawComponentsNaive<'a>(ComponentsNaive<'a>);
+
+impl<'a> RawComponentsNaive<'a> {
+    pub(super) fn new(string: &'a RawOsStr, separators: &'a [char]) -> Self {
+        Self(ComponentsNaive::new(string.as_os_str(), separators))
+    }
+
+    /// Equivalent to [`ComponentsNaive::as_os_str`].
+    #[inline]
+    #[must_use]
+    pub fn as_raw_str(&self) -> &'a RawOsStr {
+        RawOsStr::new(self.0.as_os_str())
+    }
+}
+
+impl FusedIterator for RawComponentsNaive<'_> {}
+
+impl<'a> Iterator for RawComponentsNaive<'a> {
+    type Item = &'a RawOsStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(RawOsStr::new)
+    }
+}
+
+/// The iterator returned by [`OsStrBytesExt::match_indices_os`].
+///
+/// [`OsStrBytesExt::match_indices_os`]: super::OsStrBytesExt::match_indices_os
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct MatchIndicesOs<'a> {
+    string: &'a OsStr,
+    pat: &'a OsStr,
+    index: usize,
+    overlapping: bool,
+}
+
+impl<'a> MatchIndicesOs<'a> {
+    pub(super) fn new(string: &'a OsStr, pat: &'a OsStr) -> Self {
+        Self {
+            string,
+            pat,
+            index: 0,
+            overlapping: false,
+        }
+    }
+
+    /// Creates an iterator over the matches in the portion of a string after
+    /// an index.
+    ///
+    /// Indices yielded by the iterator and returned by [`offset`] will be
+    /// relative to the full string. Therefore, iteration can be resumed from
+    /// a previously returned offset without keeping the iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `offset` is not a [valid boundary].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::iter::MatchIndicesOs;
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("a=1;b=2;c=3");
+    /// let pat = OsStr::new("=");
+    /// let mut matches = os_string.match_indices_os(pat);
+    /// assert_eq!(Some((1, pat)), matches.next());
+    /// let offset = matches.offset();
+    ///
+    /// let matches = MatchIndicesOs::with_offset(os_string, pat, offset)?;
+    /// assert!(matches.map(|(x, _)| x).eq([5, 9]));
+    /// #
+    /// # Ok::<_, os_str_bytes::BoundaryError>(())
+    /// ```
+    ///
+    /// [`offset`]: Self::offset
+    /// [valid boundary]: super::OsStrBytesExt#indices
+    #[inline]
+    pub fn with_offset(
+        string: &'a OsStr,
+        pat: &'a OsStr,
+        offset: usize,
+    ) -> Result<Self, BoundaryError> {
+        string.validate_boundary(offset)?;
+        let mut matches = Self::new(string, pat);
+        matches.index = offset;
+        Ok(matches)
+    }
+
+    /// Returns the index in the string where the search for the next match
+    /// will start.
+    ///
+    /// This index is always a [valid boundary], so it can be passed to
+    /// [`with_offset`] to resume iteration. Once the iterator is exhausted,
+    /// the length of the string is returned.
+    ///
+    /// [`with_offset`]: Self::with_offset
+    /// [valid boundary]: super::OsStrBytesExt#indices
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.index.min(self.string.len())
+    }
+
+    /// Causes the iterator to also yield matches that overlap previous
+    /// matches.
+    ///
+    /// Each match will then start after the start of the previous match,
+    /// instead of at or after its end. This option makes the iterator take
+    /// `O(n * m)` time in the worst case, where `n` is the length of the
+    /// string and `m` is the length of the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    ///
+    /// let os_string = OsStr::new("aaaa");
+    /// let pat = OsStr::new("aa");
+    /// assert!(os_string.match_indices_os(pat).map(|(x, _)| x).eq([0, 2]));
+    /// assert!(os_string
+    ///     .match_indices_os(pat)
+    ///     .overlapping()
+    ///     .map(|(x, _)| x)
+    ///     .eq([0, 1, 2]));
+    /// ```
+    #[inline]
+    pub fn overlapping(mut self) -> Self {
+        self.overlapping = true;
+        self
+    }
+}
+
+impl FusedIterator for MatchIndicesOs<'_> {}
+
+impl<'a> Iterator for MatchIndicesOs<'a> {
+    type Item = (usize, &'a OsStr);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let string = self.string.as_encoded_bytes();
+        if self.index > string.len() {
+            return None;
+        }
+        let Some(range) =
+            raw::find_os(string, self.pat.as_encoded_bytes(), self.index)
+        else {
+            self.index = usize::MAX;
+            return None;
+        };
+        self.index = if range.is_empty() {
+            let index = range.start + 1;
+            self.string.ceil_char_boundary(index).max(index)
+        } else if self.overlapping {
+            self.string.ceil_char_boundary(range.start + 1)
+        } else {
+            self.string.ceil_char_boundary(range.end)
+        };
+
+        let start = self.string.floor_char_boundary(range.start);
+        let end = self.string.ceil_char_boundary(range.end);
+        Some((start, self.string.index(start..end)))
+    }
+}
+
+/// The iterator returned by [`RawOsStr::match_indices_os`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct RawMatchIndicesOs<'a>(MatchIndicesOs<'a>);
+
+impl<'a> RawMatchIndicesOs<'a> {
+    pub(super) fn new(string: &'a RawOsStr, pat: &'a RawOsStr) -> Self {
+        Self(MatchIndicesOs::new(string.as_os_str(), pat.as_os_str()))
+    }
+
+    /// Equivalent to [`MatchIndicesOs::with_offset`].
+    #[inline]
+    pub fn with_offset(
+        string: &'a RawOsStr,
+        pat: &'a RawOsStr,
+        offset: usize,
+    ) -> Result<Self, BoundaryError> {
+        let string = string.as_os_str();
+        MatchIndicesOs::with_offset(string, pat.as_os_str(), offset).map(Self)
+    }
+
+    /// Equivalent to [`MatchIndicesOs::offset`].
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.0.offset()
+    }
+
+    /// Equivalent to [`MatchIndicesOs::overlapping`].
+    #[inline]
+    pub fn overlapping(self) -> Self {
+        Self(self.0.overlapping())
+    }
+}
+
+impl FusedIterator for RawMatchIndicesOs<'_> {}
+
+impl<'a> Iterator for RawMatchIndicesOs<'a> {
+    type Item = (usize, &'a RawOsStr);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(index, x)| (index, RawOsStr::new(x)))
+    }
+}
+
+/// The iterator returned by [`OsStrBytesExt::code_points`].
+///
+/// [`OsStrBytesExt::code_points`]: super::OsStrBytesExt::code_points
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct CodePoints<'a> {
+    string: &'a [u8],
+}
+
+impl<'a> CodePoints<'a> {
+    pub(super) fn new(string: &'a OsStr) -> Self {
+        Self {
+            string: string.as_encoded_bytes(),
+        }
+    }
+
+    fn next_invalid(&mut self) -> Result<u32, u8> {
+        #[cfg(any(target_os = "uefi", windows))]
+        {
+            use super::util;
+
+            let (surrogate, string) = self.string.split_at(SURROGATE_LENGTH);
+            internal_assert!(surrogate[1..]
+                .iter()
+                .all(|&x| util::is_continuation(x)));
+            self.string = string;
+
+            Ok(surrogate[1..].iter().fold(
+                u32::from(surrogate[0] & 0x0F),
+                |code_point, &byte| {
+                    (code_point << util::BYTE_SHIFT)
+                        | u32::from(byte & util::CONT_MASK)
+                },
+            ))
+        }
+        #[cfg(not(any(target_os = "uefi", windows)))]
+        {
+            let byte = self.string[0];
+            self.string = &self.string[1..];
+            Err(byte)
+        }
+    }
+}
+
+impl FusedIterator for CodePoints<'_> {}
+
+impl Iterator for CodePoints<'_> {
+    type Item = Result<u32, u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &byte = self.string.first()?;
+        if byte.is_ascii() {
+            self.string = &self.string[1..];
+            return Some(Ok(byte.into()));
+        }
+
+        let substring = &self.string[..self.string.len().min(MAX_UTF8_LENGTH)];
+        let valid = match str::from_utf8(substring) {
+            Ok(valid) => valid,
+            // SAFETY: This slice was validated to be UTF-8.
+            Err(error) => {
+                unchecked!(unchecked::utf8(&substring[..error.valid_up_to()]))
+            }
+        };
+        Some(if let Some(ch) = valid.chars().next() {
+            self.string = &self.string[ch.len_utf8()..];
+            Ok(ch.into())
+        } else {
+            self.next_invalid()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.string.len();
+        (length.div_ceil(MAX_UTF8_LENGTH), Some(length))
+    }
+}
+
+/// The iterator returned by [`OsStrBytesExt::units`].
+///
+/// [`OsStrBytesExt::units`]: super::OsStrBytesExt::units
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct Units<'a> {
+    string: &'a [u8],
+}
+
+impl<'a> Units<'a> {
+    pub(super) fn new(string: &'a OsStr) -> Self {
+        Self {
+            string: string.as_encoded_bytes(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "uefi", windows))]
+fn invalid_unit(string: &[u8], _: Utf8Error) -> (usize, Unit<'_>) {
+    use super::util;
+
+    let surrogate = &string[..SURROGATE_LENGTH];
+    internal_assert!(surrogate[1..].iter().all(|&x| util::is_continuation(x)));
+    let surrogate = surrogate[1..].iter().fold(
+        u16::from(surrogate[0] & 0x0F),
+        |code_point, &byte| {
+            (code_point << util::BYTE_SHIFT)
+                | u16::from(byte & util::CONT_MASK)
+        },
+    );
+    (SURROGATE_LENGTH, Unit::Surrogate(surrogate))
+}
+
+#[cfg(not(any(target_os = "uefi", windows)))]
+fn invalid_unit(string: &[u8], error: Utf8Error) -> (usize, Unit<'_>) {
+    let length = error.error_len().unwrap_or(string.len());
+    (length, Unit::Invalid(&string[..length]))
+}
+
+impl FusedIterator for Units<'_> {}
+
+impl<'a> Iterator for Units<'a> {
+    type Item = Unit<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &byte = self.string.first()?;
+        if byte.is_ascii() {
+            self.string = &self.string[1..];
+            return Some(Unit::Ascii(byte));
+        }
+
+        let substring = &self.string[..self.string.len().min(MAX_UTF8_LENGTH)];
+        let (length, unit) = match str::from_utf8(substring) {
+            Err(error) if error.valid_up_to() == 0 => {
+                invalid_unit(substring, error)
+            }
+            result => {
+                let valid = result.unwrap_or_else(|error| {
+                    // SAFETY: This slice was validated to be UTF-8.
+                    unchecked!(unchecked::utf8(
+                        &substring[..error.valid_up_to()],
+                    ))
+                });
+                let ch = internal_expect!(
+                    valid.chars().next(),
+                    return None,
+                    "missing character",
+                );
+                (ch.len_utf8(), Unit::Unicode(ch))
+            }
+        };
+        self.string = &self.string[length..];
+        Some(unit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.string.len();
+        (length.div_ceil(MAX_UTF8_LENGTH), Some(length))
+    }
+}
+
 /// The iterator returned by [`OsStrBytesExt::utf8_chunks`].
 ///
 /// [`OsStrBytesExt::utf8_chunks`]: super::OsStrBytesExt::utf8_chunks
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 #[must_use]
 pub struct Utf8Chunks<'a> {
     string: &'a OsStr,
@@ -197,7 +1367,7 @@ impl<'a> Iterator for Utf8Chunks<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         let string = self.string.as_encoded_bytes();
         if string.is_empty() {
-            debug_assert_eq!(0, self.invalid_length);
+            internal_assert_eq!(0, self.invalid_length);
             return None;
         }
 
@@ -215,24 +1385,232 @@ impl<'a> Iterator for Utf8Chunks<'a> {
                         substring.split_at(error.valid_up_to());
 
                     let invalid_length =
-                        error.error_len().unwrap_or_else(|| substring.len());
+                        error.error_len().unwrap_or(substring.len());
                     if valid.is_empty() {
                         self.invalid_length += invalid_length;
                         continue;
                     }
                     // SAFETY: This substring was separated by a UTF-8 string.
-                    self.string = unsafe { ext::os_str(substring) };
+                    self.string = unchecked!(ext::os_str(substring));
                     self.invalid_length = invalid_length;
 
                     // SAFETY: This slice was validated to be UTF-8.
-                    unsafe { str::from_utf8_unchecked(valid) }
+                    unchecked!(unchecked::utf8(valid))
                 }
             };
 
             // SAFETY: This substring was separated by a UTF-8 string and
             // validated to not be UTF-8.
-            let invalid = unsafe { NonUnicodeOsStr::new_unchecked(invalid) };
+            let invalid = unchecked!(NonUnicodeOsStr::new_unchecked(invalid));
             return Some((invalid, valid));
         }
     }
 }
+
+/// The iterator returned by [`OsStringBytesExt::into_utf8_chunks`].
+///
+/// This iterator is equivalent to [`Utf8Chunks`], but it takes ownership of
+/// the string and yields owned chunks.
+///
+/// [`OsStringBytesExt::into_utf8_chunks`]: super::OsStringBytesExt::into_utf8_chunks
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct IntoUtf8Chunks {
+    string: Vec<u8>,
+    index: usize,
+}
+
+impl IntoUtf8Chunks {
+    pub(super) fn new(string: OsString) -> Self {
+        Self {
+            string: string.into_encoded_bytes(),
+            index: 0,
+        }
+    }
+
+    /// Returns the remainder of the string that has not been yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::OsStringBytesExt;
+    ///
+    /// let mut chunks = OsString::from("foobar").into_utf8_chunks();
+    /// assert_eq!("foobar", chunks.as_os_str());
+    /// let _ = chunks.next();
+    /// assert_eq!("", chunks.as_os_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_os_str(&self) -> &OsStr {
+        // SAFETY: The index is always a valid boundary.
+        unchecked!(ext::os_str(&self.string[self.index..]))
+    }
+}
+
+impl FusedIterator for IntoUtf8Chunks {}
+
+impl Iterator for IntoUtf8Chunks {
+    type Item = (OsString, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (invalid, valid) = self.as_os_str().utf8_chunks().next()?;
+        let invalid = invalid.as_os_str().to_owned();
+        let valid = valid.to_owned();
+        self.index += invalid.len() + valid.len();
+        Some((invalid, valid))
+    }
+}
+
+if_unix_ext! {
+    use super::imp::OsStrExt;
+    use super::pattern::BytePattern;
+
+    /// The iterator returned by [`OsStrBytesUnixExt::split_bytes`].
+    ///
+    /// [`OsStrBytesUnixExt::split_bytes`]: super::OsStrBytesUnixExt::split_bytes
+    #[cfg_attr(
+        os_str_bytes_docs_rs,
+        doc(cfg(all(
+            feature = "raw_os_str",
+            not(any(
+                all(target_family = "wasm", target_os = "unknown"),
+                target_os = "uefi",
+                windows,
+            )),
+        )))
+    )]
+    #[must_use]
+    pub struct SplitBytes<'a, P>
+    where
+        P: BytePattern,
+    {
+        string: Option<&'a [u8]>,
+        pat: P::__Encoded,
+    }
+
+    impl<'a, P> SplitBytes<'a, P>
+    where
+        P: BytePattern,
+    {
+        #[track_caller]
+        pub(super) fn new(string: &'a OsStr, pat: P) -> Self {
+            let pat = pat.__encode();
+            assert!(
+                !pat.as_ref().is_empty(),
+                "cannot split using an empty pattern",
+            );
+            Self {
+                string: Some(string.as_encoded_bytes()),
+                pat,
+            }
+        }
+
+        /// Equivalent to [`Split::as_os_str`].
+        #[inline]
+        #[must_use]
+        pub fn as_os_str(&self) -> &'a OsStr {
+            OsStr::from_bytes(self.string.unwrap_or_default())
+        }
+    }
+
+    impl<P> Clone for SplitBytes<'_, P>
+    where
+        P: BytePattern,
+    {
+        #[inline]
+        fn clone(&self) -> Self {
+            Self {
+                string: self.string,
+                pat: self.pat.clone(),
+            }
+        }
+    }
+
+    impl<P> Debug for SplitBytes<'_, P>
+    where
+        P: BytePattern,
+    {
+        #[inline]
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.debug_struct("SplitBytes")
+                .field("string", &self.string.map(OsStr::from_bytes))
+                .field("pat", &self.pat)
+                .finish()
+        }
+    }
+
+    impl<P> FusedIterator for SplitBytes<'_, P> where P: BytePattern {}
+
+    impl<'a, P> Iterator for SplitBytes<'a, P>
+    where
+        P: BytePattern,
+    {
+        type Item = &'a OsStr;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let string = self.string?;
+            let pat = self.pat.as_ref();
+            let substring = if let Some(index) = ext::find(string, pat) {
+                self.string = Some(&string[index + pat.len()..]);
+                &string[..index]
+            } else {
+                self.string = None;
+                string
+            };
+            Some(OsStr::from_bytes(substring))
+        }
+    }
+}
+
+if_windows_ext! {
+    /// The iterator returned by
+    /// [`OsStrBytesWindowsExt::surrogate_positions`].
+    ///
+    /// [`OsStrBytesWindowsExt::surrogate_positions`]: super::OsStrBytesWindowsExt::surrogate_positions
+    #[cfg_attr(
+        os_str_bytes_docs_rs,
+        doc(cfg(all(
+            feature = "raw_os_str",
+            any(target_os = "uefi", windows),
+        )))
+    )]
+    #[derive(Clone, Debug, Default)]
+    #[must_use]
+    pub struct SurrogatePositions<'a> {
+        string: &'a [u8],
+        index: usize,
+    }
+
+    impl<'a> SurrogatePositions<'a> {
+        pub(super) fn new(string: &'a OsStr) -> Self {
+            Self {
+                string: string.as_encoded_bytes(),
+                index: 0,
+            }
+        }
+    }
+
+    impl FusedIterator for SurrogatePositions<'_> {}
+
+    impl Iterator for SurrogatePositions<'_> {
+        type Item = usize;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let Err(error) = str::from_utf8(self.string) else {
+                self.string = &[];
+                return None;
+            };
+            let index = error.valid_up_to();
+            self.string = &self.string[index + SURROGATE_LENGTH..];
+
+            let index = self.index + index;
+            self.index = index + SURROGATE_LENGTH;
+            Some(index)
+        }
+    }
+}
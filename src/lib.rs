@@ -8,6 +8,8 @@
 //! methods analagous to those of [`str`] but for [`OsStr`]. These methods will
 //! never panic for invalid UTF-8 in a platform string, so they can be used to
 //! manipulate [`OsStr`] values with the same simplicity possible for [`str`].
+//! To import it along with the other traits of this crate, use
+//! [`os_str_bytes::prelude::*`][prelude].
 //!
 //! Additionally, the following wrappers are provided. They are primarily
 //! legacy types from when this crate needed to perform more frequent encoding
@@ -28,12 +30,17 @@
 //! byte sequences that are invalid for input and output streams. Therefore,
 //! they can be used to convert between bytes strings exposed to users and
 //! platform strings.
+//! - [`OsStrBytes::from_c_str`]
 //! - [`OsStrBytes::from_io_bytes`]
 //! - [`OsStrBytes::to_io_bytes`]
 //! - [`OsStrBytes::to_io_bytes_lossy`]
 //! - [`OsStringBytes::from_io_vec`]
 //! - [`OsStringBytes::into_io_vec`]
 //! - [`OsStringBytes::into_io_vec_lossy`]
+//! - [`io::read_nul_delimited`]
+//!
+//! Byte strings read from files may also begin with a byte order mark, which
+//! can be found using [`detect_text_encoding`].
 //!
 //! # Features
 //!
@@ -50,21 +57,49 @@
 //!
 //! - **raw\_os\_str** -
 //!   Provides:
+//!   - [`intern`]
 //!   - [`iter`]
+//!   - [`BoundaryError`]
+//!   - [`CharOrInvalid`]
+//!   - [`DynPattern`]
+//!   - [`EmptyPatternError`]
+//!   - [`LimitUnit`]
 //!   - [`NonUnicodeOsStr`]
+//!   - [`NonUnicodePatternError`]
+//!   - [`OsStrBuilder`]
 //!   - [`OsStrBytesExt`]
+//!   - [`OsStringBytesExt`]
+//!   - [`OsStringMut`]
 //!   - [`Pattern`]
+//!   - `BytePattern` (Unix only)
+//!   - `OsStrBytesUnixExt` (Unix only)
+//!   - `OsStrBytesWindowsExt` (Windows and UEFI only)
 //!   - [`RawOsStr`]
 //!   - [`RawOsStrCow`]
+//!   - [`RawOsStrMut`]
 //!   - [`RawOsString`]
+//!   - [`SliceIndex`]
+//!   - [`SplitOptions`]
+//!   - [`TryIntoPattern`]
+//!   - [`UnescapeError`]
+//!   - [`Unit`]
+//!   - [`sanitize`]
+//!   - [`uses_memchr`]
 //!
 //! ### Optional Features
 //!
+//! - **borrowed** -
+//!   Provides [`borrowed`].
+//!
+//!   This feature enables the "raw\_os\_str" feature.
+//!
 //! - **checked\_conversions** -
 //!   Provides:
 //!   - [`EncodingError`]
+//!   - [`concat_raw`]
 //!   - [`OsStrBytes::from_raw_bytes`]
 //!   - [`OsStringBytes::from_raw_vec`]
+//!   - [`RawDecoder`]
 //!   - [`RawOsStr::cow_from_raw_bytes`]
 //!   - [`RawOsString::from_raw_vec`]
 //!
@@ -72,23 +107,115 @@
 //!   "OS\_STR\_BYTES\_CHECKED\_CONVERSIONS" environment variable must be
 //!   defined during compilation.
 //!
+//! - **collections** -
+//!   Provides [`collections`].
+//!
+//!   This feature enables the "raw\_os\_str" feature.
+//!
+//! - **console** -
+//!   Provides [`console`].
+//!
+//!   Writing to Windows consoles requires unsafe code, so consoles are not
+//!   detected when the "forbid\_unsafe" feature is enabled.
+//!
 //! - **conversions** -
 //!   Provides methods that require encoding conversion and may be expensive:
+//!   - [`OsStrBytesExt::cross_platform_cmp`]
 //!   - [`OsStrBytesExt::ends_with_os`]
+//!   - [`OsStrBytesExt::len_wide`]
+//!   - [`OsStrBytesExt::nt_case_eq`]
 //!   - [`OsStrBytesExt::starts_with_os`]
 //!   - [`RawOsStr::assert_cow_from_raw_bytes`]
+//!   - [`RawOsStr::cross_platform_cmp`]
 //!   - [`RawOsStr::ends_with_os`]
+//!   - [`RawOsStr::len_wide`]
+//!   - [`RawOsStr::nt_case_eq`]
 //!   - [`RawOsStr::starts_with_os`]
 //!   - [`RawOsStr::to_raw_bytes`]
 //!   - [`RawOsString::assert_from_raw_vec`]
 //!   - [`RawOsString::into_raw_vec`]
 //!   - [`OsStrBytes::assert_from_raw_bytes`]
+//!   - [`OsStrBytes::from_raw_bytes_partial`]
 //!   - [`OsStrBytes::to_raw_bytes`]
 //!   - [`OsStringBytes::assert_from_raw_vec`]
 //!   - [`OsStringBytes::into_raw_vec`]
+//!   - `WideEncoder` (Windows and UEFI only)
+//!   - [`WideError`]
+//!   - [`from_wide_strict`]
+//!   - [`validate_wide`]
 //!
 //!   For more information, see [Encoding Conversions].
 //!
+//! - **elide\_assertions** -
+//!   Removes checks of internal invariants from debug builds. They are never
+//!   included in release builds, where a conservative result is returned
+//!   instead if an invariant does not hold. Thus, this feature can be enabled
+//!   when a program must be audited to not panic in any profile. Panics
+//!   documented for methods of this crate, such as for invalid indices, are
+//!   unaffected.
+//!
+//! - **equivalent** -
+//!   Implements [`Equivalent`] for [`OsStr`] and `&OsStr`, so that maps keyed
+//!   by [`RawOsString`] from crates such as [hashbrown] and [indexmap] can be
+//!   searched using borrowed platform strings.
+//!
+//!   `&str` cannot be supported, since its [`Hash`] implementation is
+//!   incompatible. Use [`OsStr::new`] to convert it first.
+//!
+//!   This feature enables the "raw\_os\_str" feature.
+//!
+//! - **forbid\_unsafe** -
+//!   Forbids unsafe code in this crate, replacing unchecked operations with
+//!   checked equivalents that may be slower. Methods such as
+//!   [`RawOsStr::from_encoded_bytes_unchecked`] become safe to call, since
+//!   they panic for invalid arguments instead.
+//!
+//!   The types provided by the "raw\_os\_str" feature are transparent
+//!   wrappers, and referencing substrings of platform strings on Windows
+//!   requires casting bytes. The language provides no safe way to perform
+//!   those casts, so they are isolated in a single private module, which is
+//!   the only one where unsafe code is not forbidden. It validates the bytes
+//!   before casting them and also declares
+//!   [`RawOsString::from_raw_parts`], which cannot be checked.
+//!
+//! - **icu** -
+//!   Provides [`collate`], which sorts platform strings using a [`Collator`]
+//!   from [icu\_collator], such as to list file names in the order that a
+//!   user expects.
+//!
+//! - **small\_string** -
+//!   Stores short [`RawOsString`] values inline, instead of allocating them on
+//!   the heap. The API is unchanged, but programs that create many short
+//!   strings, such as when parsing arguments, may allocate less often.
+//!
+//!   Strings are stored inline when they are cloned, sliced, or built by
+//!   appending to an empty string, while they fit. Strings created from an
+//!   existing allocation keep using it, and inline strings are copied to the
+//!   heap when converted to an [`OsString`].
+//!
+//!   This feature enables the "raw\_os\_str" feature.
+//!
+//! - **test\_util** -
+//!   Provides [`test_util`].
+//!
+//!   This feature enables the "conversions" feature.
+//!
+//! - **tracing** -
+//!   Emits [tracing] events at the "debug" level when byte strings cannot be
+//!   converted to platform strings, such as by [`OsStrBytes::from_io_bytes`].
+//!   Each event records the length of the string, the index of the first
+//!   invalid byte, and up to 8 bytes on each side of it as hexadecimal. The
+//!   rest of the string is not recorded, since it may be sensitive. Events can
+//!   be enabled or disabled at runtime using the filters of a subscriber.
+//!
+//!   On Unix, no events are emitted, since these conversions cannot fail.
+//!
+//! - **zeroize** -
+//!   Implements [`Zeroize`] and [`ZeroizeOnDrop`] for [`RawOsString`], so
+//!   that its buffer is overwritten when it is no longer used.
+//!
+//!   This feature enables the "raw\_os\_str" feature.
+//!
 //! # Implementation
 //!
 //! Some methods return [`Cow`] to account for platform differences. However,
@@ -126,9 +253,9 @@
 //! - [`RawOsString::assert_from_raw_vec`]
 //!
 //! Concatenation may yield unexpected results without a UTF-8 separator. If
-//! two platform strings need to be concatenated, the only safe way to do so is
-//! using [`OsString::push`]. This limitation also makes it undesirable to use
-//! the bytes in interchange.
+//! two platform strings need to be concatenated, the only safe ways to do so
+//! are using [`OsString::push`] or [`concat_raw`]. This limitation also makes
+//! it undesirable to use the bytes in interchange.
 //!
 //! Since this encoding can change between versions and platforms, it should
 //! not be used for storage. The standard library provides implementations of
@@ -184,13 +311,22 @@
 //! ```
 //!
 //! [Encoding Conversions]: #encoding-conversions
+//! [`Collator`]: https://docs.rs/icu_collator/latest/icu_collator/struct.Collator.html
+//! [`Equivalent`]: https://docs.rs/equivalent/latest/equivalent/trait.Equivalent.html
+//! [`Hash`]: std::hash::Hash
+//! [hashbrown]: https://crates.io/crates/hashbrown
+//! [indexmap]: https://crates.io/crates/indexmap
+//! [icu\_collator]: https://crates.io/crates/icu_collator
 //! [memchr]: https://crates.io/crates/memchr
 //! [memchr_complexity]: OsStrBytesExt#complexity
 //! [`OsStrExt`]: ::std::os::unix::ffi::OsStrExt
 //! [`OsStringExt`]: ::std::os::unix::ffi::OsStringExt
 //! [print\_bytes]: https://crates.io/crates/print_bytes
 //! [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#c-sealed
+//! [tracing]: https://crates.io/crates/tracing
 //! [uniquote]: https://crates.io/crates/uniquote
+//! [`Zeroize`]: https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html
+//! [`ZeroizeOnDrop`]: https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html
 //! [windows_considerations]: https://doc.rust-lang.org/std/io/struct.Stdout.html#note-windows-portability-considerations
 
 // Only require a nightly compiler when building documentation for docs.rs.
@@ -203,13 +339,20 @@
     feature(sgx_platform)
 )]
 #![cfg_attr(target_os = "uefi", feature(uefi_std))]
+#![cfg_attr(feature = "forbid_unsafe", deny(unsafe_code))]
 #![warn(unused_results)]
 
 use std::borrow::Cow;
+use std::error::Error;
+use std::ffi::CStr;
 use std::ffi::OsStr;
 use std::ffi::OsString;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::path::Path;
 use std::path::PathBuf;
+use std::result;
 
 macro_rules! if_checked_conversions {
     ( $($item:item)+ ) => {
@@ -220,14 +363,6 @@ macro_rules! if_checked_conversions {
     };
 }
 
-if_checked_conversions! {
-    use std::error::Error;
-    use std::fmt;
-    use std::fmt::Display;
-    use std::fmt::Formatter;
-    use std::result;
-}
-
 #[cfg(not(os_str_bytes_docs_rs))]
 if_checked_conversions! {
     const _: &str = env!(
@@ -258,11 +393,85 @@ macro_rules! if_conversions {
 if_conversions! {
     macro_rules! expect_encoded {
         ( $result:expr ) => {
-            $result.expect("invalid raw bytes")
+            match $result {
+                Ok(result) => result,
+                Err(error) => panic!("invalid raw bytes: {}", error),
+            }
         };
     }
 }
 
+// These macros check invariants of this crate, so they should never fail.
+// Unlike [debug_assert!], they can be removed from debug builds using the
+// "elide_assertions" feature.
+#[cfg_attr(not(feature = "raw_os_str"), allow(unused_macros))]
+macro_rules! internal_assert {
+    ( $($arg:tt)+ ) => {
+        if cfg!(all(debug_assertions, not(feature = "elide_assertions"))) {
+            assert!($($arg)+);
+        }
+    };
+}
+
+#[cfg_attr(not(feature = "raw_os_str"), allow(unused_macros))]
+macro_rules! internal_assert_eq {
+    ( $($arg:tt)+ ) => {
+        if cfg!(all(debug_assertions, not(feature = "elide_assertions"))) {
+            assert_eq!($($arg)+);
+        }
+    };
+}
+
+// Unwraps a value that should always be present. The fallback is only used
+// when assertions are disabled.
+#[allow(unused_macros)]
+macro_rules! internal_expect {
+    ( $value:expr , $fallback:expr , $message:expr $(,)? ) => {
+        match $value {
+            Some(value) => value,
+            None => {
+                if cfg!(all(
+                    debug_assertions,
+                    not(feature = "elide_assertions")
+                )) {
+                    panic!($message);
+                }
+                $fallback
+            }
+        }
+    };
+}
+
+// Declares a function that is only unsafe to call when unsafe code is
+// allowed. Otherwise, its safety requirements are checked by [unchecked].
+#[cfg_attr(not(feature = "raw_os_str"), allow(unused_macros))]
+macro_rules! unchecked_fn {
+    ( $(#[ $attr:meta ])* $vis:vis unsafe fn $($rest:tt)+ ) => {
+        $(#[$attr])*
+        #[cfg(feature = "forbid_unsafe")]
+        $vis fn $($rest)+
+
+        $(#[$attr])*
+        #[cfg(not(feature = "forbid_unsafe"))]
+        $vis unsafe fn $($rest)+
+    };
+}
+
+// Calls functions declared using [unchecked_fn].
+#[cfg_attr(not(feature = "raw_os_str"), allow(unused_macros))]
+macro_rules! unchecked {
+    ( $expr:expr ) => {{
+        #[cfg(feature = "forbid_unsafe")]
+        {
+            $expr
+        }
+        #[cfg(not(feature = "forbid_unsafe"))]
+        {
+            unsafe { $expr }
+        }
+    }};
+}
+
 macro_rules! if_raw_str {
     ( $($item:item)+ ) => {
     $(
@@ -272,6 +481,34 @@ macro_rules! if_raw_str {
     };
 }
 
+macro_rules! if_unix_ext {
+    ( $($item:item)+ ) => {
+    $(
+        #[cfg(all(
+            feature = "raw_os_str",
+            not(any(
+                all(target_family = "wasm", target_os = "unknown"),
+                target_os = "uefi",
+                windows,
+            )),
+        ))]
+        $item
+    )+
+    };
+}
+
+macro_rules! if_windows_ext {
+    ( $($item:item)+ ) => {
+    $(
+        #[cfg(all(
+            feature = "raw_os_str",
+            any(target_os = "uefi", windows),
+        ))]
+        $item
+    )+
+    };
+}
+
 #[cfg_attr(
     all(target_family = "wasm", target_os = "unknown"),
     path = "wasm/mod.rs"
@@ -288,30 +525,141 @@ macro_rules! if_raw_str {
 mod imp;
 use imp::convert_io;
 
+pub mod io;
+
+pub mod prelude;
+
+#[cfg(feature = "console")]
+pub mod console;
+
+#[cfg(feature = "test_util")]
+pub mod test_util;
+
 if_conversions! {
     use imp::convert;
 }
 
+if_checked_conversions! {
+    mod decoder;
+    pub use decoder::RawDecoder;
+}
+
+#[cfg(any(target_os = "uefi", windows))]
+if_conversions! {
+    pub use convert::WideEncoder;
+}
+
+#[cfg(feature = "icu")]
+mod collate;
+#[cfg(feature = "icu")]
+pub use collate::collate;
+
+if_conversions! {
+    mod wide;
+    pub use wide::from_wide_strict;
+    pub use wide::validate_wide;
+    pub use wide::WideError;
+}
+
+#[cfg(all(
+    feature = "tracing",
+    any(
+        all(target_family = "wasm", target_os = "unknown"),
+        target_os = "uefi",
+        windows,
+    ),
+))]
+mod trace;
+
 #[cfg(any(
     all(feature = "conversions", any(target_os = "uefi", windows)),
+    all(
+        feature = "checked_conversions",
+        target_family = "wasm",
+        target_os = "unknown",
+    ),
     feature = "raw_os_str",
 ))]
 mod util;
 
 if_raw_str! {
     mod ext;
+    pub use ext::BoundaryError;
+    pub use ext::CharOrInvalid;
+    pub use ext::EmptyPatternError;
+    pub use ext::LimitUnit;
     pub use ext::NonUnicodeOsStr;
     pub use ext::OsStrBytesExt;
+    pub use ext::OsStringBytesExt;
+    pub use ext::SliceIndex;
+    pub use ext::SplitOptions;
+    pub use ext::UnescapeError;
+    pub use ext::Unit;
+
+    #[cfg(feature = "borrowed")]
+    pub mod borrowed;
+
+    mod buffer;
+
+    mod builder;
+    pub use builder::OsStrBuilder;
+
+    #[cfg(feature = "collections")]
+    pub mod collections;
+
+    pub mod intern;
 
     pub mod iter;
 
     mod pattern;
+    pub use pattern::DynPattern;
+    pub use pattern::NonUnicodePatternError;
     pub use pattern::Pattern;
+    pub use pattern::TryIntoPattern;
 
     mod raw_str;
+    pub use raw_str::OsStringMut;
     pub use raw_str::RawOsStr;
     pub use raw_str::RawOsStrCow;
+    pub use raw_str::RawOsStrMut;
     pub use raw_str::RawOsString;
+
+    pub mod sanitize;
+
+    #[cfg(not(feature = "memchr"))]
+    mod two_way;
+
+    mod unchecked;
+}
+
+if_raw_str! {
+    /// Returns `true` if the "memchr" feature is enabled.
+    ///
+    /// That feature changes the implementation of [searching methods], so
+    /// this function can be used by benchmarks to label their results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// println!("memchr: {}", os_str_bytes::uses_memchr());
+    /// ```
+    ///
+    /// [searching methods]: OsStrBytesExt#complexity
+    #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+    #[inline]
+    #[must_use]
+    pub const fn uses_memchr() -> bool {
+        cfg!(feature = "memchr")
+    }
+}
+
+if_unix_ext! {
+    pub use ext::OsStrBytesUnixExt;
+    pub use pattern::BytePattern;
+}
+
+if_windows_ext! {
+    pub use ext::OsStrBytesWindowsExt;
 }
 
 if_checked_conversions! {
@@ -351,6 +699,179 @@ if_checked_conversions! {
 
 if_checked_conversions! {
     type Result<T> = result::Result<T, EncodingError>;
+
+    /// Concatenates two byte strings in the [unspecified encoding].
+    ///
+    /// [The module-level documentation][encoding] warns against concatenating
+    /// these bytes directly, since sequences at the joint may combine
+    /// unexpectedly. This function instead produces the same bytes as
+    /// encoding the result of [`OsString::push`]. Only the sequences adjacent
+    /// to the joint are validated, so no more than a few bytes are examined,
+    /// in addition to those copied.
+    ///
+    /// Both strings should have been validated previously, such as by
+    /// [`OsStrBytes::from_raw_bytes`] or by being returned by
+    /// [`OsStrBytes::to_raw_bytes`]. Invalid sequences that are not adjacent
+    /// to the joint may not be detected, but they will still be rejected when
+    /// the result is converted to a platform string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequences adjacent to the joint are invalid,
+    /// such as if `string` ends with an incomplete sequence that `other` does
+    /// not complete.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytes;
+    ///
+    /// let foo = OsStr::new("foo").to_raw_bytes();
+    /// let bar = OsStr::new("bar").to_raw_bytes();
+    /// let foobar = os_str_bytes::concat_raw(&foo, &bar)?;
+    /// assert_eq!(OsStr::new("foobar"), OsStr::from_raw_bytes(foobar)?);
+    /// #
+    /// # Ok::<_, os_str_bytes::EncodingError>(())
+    /// ```
+    ///
+    /// [encoding]: self#encoding-conversions
+    /// [unspecified encoding]: self#encoding-conversions
+    #[cfg_attr(
+        os_str_bytes_docs_rs,
+        doc(cfg(feature = "checked_conversions"))
+    )]
+    #[inline]
+    pub fn concat_raw(string: &[u8], other: &[u8]) -> Result<Vec<u8>> {
+        convert::concat(string, other).map_err(EncodingError)
+    }
+}
+
+/// The error that occurs when a byte string is not [IO-safe].
+///
+/// On Unix, this error is never returned.
+///
+/// [IO-safe]: self#user-input
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IoEncodingError(());
+
+impl IoEncodingError {
+    const fn new() -> Self {
+        Self(())
+    }
+}
+
+impl Display for IoEncodingError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("byte string is not IO-safe")
+    }
+}
+
+impl Error for IoEncodingError {}
+
+/// The encoding of a byte string, as detected by [`detect_text_encoding`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TextEncoding {
+    /// UTF-8 without a byte order mark.
+    ///
+    /// The string is [IO-safe] on all platforms.
+    ///
+    /// [IO-safe]: self#user-input
+    Utf8,
+
+    /// UTF-8 with a byte order mark.
+    ///
+    /// The string is [IO-safe] on all platforms after removing the mark.
+    ///
+    /// [IO-safe]: self#user-input
+    Utf8Bom,
+
+    /// UTF-16 with a big-endian byte order mark.
+    Utf16Be,
+
+    /// UTF-16 with a little-endian byte order mark.
+    Utf16Le,
+
+    /// An encoding that could not be detected.
+    ///
+    /// The string has no byte order mark and is not valid UTF-8. On Unix, it
+    /// may still be [IO-safe].
+    ///
+    /// [IO-safe]: self#user-input
+    Unknown,
+}
+
+impl TextEncoding {
+    /// Returns the length of the byte order mark for this encoding, in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytes;
+    ///
+    /// let string = b"\xEF\xBB\xBFfoo";
+    /// let encoding = os_str_bytes::detect_text_encoding(string);
+    /// assert_eq!(
+    ///     Some(OsStr::new("foo")),
+    ///     OsStr::from_io_bytes(&string[encoding.bom_len()..]),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn bom_len(self) -> usize {
+        match self {
+            Self::Utf8 | Self::Unknown => 0,
+            Self::Utf8Bom => 3,
+            Self::Utf16Be | Self::Utf16Le => 2,
+        }
+    }
+}
+
+/// Detects the encoding of a byte string using its byte order mark.
+///
+/// Strings without a mark are assumed to be UTF-8 if they are valid, which is
+/// the same requirement used for [IO-safe] strings on Windows. UTF-32 marks
+/// are not recognized, since the little-endian mark begins with the same
+/// bytes as the one for UTF-16.
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::TextEncoding;
+///
+/// assert_eq!(
+///     TextEncoding::Utf8Bom,
+///     os_str_bytes::detect_text_encoding(b"\xEF\xBB\xBFfoo"),
+/// );
+/// assert_eq!(
+///     TextEncoding::Utf16Le,
+///     os_str_bytes::detect_text_encoding(b"\xFF\xFEf\x00"),
+/// );
+/// assert_eq!(
+///     TextEncoding::Utf8,
+///     os_str_bytes::detect_text_encoding(b"foo"),
+/// );
+/// assert_eq!(
+///     TextEncoding::Unknown,
+///     os_str_bytes::detect_text_encoding(b"\xFF"),
+/// );
+/// ```
+///
+/// [IO-safe]: self#user-input
+#[must_use]
+pub fn detect_text_encoding(string: &[u8]) -> TextEncoding {
+    match string {
+        [0xEF, 0xBB, 0xBF, ..] => TextEncoding::Utf8Bom,
+        [0xFE, 0xFF, ..] => TextEncoding::Utf16Be,
+        [0xFF, 0xFE, ..] => TextEncoding::Utf16Le,
+        _ if std::str::from_utf8(string).is_ok() => TextEncoding::Utf8,
+        _ => TextEncoding::Unknown,
+    }
 }
 
 if_conversions! {
@@ -367,6 +888,13 @@ if_conversions! {
     }
 }
 
+if_conversions! {
+    fn from_raw_bytes_partial(string: &[u8]) -> (Cow<'_, OsStr>, &[u8]) {
+        let (prefix, suffix) = string.split_at(convert::valid_length(string));
+        (expect_encoded!(convert::os_str_from_bytes(prefix)), suffix)
+    }
+}
+
 if_conversions! {
     fn cow_os_str_into_path(string: Cow<'_, OsStr>) -> Cow<'_, Path> {
         match string {
@@ -416,6 +944,35 @@ pub trait OsStrBytes: private::Sealed + ToOwned {
             S: Into<Cow<'a, [u8]>>;
     }
 
+    /// Converts a C string into an equivalent platform-native string, if it
+    /// is [IO-safe].
+    ///
+    /// This method is equivalent to calling [`from_io_bytes`] with
+    /// [`CStr::to_bytes`]. On Unix, the conversion always succeeds and does
+    /// not validate the string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not IO-safe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::CStr;
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytes;
+    ///
+    /// let c_string = CStr::from_bytes_with_nul(b"foobar\0").unwrap();
+    /// assert_eq!("foobar", OsStr::from_c_str(c_string)?);
+    /// #
+    /// # Ok::<_, os_str_bytes::IoEncodingError>(())
+    /// ```
+    ///
+    /// [`from_io_bytes`]: Self::from_io_bytes
+    /// [IO-safe]: self#user-input
+    fn from_c_str(string: &CStr) -> result::Result<&Self, IoEncodingError>;
+
     /// Converts a byte string into an equivalent platform-native string, if it
     /// is [IO-safe].
     ///
@@ -442,6 +999,41 @@ pub trait OsStrBytes: private::Sealed + ToOwned {
     #[must_use]
     fn from_io_bytes(string: &[u8]) -> Option<&Self>;
 
+    /// Equivalent to [`from_io_bytes`], but the result is written to an
+    /// existing buffer.
+    ///
+    /// The buffer is cleared before writing, but its allocation is reused.
+    /// This method avoids allocating when converting many strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the buffer unchanged if the string is not
+    /// [IO-safe].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::OsStrBytes;
+    ///
+    /// let mut buffer = OsString::new();
+    /// for string in [&b"foo"[..], b"bar"] {
+    ///     OsStr::from_io_bytes_into(string, &mut buffer)?;
+    ///     assert_eq!(string, buffer.as_encoded_bytes());
+    /// }
+    /// #
+    /// # Ok::<_, os_str_bytes::IoEncodingError>(())
+    /// ```
+    ///
+    /// [`from_io_bytes`]: Self::from_io_bytes
+    /// [IO-safe]: self#user-input
+    fn from_io_bytes_into(
+        string: &[u8],
+        buffer: &mut Self::Owned,
+    ) -> result::Result<(), IoEncodingError>;
+
     if_checked_conversions! {
         /// Converts a byte string into an equivalent platform-native string.
         ///
@@ -478,6 +1070,35 @@ pub trait OsStrBytes: private::Sealed + ToOwned {
             S: Into<Cow<'a, [u8]>>;
     }
 
+    if_conversions! {
+        /// Converts the longest valid prefix of a byte string into an
+        /// equivalent platform-native string.
+        ///
+        /// The conversion stops at the first sequence that is invalid or
+        /// incomplete for the [unspecified encoding] used by this crate, and
+        /// the remaining bytes are returned unchanged. This method is useful
+        /// when reading data that may have been truncated. On Unix, the
+        /// remainder is always empty.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        ///
+        /// use os_str_bytes::OsStrBytes;
+        ///
+        /// let (os_string, remainder) =
+        ///     OsStr::from_raw_bytes_partial(b"foobar");
+        /// assert_eq!("foobar", &*os_string);
+        /// assert!(remainder.is_empty());
+        /// ```
+        ///
+        /// [unspecified encoding]: self#encoding-conversions
+        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+        #[must_use]
+        fn from_raw_bytes_partial(string: &[u8]) -> (Cow<'_, Self>, &[u8]);
+    }
+
     /// Converts a platform-native string into an equivalent byte string, if it
     /// is [IO-safe].
     ///
@@ -503,6 +1124,40 @@ pub trait OsStrBytes: private::Sealed + ToOwned {
     #[must_use]
     fn to_io_bytes(&self) -> Option<&'_ [u8]>;
 
+    /// Equivalent to [`to_io_bytes`], but the result is written to an
+    /// existing buffer.
+    ///
+    /// The buffer is cleared before writing, but its allocation is reused.
+    /// This method avoids allocating when converting many strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the buffer unchanged if this string is not
+    /// [IO-safe].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytes;
+    ///
+    /// let mut buffer = Vec::new();
+    /// for string in ["foo", "bar"] {
+    ///     OsStr::new(string).to_io_bytes_into(&mut buffer)?;
+    ///     assert_eq!(string.as_bytes(), buffer);
+    /// }
+    /// #
+    /// # Ok::<_, os_str_bytes::IoEncodingError>(())
+    /// ```
+    ///
+    /// [`to_io_bytes`]: Self::to_io_bytes
+    /// [IO-safe]: self#user-input
+    fn to_io_bytes_into(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> result::Result<(), IoEncodingError>;
+
     /// Converts a platform-native string into an equivalent byte string.
     ///
     /// If the string is not [IO-safe], invalid characters will be replaced
@@ -564,11 +1219,28 @@ impl OsStrBytes for OsStr {
         }
     }
 
+    #[inline]
+    fn from_c_str(string: &CStr) -> result::Result<&Self, IoEncodingError> {
+        Self::from_io_bytes(string.to_bytes()).ok_or(IoEncodingError::new())
+    }
+
     #[inline]
     fn from_io_bytes(string: &[u8]) -> Option<&Self> {
         convert_io::os_str_from_bytes(string)
     }
 
+    #[inline]
+    fn from_io_bytes_into(
+        string: &[u8],
+        buffer: &mut OsString,
+    ) -> result::Result<(), IoEncodingError> {
+        let string =
+            Self::from_io_bytes(string).ok_or(IoEncodingError::new())?;
+        buffer.clear();
+        buffer.push(string);
+        Ok(())
+    }
+
     if_checked_conversions! {
         #[inline]
         fn from_raw_bytes<'a, S>(string: S) -> Result<Cow<'a, Self>>
@@ -579,11 +1251,29 @@ impl OsStrBytes for OsStr {
         }
     }
 
+    if_conversions! {
+        #[inline]
+        fn from_raw_bytes_partial(string: &[u8]) -> (Cow<'_, Self>, &[u8]) {
+            from_raw_bytes_partial(string)
+        }
+    }
+
     #[inline]
     fn to_io_bytes(&self) -> Option<&'_ [u8]> {
         convert_io::os_str_to_bytes(self)
     }
 
+    #[inline]
+    fn to_io_bytes_into(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> result::Result<(), IoEncodingError> {
+        let string = self.to_io_bytes().ok_or(IoEncodingError::new())?;
+        buffer.clear();
+        buffer.extend_from_slice(string);
+        Ok(())
+    }
+
     #[inline]
     fn to_io_bytes_lossy(&self) -> Cow<'_, [u8]> {
         convert_io::os_str_to_bytes_lossy(self)
@@ -608,11 +1298,24 @@ impl OsStrBytes for Path {
         }
     }
 
+    #[inline]
+    fn from_c_str(string: &CStr) -> result::Result<&Self, IoEncodingError> {
+        OsStr::from_c_str(string).map(Self::new)
+    }
+
     #[inline]
     fn from_io_bytes(string: &[u8]) -> Option<&Self> {
         OsStr::from_io_bytes(string).map(Self::new)
     }
 
+    #[inline]
+    fn from_io_bytes_into(
+        string: &[u8],
+        buffer: &mut PathBuf,
+    ) -> result::Result<(), IoEncodingError> {
+        OsStr::from_io_bytes_into(string, buffer.as_mut_os_string())
+    }
+
     if_checked_conversions! {
         #[inline]
         fn from_raw_bytes<'a, S>(string: S) -> Result<Cow<'a, Self>>
@@ -623,11 +1326,27 @@ impl OsStrBytes for Path {
         }
     }
 
+    if_conversions! {
+        #[inline]
+        fn from_raw_bytes_partial(string: &[u8]) -> (Cow<'_, Self>, &[u8]) {
+            let (prefix, suffix) = OsStr::from_raw_bytes_partial(string);
+            (cow_os_str_into_path(prefix), suffix)
+        }
+    }
+
     #[inline]
     fn to_io_bytes(&self) -> Option<&'_ [u8]> {
         self.as_os_str().to_io_bytes()
     }
 
+    #[inline]
+    fn to_io_bytes_into(
+        &self,
+        buffer: &mut Vec<u8>,
+    ) -> result::Result<(), IoEncodingError> {
+        self.as_os_str().to_io_bytes_into(buffer)
+    }
+
     #[inline]
     fn to_io_bytes_lossy(&self) -> Cow<'_, [u8]> {
         self.as_os_str().to_io_bytes_lossy()
@@ -770,7 +1489,11 @@ pub trait OsStringBytes: private::Sealed + Sized {
     /// Converts a platform-native string into an equivalent byte string.
     ///
     /// If the string is not [IO-safe], invalid characters will be replaced
-    /// with [`REPLACEMENT_CHARACTER`].
+    /// with [`REPLACEMENT_CHARACTER`]. Each invalid character is replaced
+    /// once, so the result is equal to that of
+    /// [`OsStrBytes::to_io_bytes_lossy`]. On Windows, earlier releases
+    /// replaced each byte of an unpaired surrogate separately, producing
+    /// three replacement characters per surrogate.
     ///
     /// # Examples
     ///
@@ -901,7 +1624,14 @@ mod private {
 
     if_raw_str! {
         use std::borrow::Cow;
-
+        use std::ops::Range;
+        use std::ops::RangeFrom;
+        use std::ops::RangeFull;
+        use std::ops::RangeInclusive;
+        use std::ops::RangeTo;
+        use std::ops::RangeToInclusive;
+
+        use super::DynPattern;
         use super::RawOsStr;
     }
 
@@ -916,6 +1646,47 @@ mod private {
     impl Sealed for &String {}
 
     if_raw_str! {
+        impl Sealed for &Box<str> {}
+        impl Sealed for &DynPattern {}
+        impl Sealed for &OsStr {}
+        impl Sealed for &RawOsStr {}
         impl Sealed for Cow<'_, RawOsStr> {}
+        impl Sealed for Cow<'_, str> {}
+        impl Sealed for Range<usize> {}
+        impl Sealed for RangeFrom<usize> {}
+        impl Sealed for RangeFull {}
+        impl Sealed for RangeInclusive<usize> {}
+        impl Sealed for RangeTo<usize> {}
+        impl Sealed for RangeToInclusive<usize> {}
+        impl Sealed for usize {}
+    }
+
+    #[cfg(feature = "console")]
+    mod console {
+        use std::fs::File;
+        use std::io::BufWriter;
+        use std::io::Stderr;
+        use std::io::StderrLock;
+        use std::io::Stdout;
+        use std::io::StdoutLock;
+        use std::io::Write;
+
+        use super::Sealed;
+
+        impl Sealed for File {}
+        impl Sealed for Stderr {}
+        impl Sealed for StderrLock<'_> {}
+        impl Sealed for Stdout {}
+        impl Sealed for StdoutLock<'_> {}
+        impl<W> Sealed for BufWriter<W> where W: Sealed + Write {}
+        impl Sealed for Vec<u8> {}
+        impl<W> Sealed for &mut W where W: ?Sized + Sealed {}
+    }
+
+    if_unix_ext! {
+        impl Sealed for u8 {}
+        impl Sealed for &[u8] {}
+        impl<const N: usize> Sealed for &[u8; N] {}
+        impl Sealed for &Vec<u8> {}
     }
 }
@@ -1,8 +1,19 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fmt;
 use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::result;
 use std::str;
 
 use super::private;
+use super::unchecked;
 use super::util::MAX_UTF8_LENGTH;
+use super::RawOsStr;
 
 pub trait Encoded {
     fn __as_bytes(&self) -> &[u8] {
@@ -21,7 +32,7 @@ pub struct EncodedChar {
 impl Encoded for EncodedChar {
     fn __as_str(&self) -> &str {
         // SAFETY: This slice was encoded from a character.
-        unsafe { str::from_utf8_unchecked(&self.buffer[..self.length]) }
+        unchecked!(unchecked::utf8(&self.buffer[..self.length]))
     }
 }
 
@@ -31,6 +42,12 @@ impl Encoded for &str {
     }
 }
 
+impl Encoded for Cow<'_, str> {
+    fn __as_str(&self) -> &str {
+        self
+    }
+}
+
 /// Allows a type to be used for searching by [`RawOsStr`] and [`RawOsString`].
 ///
 /// This trait is very similar to [`str::pattern::Pattern`], but its methods
@@ -41,7 +58,7 @@ impl Encoded for &str {
 #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
 pub trait Pattern: private::Sealed {
     #[doc(hidden)]
-    type __Encoded: Clone + Debug + Encoded;
+    type __Encoded: Clone + Debug + Encoded + Send + Sync;
 
     #[doc(hidden)]
     fn __encode(self) -> Self::__Encoded;
@@ -53,9 +70,13 @@ impl Pattern for char {
     fn __encode(self) -> Self::__Encoded {
         let mut encoded = EncodedChar {
             buffer: [0; MAX_UTF8_LENGTH],
-            length: 0,
+            length: 1,
         };
-        encoded.length = self.encode_utf8(&mut encoded.buffer).len();
+        if self.is_ascii() {
+            encoded.buffer[0] = self as u8;
+        } else {
+            encoded.length = self.encode_utf8(&mut encoded.buffer).len();
+        }
         encoded
     }
 }
@@ -75,3 +96,253 @@ impl<'a> Pattern for &'a String {
         (**self).__encode()
     }
 }
+
+impl<'a> Pattern for &'a Box<str> {
+    type __Encoded = <&'a str as Pattern>::__Encoded;
+
+    fn __encode(self) -> Self::__Encoded {
+        (**self).__encode()
+    }
+}
+
+impl<'a> Pattern for Cow<'a, str> {
+    type __Encoded = Self;
+
+    fn __encode(self) -> Self::__Encoded {
+        self
+    }
+}
+
+/// A pattern constructed at runtime.
+///
+/// Since [`Pattern`] has an associated type, patterns of different types
+/// cannot be stored together or passed through trait objects. This struct
+/// stores any pattern as an owned string, so it can be used where the type
+/// of a pattern is only known at runtime, such as when it is read from a
+/// configuration file. A reference to it can be passed to any method that
+/// accepts a [`Pattern`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::DynPattern;
+/// use os_str_bytes::OsStrBytesExt;
+///
+/// let separators = [DynPattern::new(','), DynPattern::new("::")];
+///
+/// let os_string = OsStr::new("foo::bar,baz");
+/// assert_eq!(Some(8), os_string.find(&separators[0]));
+/// assert_eq!(Some(3), os_string.find(&separators[1]));
+/// ```
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DynPattern(Box<str>);
+
+impl DynPattern {
+    /// Creates a runtime pattern equivalent to the given pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::DynPattern;
+    ///
+    /// assert_eq!("\u{F6}", DynPattern::new('\u{F6}').as_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new<P>(pat: P) -> Self
+    where
+        P: Pattern,
+    {
+        Self(pat.__encode().__as_str().into())
+    }
+
+    /// Returns the string searched for by this pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::DynPattern;
+    ///
+    /// assert_eq!("foo", DynPattern::new("foo").as_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<char> for DynPattern {
+    #[inline]
+    fn from(value: char) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for DynPattern {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for DynPattern {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self(value.into_boxed_str())
+    }
+}
+
+impl TryFrom<&OsStr> for DynPattern {
+    type Error = NonUnicodePatternError;
+
+    #[inline]
+    fn try_from(value: &OsStr) -> result::Result<Self, Self::Error> {
+        value.try_into_pattern().map(Self::new)
+    }
+}
+
+impl<'a> Pattern for &'a DynPattern {
+    type __Encoded = &'a str;
+
+    fn __encode(self) -> Self::__Encoded {
+        &self.0
+    }
+}
+
+/// The error returned by [`TryIntoPattern::try_into_pattern`] when a string
+/// is not valid UTF-8.
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NonUnicodePatternError(());
+
+impl Display for NonUnicodePatternError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("pattern is not valid Unicode")
+    }
+}
+
+impl Error for NonUnicodePatternError {}
+
+/// Allows a platform string to be used as a [`Pattern`], if it is valid
+/// UTF-8.
+///
+/// Patterns must be valid UTF-8, but separators are sometimes only available
+/// as platform strings, such as when they are read from the environment.
+/// This trait checks them at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::OsStrBytesExt;
+/// use os_str_bytes::TryIntoPattern;
+///
+/// let separator = OsStr::new(",");
+/// let pat = separator.try_into_pattern()?;
+/// assert!(OsStr::new("foo,bar").split(pat).eq(["foo", "bar"]));
+/// #
+/// # Ok::<_, os_str_bytes::NonUnicodePatternError>(())
+/// ```
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+pub trait TryIntoPattern: private::Sealed {
+    /// The type of the pattern.
+    type Pattern: Pattern;
+
+    /// Converts this string into a pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid UTF-8.
+    fn try_into_pattern(
+        self,
+    ) -> result::Result<Self::Pattern, NonUnicodePatternError>;
+}
+
+impl<'a> TryIntoPattern for &'a OsStr {
+    type Pattern = &'a str;
+
+    #[inline]
+    fn try_into_pattern(
+        self,
+    ) -> result::Result<Self::Pattern, NonUnicodePatternError> {
+        self.to_str().ok_or(NonUnicodePatternError(()))
+    }
+}
+
+impl<'a> TryIntoPattern for &'a RawOsStr {
+    type Pattern = &'a str;
+
+    #[inline]
+    fn try_into_pattern(
+        self,
+    ) -> result::Result<Self::Pattern, NonUnicodePatternError> {
+        self.as_os_str().try_into_pattern()
+    }
+}
+
+if_unix_ext! {
+    /// Allows a type to be used for searching by [`OsStrBytesUnixExt`].
+    ///
+    /// Unlike [`Pattern`], this trait is implemented for byte sequences that
+    /// do not need to be valid UTF-8. It is only available on platforms where
+    /// [`OsStr`] can contain arbitrary bytes.
+    ///
+    /// [`OsStr`]: ::std::ffi::OsStr
+    /// [`OsStrBytesUnixExt`]: super::OsStrBytesUnixExt
+    #[cfg_attr(
+        os_str_bytes_docs_rs,
+        doc(cfg(all(
+            feature = "raw_os_str",
+            not(any(
+                all(target_family = "wasm", target_os = "unknown"),
+                target_os = "uefi",
+                windows,
+            )),
+        )))
+    )]
+    pub trait BytePattern: private::Sealed {
+        #[doc(hidden)]
+        type __Encoded: AsRef<[u8]> + Clone + Debug + Send + Sync;
+
+        #[doc(hidden)]
+        fn __encode(self) -> Self::__Encoded;
+    }
+
+    impl BytePattern for u8 {
+        type __Encoded = [u8; 1];
+
+        fn __encode(self) -> Self::__Encoded {
+            [self]
+        }
+    }
+
+    impl BytePattern for &[u8] {
+        type __Encoded = Self;
+
+        fn __encode(self) -> Self::__Encoded {
+            self
+        }
+    }
+
+    impl<const N: usize> BytePattern for &[u8; N] {
+        type __Encoded = Self;
+
+        fn __encode(self) -> Self::__Encoded {
+            self
+        }
+    }
+
+    impl<'a> BytePattern for &'a Vec<u8> {
+        type __Encoded = &'a [u8];
+
+        fn __encode(self) -> Self::__Encoded {
+            self
+        }
+    }
+}
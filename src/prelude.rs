@@ -0,0 +1,50 @@
+//! Traits that provide the methods of this crate.
+//!
+//! Importing this module using a glob import makes all extension methods
+//! available at once. Only traits are included, so the import will not
+//! conflict with the names of other types in scope.
+//!
+//! The trait provided by the "borrowed" feature is not included, since it
+//! provides methods with the same names as [`OsStrBytesExt`].
+//!
+//! # Examples
+//!
+//! ```
+//! use std::ffi::OsStr;
+//! use std::ffi::OsString;
+//!
+//! use os_str_bytes::prelude::*;
+//!
+//! let os_string = OsString::from_io_vec(b"foo=bar".to_vec()).unwrap();
+//! assert_eq!(Some(&*os_string), OsStr::from_io_bytes(b"foo=bar"));
+//! # #[cfg(feature = "raw_os_str")]
+//! # {
+//! let (key, value) = os_string.split_once('=').unwrap();
+//! assert_eq!("foo", key);
+//! assert_eq!("bar", value);
+//! # }
+//! ```
+//!
+//! [`OsStrBytesExt`]: super::OsStrBytesExt
+
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+pub use super::OsStrBytes;
+pub use super::OsStringBytes;
+
+if_raw_str! {
+    pub use super::OsStrBytesExt;
+    pub use super::OsStringBytesExt;
+    pub use super::Pattern;
+    pub use super::RawOsStrCow;
+    pub use super::TryIntoPattern;
+}
+
+if_unix_ext! {
+    pub use super::BytePattern;
+    pub use super::OsStrBytesUnixExt;
+}
+
+if_windows_ext! {
+    pub use super::OsStrBytesWindowsExt;
+}
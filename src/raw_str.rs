@@ -1,56 +1,105 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
 use std::borrow::Borrow;
 use std::borrow::Cow;
 use std::borrow::ToOwned;
+use std::cmp::Ordering;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::mem;
+use std::mem::ManuallyDrop;
 use std::ops::Deref;
+use std::ops::DerefMut;
 use std::ops::Index;
+use std::path::Path;
+use std::path::PathBuf;
 use std::result;
 use std::str;
 
+#[cfg(feature = "equivalent")]
+use equivalent::Equivalent;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+#[cfg(feature = "zeroize")]
+use zeroize::ZeroizeOnDrop;
+
+use super::buffer::Buffer;
+use super::buffer::UnicodeCache;
 use super::ext;
-use super::ext::SliceIndex;
+use super::iter::CodePoints;
+use super::iter::IntoUtf8Chunks;
+use super::iter::RawChunkBySize;
+use super::iter::RawComponentsNaive;
+use super::iter::RawMatchIndicesOs;
 use super::iter::RawRSplit;
 use super::iter::RawSplit;
+use super::iter::RawSplitIndices;
+use super::iter::RawSplitKeep;
+use super::iter::RawSplitWith;
+use super::iter::Units;
 use super::iter::Utf8Chunks;
 use super::private;
+use super::unchecked;
+use super::unchecked::TransmuteBox;
+use super::util::MAX_UTF8_LENGTH;
+use super::BoundaryError;
+use super::CharOrInvalid;
+use super::EmptyPatternError;
+use super::LimitUnit;
+use super::NonUnicodeOsStr;
+use super::OsStrBytes;
 use super::OsStrBytesExt;
+use super::OsStringBytes;
+use super::OsStringBytesExt;
 use super::Pattern;
+use super::SliceIndex;
+use super::SplitOptions;
+use super::UnescapeError;
 
 if_checked_conversions! {
     use super::Result;
 }
 
-if_conversions! {
-    use super::OsStrBytes;
-    use super::OsStringBytes;
-}
+#[cfg(target_os = "uefi")]
+use std::os::uefi as os;
+#[cfg(windows)]
+use std::os::windows as os;
 
-#[allow(clippy::missing_safety_doc)]
-unsafe trait TransmuteBox {
-    fn transmute_box<R>(self: Box<Self>) -> Box<R>
-    where
-        R: ?Sized + TransmuteBox,
-    {
-        let value = Box::into_raw(self);
-        // SAFETY: This trait is only implemented for types that can be
-        // transmuted.
-        unsafe { Box::from_raw(mem::transmute_copy(&value)) }
-    }
-}
+#[cfg(any(target_os = "uefi", windows))]
+use os::ffi::OsStringExt;
 
-// SAFETY: This struct has a layout that makes this operation safe.
-unsafe impl TransmuteBox for RawOsStr {}
-unsafe impl TransmuteBox for [u8] {}
+#[cfg(any(target_os = "uefi", windows))]
+use super::imp::raw;
+#[cfg(any(target_os = "uefi", windows))]
+use super::imp::SURROGATE_LENGTH;
+
+#[cfg(any(target_os = "uefi", windows))]
+if_conversions! {
+    use os::ffi::OsStrExt;
+}
 
 /// A container providing additional functionality for [`OsStr`].
 ///
 /// For more information, see [`OsStrBytesExt`].
-#[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+///
+/// # Layout
+///
+/// This struct is guaranteed to have the same layout as `[u8]`, since it is
+/// declared with `#[repr(transparent)]`. Its bytes are those returned by
+/// [`as_encoded_bytes`], which use the internal encoding of [`OsStr`]. This
+/// guarantee will not change without a major version. Thus, foreign code can
+/// receive a string as the pointer and length returned by [`as_ptr`] and
+/// [`len`].
+///
+/// [`as_encoded_bytes`]: Self::as_encoded_bytes
+/// [`as_ptr`]: Self::as_ptr
+/// [`len`]: Self::len
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
 #[repr(transparent)]
 pub struct RawOsStr([u8]);
@@ -77,10 +126,7 @@ impl RawOsStr {
     where
         S: AsRef<OsStr> + ?Sized,
     {
-        let string = string.as_ref().as_encoded_bytes();
-        // SAFETY: [OsStr] prevents violating the invariants of its internal
-        // encoding.
-        unsafe { Self::from_encoded_bytes_unchecked(string) }
+        unchecked::os_str_as_raw_os_str(string.as_ref())
     }
 
     fn from_tuple<'a, 'b>(
@@ -111,6 +157,55 @@ impl RawOsStr {
         Self::new(string)
     }
 
+    /// Wraps a path, without copying or encoding conversion.
+    ///
+    /// This method is equivalent to [`new`], but it can be passed to
+    /// functions such as [`Option::map`] without specifying a type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// # use std::io;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let path = env::current_exe()?;
+    /// let raw = path.parent().map(RawOsStr::from_path);
+    /// println!("{:?}", raw);
+    /// #
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    ///
+    /// [`new`]: Self::new
+    #[inline]
+    #[must_use]
+    pub fn from_path(path: &Path) -> &Self {
+        Self::new(path)
+    }
+
+    /// Wraps a string literal, without copying or encoding conversion.
+    ///
+    /// Unlike [`new`], this method can be called in constant contexts, so
+    /// default values can be declared as constants or statics instead of
+    /// being initialized lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// const DEFAULT_NAME: &RawOsStr = RawOsStr::from_static("foobar");
+    /// assert_eq!(RawOsStr::new("foobar"), DEFAULT_NAME);
+    /// ```
+    ///
+    /// [`new`]: Self::new
+    #[inline]
+    #[must_use]
+    pub const fn from_static(string: &'static str) -> &'static Self {
+        unchecked::str_as_raw_os_str(string)
+    }
+
     /// Wraps a string, without copying or encoding conversion.
     ///
     /// # Examples
@@ -130,31 +225,51 @@ impl RawOsStr {
         Self::new(string)
     }
 
-    /// Equivalent to [`OsStr::from_encoded_bytes_unchecked`].
+    unchecked_fn! {
+        /// Equivalent to [`OsStr::from_encoded_bytes_unchecked`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::env;
+        /// # use std::io;
+        ///
+        /// use os_str_bytes::RawOsStr;
+        ///
+        /// let os_string = env::current_exe()?.into_os_string();
+        /// let raw = RawOsStr::new(&os_string);
+        /// let raw_bytes = raw.as_encoded_bytes();
+        /// assert_eq!(raw, unsafe {
+        ///     RawOsStr::from_encoded_bytes_unchecked(raw_bytes)
+        /// });
+        /// #
+        /// # Ok::<_, io::Error>(())
+        /// ```
+        #[allow(clippy::missing_safety_doc)]
+        #[inline]
+        #[must_use]
+        pub unsafe fn from_encoded_bytes_unchecked(string: &[u8]) -> &Self {
+            // SAFETY: This method has equivalent safety requirements.
+            unchecked!(unchecked::raw_os_str(string))
+        }
+    }
+
+    /// Equivalent to [`OsStrBytes::from_io_bytes`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::env;
-    /// # use std::io;
-    ///
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let os_string = env::current_exe()?.into_os_string();
-    /// let raw = RawOsStr::new(&os_string);
-    /// let raw_bytes = raw.as_encoded_bytes();
-    /// assert_eq!(raw, unsafe {
-    ///     RawOsStr::from_encoded_bytes_unchecked(raw_bytes)
-    /// });
-    /// #
-    /// # Ok::<_, io::Error>(())
+    /// assert_eq!(
+    ///     Some(RawOsStr::new("foobar")),
+    ///     RawOsStr::from_io_bytes(b"foobar"),
+    /// );
     /// ```
-    #[allow(clippy::missing_safety_doc)]
     #[inline]
     #[must_use]
-    pub unsafe fn from_encoded_bytes_unchecked(string: &[u8]) -> &Self {
-        // SAFETY: This struct has a layout that makes this operation safe.
-        unsafe { mem::transmute(string) }
+    pub fn from_io_bytes(string: &[u8]) -> Option<&Self> {
+        OsStr::from_io_bytes(string).map(Self::new)
     }
 
     if_conversions! {
@@ -216,45 +331,50 @@ impl RawOsStr {
     }
 
     if_conversions! {
-        /// Converts and wraps a byte string.
-        ///
-        /// # Safety
-        ///
-        /// The string must be valid for the [unspecified encoding] used by
-        /// this crate.
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// use std::env;
-        /// # use std::io;
-        ///
-        /// use os_str_bytes::RawOsStr;
-        ///
-        /// let os_string = env::current_exe()?.into_os_string();
-        /// let raw = RawOsStr::new(&os_string);
-        /// let raw_bytes = raw.to_raw_bytes();
-        /// assert_eq!(raw, unsafe {
-        ///     &*RawOsStr::cow_from_raw_bytes_unchecked(&raw_bytes)
-        /// });
-        /// #
-        /// # Ok::<_, io::Error>(())
-        /// ```
-        ///
-        /// [unspecified encoding]: super#encoding-conversions
-        #[deprecated(
-            since = "6.6.0",
-            note = "use `assert_cow_from_raw_bytes` or
-                    `from_encoded_bytes_unchecked` instead",
-        )]
-        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
-        #[inline]
-        #[must_use]
-        #[track_caller]
-        pub unsafe fn cow_from_raw_bytes_unchecked(
-            string: &[u8],
-        ) -> Cow<'_, Self> {
-            Self::assert_cow_from_raw_bytes(string)
+        unchecked_fn! {
+            /// Converts and wraps a byte string.
+            ///
+            /// # Safety
+            ///
+            /// The string must be valid for the [unspecified encoding] used by
+            /// this crate.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::env;
+            /// # use std::io;
+            ///
+            /// use os_str_bytes::RawOsStr;
+            ///
+            /// let os_string = env::current_exe()?.into_os_string();
+            /// let raw = RawOsStr::new(&os_string);
+            /// let raw_bytes = raw.to_raw_bytes();
+            /// assert_eq!(raw, unsafe {
+            ///     &*RawOsStr::cow_from_raw_bytes_unchecked(&raw_bytes)
+            /// });
+            /// #
+            /// # Ok::<_, io::Error>(())
+            /// ```
+            ///
+            /// [unspecified encoding]: super#encoding-conversions
+            #[deprecated(
+                since = "6.6.0",
+                note = "use `assert_cow_from_raw_bytes` or
+                        `from_encoded_bytes_unchecked` instead",
+            )]
+            #[cfg_attr(
+                os_str_bytes_docs_rs,
+                doc(cfg(feature = "conversions"))
+            )]
+            #[inline]
+            #[must_use]
+            #[track_caller]
+            pub unsafe fn cow_from_raw_bytes_unchecked(
+                string: &[u8],
+            ) -> Cow<'_, Self> {
+                Self::assert_cow_from_raw_bytes(string)
+            }
         }
     }
 
@@ -304,7 +424,118 @@ impl RawOsStr {
     pub fn as_os_str(&self) -> &OsStr {
         // SAFETY: This wrapper prevents violating the invariants of the
         // internal encoding for [OsStr].
-        unsafe { ext::os_str(&self.0) }
+        unchecked!(ext::os_str(&self.0))
+    }
+
+    /// Returns a pointer to the first byte of this string.
+    ///
+    /// The pointer can be passed to foreign code with [`len`] to describe the
+    /// bytes returned by [`as_encoded_bytes`]. It will only be valid while
+    /// this string is borrowed, and the bytes must not be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::slice;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// let bytes = unsafe { slice::from_raw_parts(raw.as_ptr(), raw.len()) };
+    /// assert_eq!(raw.as_encoded_bytes(), bytes);
+    /// ```
+    ///
+    /// [`as_encoded_bytes`]: Self::as_encoded_bytes
+    /// [`len`]: Self::len
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::byte_len`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}\u{F6}");
+    /// assert_eq!(5, raw.byte_len());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn byte_len(&self) -> usize {
+        self.as_os_str().byte_len()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::ceil_char_boundary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}\u{F6}");
+    /// assert_eq!(3, raw.ceil_char_boundary(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        self.as_os_str().ceil_char_boundary(index)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::chunk_by_size`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("fo\u{F6}bar");
+    /// assert!(raw.chunk_by_size(3).eq(["fo", "\u{F6}b", "ar"]));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn chunk_by_size(&self, size: usize) -> RawChunkBySize<'_> {
+        RawChunkBySize::new(self, size)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::code_points`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}\u{1F4A9}");
+    /// assert!(raw.code_points().eq([Ok(0x66), Ok(0xF6), Ok(0x1F4A9)]));
+    /// ```
+    #[inline]
+    pub fn code_points(&self) -> CodePoints<'_> {
+        CodePoints::new(self.as_os_str())
+    }
+
+    /// Equivalent to [`OsStrBytesExt::components_naive`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("/foo//bar/");
+    /// assert!(raw.components_naive(&['/']).eq(["foo", "bar"]));
+    /// ```
+    #[inline]
+    pub fn components_naive<'a>(
+        &'a self,
+        separators: &'a [char],
+    ) -> RawComponentsNaive<'a> {
+        RawComponentsNaive::new(self, separators)
     }
 
     /// Equivalent to [`OsStrBytesExt::contains`].
@@ -327,6 +558,51 @@ impl RawOsStr {
         self.as_os_str().contains(pat)
     }
 
+    /// Equivalent to [`OsStrBytesExt::contains_os`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.contains_os(RawOsStr::new("oo")));
+    /// assert!(!raw.contains_os(RawOsStr::new("of")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_os(&self, pat: &Self) -> bool {
+        self.as_os_str().contains_os(pat.as_os_str())
+    }
+
+    if_conversions! {
+        /// Equivalent to [`OsStrBytesExt::cross_platform_cmp`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::cmp::Ordering;
+        ///
+        /// use os_str_bytes::RawOsStr;
+        ///
+        /// let raw = RawOsStr::new("foo\u{E9}");
+        /// assert_eq!(
+        ///     Ordering::Less,
+        ///     raw.cross_platform_cmp(RawOsStr::new("foo\u{1F4A9}")),
+        /// );
+        /// assert_eq!(
+        ///     Ordering::Greater,
+        ///     raw.cross_platform_cmp(RawOsStr::new("foo")),
+        /// );
+        /// ```
+        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+        #[inline]
+        #[must_use]
+        pub fn cross_platform_cmp(&self, other: &Self) -> Ordering {
+            self.as_os_str().cross_platform_cmp(other.as_os_str())
+        }
+    }
+
     /// Equivalent to [`OsStrBytesExt::ends_with`].
     ///
     /// # Examples
@@ -347,6 +623,26 @@ impl RawOsStr {
         self.as_os_str().ends_with(pat)
     }
 
+    /// Equivalent to [`OsStrBytesExt::ends_with_any`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo.tar.gz");
+    /// assert!(raw.ends_with_any(&[".tar.gz", ".tgz"]));
+    /// assert!(!raw.ends_with_any(&[".zip", ".tar"]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ends_with_any<P>(&self, pats: &[P]) -> bool
+    where
+        P: Clone + Pattern,
+    {
+        self.as_os_str().ends_with_any(pats)
+    }
+
     if_conversions! {
         /// Equivalent to [`OsStrBytesExt::ends_with_os`].
         ///
@@ -367,102 +663,102 @@ impl RawOsStr {
         }
     }
 
-    /// Equivalent to [`OsStrBytesExt::find`].
+    /// Equivalent to [`OsStrBytesExt::ensure_prefix`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert_eq!(Some(1), raw.find("o"));
-    /// assert_eq!(None, raw.find("of"));
+    /// let raw = RawOsStr::new("foo");
+    /// assert_eq!("/foo", raw.ensure_prefix("/").as_ref());
     /// ```
     #[inline]
     #[must_use]
-    pub fn find<P>(&self, pat: P) -> Option<usize>
+    pub fn ensure_prefix<P>(&self, pat: P) -> Cow<'_, Self>
     where
         P: Pattern,
     {
-        self.as_os_str().find(pat)
+        Cow::from_os_str(self.as_os_str().ensure_prefix(pat))
     }
 
-    /// Equivalent to [`OsStrBytesExt::get_unchecked`].
+    /// Equivalent to [`OsStrBytesExt::ensure_suffix`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert_eq!("foo", unsafe { raw.get_unchecked(..3) });
-    /// assert_eq!("bar", unsafe { raw.get_unchecked(3..) });
+    /// let raw = RawOsStr::new("foo");
+    /// assert_eq!("foo/", raw.ensure_suffix("/").as_ref());
     /// ```
-    #[allow(clippy::missing_safety_doc)]
     #[inline]
     #[must_use]
-    pub unsafe fn get_unchecked<I>(&self, index: I) -> &Self
+    pub fn ensure_suffix<P>(&self, pat: P) -> Cow<'_, Self>
     where
-        I: SliceIndex,
+        P: Pattern,
     {
-        let string = self.as_os_str();
-        // SAFETY: This method has equivalent safety requirements.
-        Self::new(unsafe { string.get_unchecked(index) })
+        Cow::from_os_str(self.as_os_str().ensure_suffix(pat))
     }
 
-    /// Equivalent to [`OsStr::is_empty`].
+    /// Equivalent to [`OsStrBytesExt::eq_constant_time`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// assert!(RawOsStr::new("").is_empty());
-    /// assert!(!RawOsStr::new("foobar").is_empty());
+    /// let raw = RawOsStr::new("secret");
+    /// assert!(raw.eq_constant_time(RawOsStr::new("secret")));
+    /// assert!(!raw.eq_constant_time(RawOsStr::new("Secret")));
     /// ```
     #[inline]
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.as_os_str().is_empty()
+    pub fn eq_constant_time(&self, other: &Self) -> bool {
+        self.as_os_str().eq_constant_time(other.as_os_str())
     }
 
-    /// Equivalent to [`OsStrBytesExt::repeat`].
+    /// Equivalent to [`OsStrBytesExt::eq_os_ignoring_trailing_separators`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foo");
-    /// assert_eq!("foofoofoo", raw.repeat(3));
+    /// let raw = RawOsStr::new("foo/bar");
+    /// assert!(raw.eq_os_ignoring_trailing_separators(
+    ///     RawOsStr::new("foo/bar//"),
+    ///     &['/'],
+    /// ));
     /// ```
     #[inline]
     #[must_use]
-    pub fn repeat(&self, n: usize) -> RawOsString {
-        RawOsString::new(self.as_os_str().repeat(n))
+    pub fn eq_os_ignoring_trailing_separators(
+        &self,
+        other: &Self,
+        separators: &[char],
+    ) -> bool {
+        self.as_os_str()
+            .eq_os_ignoring_trailing_separators(other.as_os_str(), separators)
     }
 
-    /// Equivalent to [`OsStrBytesExt::rfind`].
+    /// Equivalent to [`OsStrBytesExt::escape_ascii`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert_eq!(Some(2), raw.rfind("o"));
-    /// assert_eq!(None, raw.rfind("of"));
+    /// let raw = RawOsStr::new("f\u{F6}\u{F6}");
+    /// assert_eq!(r"f\u{f6}\u{f6}", raw.escape_ascii());
     /// ```
     #[inline]
     #[must_use]
-    pub fn rfind<P>(&self, pat: P) -> Option<usize>
-    where
-        P: Pattern,
-    {
-        self.as_os_str().rfind(pat)
+    pub fn escape_ascii(&self) -> String {
+        self.as_os_str().escape_ascii()
     }
 
-    /// Equivalent to [`OsStrBytesExt::rsplit`].
+    /// Equivalent to [`OsStrBytesExt::find`].
     ///
     /// # Examples
     ///
@@ -470,103 +766,1051 @@ impl RawOsStr {
     /// use os_str_bytes::RawOsStr;
     ///
     /// let raw = RawOsStr::new("foobar");
-    /// assert!(raw.rsplit("o").eq(["bar", "", "f"]));
+    /// assert_eq!(Some(1), raw.find("o"));
+    /// assert_eq!(None, raw.find("of"));
     /// ```
     #[inline]
-    #[track_caller]
-    pub fn rsplit<P>(&self, pat: P) -> RawRSplit<'_, P>
-    where
+    #[must_use]
+    pub fn find<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern,
+    {
+        self.as_os_str().find(pat)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::find_any_of`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo\\bar/baz");
+    /// assert_eq!(Some(3), raw.find_any_of(b"/\\"));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn find_any_of<const N: usize>(
+        &self,
+        bytes: &[u8; N],
+    ) -> Option<usize> {
+        self.as_os_str().find_any_of(bytes)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::find_map_chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo\nbar");
+    /// assert_eq!(
+    ///     Some(3),
+    ///     raw.find_map_chunks(|index, invalid, string| {
+    ///         let index = index + invalid.as_os_str().len();
+    ///         string.find('\n').map(|x| index + x)
+    ///     }),
+    /// );
+    /// ```
+    #[inline]
+    pub fn find_map_chunks<F, T>(&self, f: F) -> Option<T>
+    where
+        F: FnMut(usize, &NonUnicodeOsStr, &str) -> Option<T>,
+    {
+        self.as_os_str().find_map_chunks(f)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::fingerprint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert_eq!(0xDCB27518FED9D577, RawOsStr::new("foo").fingerprint());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        self.as_os_str().fingerprint()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::floor_char_boundary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}\u{F6}");
+    /// assert_eq!(1, raw.floor_char_boundary(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        self.as_os_str().floor_char_boundary(index)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::first_char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert_eq!(Some('-'), RawOsStr::new("-9").first_char());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn first_char(&self) -> Option<char> {
+        self.as_os_str().first_char()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::get_range`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}o");
+    /// assert_eq!(Some(RawOsStr::new("f\u{F6}")), raw.get_range(..3));
+    /// assert_eq!(None, raw.get_range(..2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_range<I>(&self, index: I) -> Option<&Self>
+    where
+        I: SliceIndex,
+    {
+        self.as_os_str().get_range(index).map(Self::new)
+    }
+
+    unchecked_fn! {
+        /// Equivalent to [`OsStrBytesExt::get_unchecked`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use os_str_bytes::RawOsStr;
+        ///
+        /// let raw = RawOsStr::new("foobar");
+        /// assert_eq!("foo", unsafe { raw.get_unchecked(..3) });
+        /// assert_eq!("bar", unsafe { raw.get_unchecked(3..) });
+        /// ```
+        #[allow(clippy::missing_safety_doc)]
+        #[inline]
+        #[must_use]
+        pub unsafe fn get_unchecked<I>(&self, index: I) -> &Self
+        where
+            I: SliceIndex,
+        {
+            let string = self.as_os_str();
+            // SAFETY: This method has equivalent safety requirements.
+            Self::new(unchecked!(string.get_unchecked(index)))
+        }
+    }
+
+    /// Equivalent to [`OsStrBytesExt::has_extension`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo.d/bar.TAR.gz");
+    /// assert!(raw.has_extension(".tar.gz", true));
+    /// assert!(!raw.has_extension("tar.gz", false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn has_extension(&self, ext: &str, case_insensitive: bool) -> bool {
+        self.as_os_str().has_extension(ext, case_insensitive)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::has_invalid_sequences`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert!(!RawOsStr::new("foobar").has_invalid_sequences());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn has_invalid_sequences(&self) -> bool {
+        self.as_os_str().has_invalid_sequences()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::is_ascii`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert!(RawOsStr::new("foobar").is_ascii());
+    /// assert!(!RawOsStr::new("f\u{F6}\u{F6}bar").is_ascii());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_ascii(&self) -> bool {
+        OsStrBytesExt::is_ascii(self.as_os_str())
+    }
+
+    /// Equivalent to [`OsStr::is_empty`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert!(RawOsStr::new("").is_empty());
+    /// assert!(!RawOsStr::new("foobar").is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::is_unicode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert!(RawOsStr::new("f\u{F6}\u{F6}bar").is_unicode());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_unicode(&self) -> bool {
+        self.as_os_str().is_unicode()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::last_char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert_eq!(Some('\u{F6}'), RawOsStr::new("f\u{F6}\u{F6}").last_char());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn last_char(&self) -> Option<char> {
+        self.as_os_str().last_char()
+    }
+
+    /// Equivalent to [`OsStr::len`].
+    ///
+    /// The result is the number of bytes returned by [`as_encoded_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// assert_eq!(6, RawOsStr::new("foobar").len());
+    /// ```
+    ///
+    /// [`as_encoded_bytes`]: Self::as_encoded_bytes
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::len_chars`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo\u{1F4A9}");
+    /// assert_eq!(4, raw.len_chars());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn len_chars(&self) -> usize {
+        self.as_os_str().len_chars()
+    }
+
+    if_conversions! {
+        /// Equivalent to [`OsStrBytesExt::len_wide`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use os_str_bytes::RawOsStr;
+        ///
+        /// let raw = RawOsStr::new("foo\u{1F4A9}");
+        /// assert_eq!(5, raw.len_wide());
+        /// ```
+        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+        #[inline]
+        #[must_use]
+        pub fn len_wide(&self) -> usize {
+            self.as_os_str().len_wide()
+        }
+    }
+
+    /// Equivalent to [`OsStrBytesExt::map_utf8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo bar");
+    /// let uppercase = raw.map_utf8(|x| x.to_uppercase().into());
+    /// assert_eq!("FOO BAR", uppercase.as_ref());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn map_utf8<F>(&self, f: F) -> Cow<'_, Self>
+    where
+        F: FnMut(&str) -> Cow<'_, str>,
+    {
+        Cow::from_os_str(self.as_os_str().map_utf8(f))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::match_indices_os`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("../foo/../bar");
+    /// let pat = RawOsStr::new("../");
+    /// assert!(raw.match_indices_os(pat).eq([(0, pat), (7, pat)]));
+    /// ```
+    #[inline]
+    pub fn match_indices_os<'a>(
+        &'a self,
+        pat: &'a Self,
+    ) -> RawMatchIndicesOs<'a> {
+        RawMatchIndicesOs::new(self, pat)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::normalize_newlines`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo\r\nbar");
+    /// assert_eq!("foo\nbar", raw.normalize_newlines().as_ref());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn normalize_newlines(&self) -> Cow<'_, Self> {
+        Cow::from_os_str(self.as_os_str().normalize_newlines())
+    }
+
+    if_conversions! {
+        /// Equivalent to [`OsStrBytesExt::nt_case_eq`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use os_str_bytes::RawOsStr;
+        ///
+        /// let raw = RawOsStr::new("Stra\u{DF}e.txt");
+        /// assert!(raw.nt_case_eq(RawOsStr::new("STRA\u{DF}E.TXT")));
+        /// assert!(!raw.nt_case_eq(RawOsStr::new("STRASSE.TXT")));
+        /// ```
+        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+        #[inline]
+        #[must_use]
+        pub fn nt_case_eq(&self, other: &Self) -> bool {
+            self.as_os_str().nt_case_eq(other.as_os_str())
+        }
+    }
+
+    /// Equivalent to [`OsStrBytesExt::repeat`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo");
+    /// assert_eq!("foofoofoo", raw.repeat(3));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> RawOsString {
+        RawOsString::new(self.as_os_str().repeat(n))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::replace_prefix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("/src/foo.rs");
+    /// let replaced =
+    ///     raw.replace_prefix(RawOsStr::new("/src/"), RawOsStr::new("/dst/"));
+    /// assert_eq!("/dst/foo.rs", &*replaced);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn replace_prefix(&self, from: &Self, to: &Self) -> Cow<'_, Self> {
+        Cow::from_os_str(
+            self.as_os_str()
+                .replace_prefix(from.as_os_str(), to.as_os_str()),
+        )
+    }
+
+    /// Equivalent to [`OsStrBytesExt::rfind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert_eq!(Some(2), raw.rfind("o"));
+    /// assert_eq!(None, raw.rfind("of"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rfind<P>(&self, pat: P) -> Option<usize>
+    where
+        P: Pattern,
+    {
+        self.as_os_str().rfind(pat)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::rfind_any_of`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo\\bar/baz");
+    /// assert_eq!(Some(7), raw.rfind_any_of(b"/\\"));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn rfind_any_of<const N: usize>(
+        &self,
+        bytes: &[u8; N],
+    ) -> Option<usize> {
+        self.as_os_str().rfind_any_of(bytes)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::rsplit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.rsplit("o").eq(["bar", "", "f"]));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn rsplit<P>(&self, pat: P) -> RawRSplit<'_, P>
+    where
+        P: Pattern,
+    {
+        RawRSplit::new(self, pat)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::rsplit_once`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert_eq!(
+    ///     Some((RawOsStr::new("fo"), RawOsStr::new("bar"))),
+    ///     raw.rsplit_once("o"),
+    /// );
+    /// assert_eq!(None, raw.rsplit_once("of"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern,
+    {
+        self.as_os_str().rsplit_once(pat).map(Self::from_tuple)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::slice_from`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}o");
+    /// assert_eq!("o", raw.slice_from(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn slice_from(&self, index: usize) -> &Self {
+        Self::new(self.as_os_str().slice_from(index))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::slice_up_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}o");
+    /// assert_eq!("f", raw.slice_up_to(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn slice_up_to(&self, index: usize) -> &Self {
+        Self::new(self.as_os_str().slice_up_to(index))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.split("o").eq(["f", "", "bar"]));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn split<P>(&self, pat: P) -> RawSplit<'_, P>
+    where
+        P: Pattern,
+    {
+        RawSplit::new(self, pat)
+    }
+
+    /// Copies a substring into a new [`RawOsString`].
+    ///
+    /// This method is equivalent to indexing this string and calling
+    /// [`ToOwned::to_owned`] on the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not a [valid boundary].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert_eq!("bar", raw.slice_owned(3..));
+    /// ```
+    ///
+    /// [valid boundary]: OsStrBytesExt#indices
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn slice_owned<I>(&self, index: I) -> RawOsString
+    where
+        I: SliceIndex,
+    {
+        self[index].to_owned()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert_eq!(
+    ///     (RawOsStr::new("fo"), RawOsStr::new("obar")),
+    ///     raw.split_at(2),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        Self::from_tuple(self.as_os_str().split_at(mid))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_at_checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}o");
+    /// assert_eq!(
+    ///     Some((RawOsStr::new("f"), RawOsStr::new("\u{F6}o"))),
+    ///     raw.split_at_checked(1),
+    /// );
+    /// assert_eq!(None, raw.split_at_checked(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)> {
+        self.as_os_str().split_at_checked(mid).map(Self::from_tuple)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_first`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::CharOrInvalid;
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("\u{F6}foo");
+    /// assert_eq!(
+    ///     Some((CharOrInvalid::Char('\u{F6}'), RawOsStr::new("foo"))),
+    ///     raw.split_first(),
+    /// );
+    /// assert_eq!(None, RawOsStr::new("").split_first());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_first(&self) -> Option<(CharOrInvalid<'_>, &Self)> {
+        self.as_os_str()
+            .split_first()
+            .map(|(unit, string)| (unit, Self::new(string)))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_indices`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo,bar,baz");
+    /// let mut split = raw.split_indices(",");
+    /// assert_eq!(Some((0, RawOsStr::new("foo"))), split.next());
+    /// assert_eq!(Some((4, RawOsStr::new("bar"))), split.next());
+    /// assert_eq!(Some((8, RawOsStr::new("baz"))), split.next());
+    /// assert_eq!(None, split.next());
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn split_indices<P>(&self, pat: P) -> RawSplitIndices<'_, P>
+    where
+        P: Pattern,
+    {
+        RawSplitIndices::new(self, pat)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_keep`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::iter::SplitItem;
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo=bar");
+    /// assert!(raw.split_keep("=").eq([
+    ///     SplitItem::Field(RawOsStr::new("foo")),
+    ///     SplitItem::Separator("="),
+    ///     SplitItem::Field(RawOsStr::new("bar")),
+    /// ]));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn split_keep<P>(&self, pat: P) -> RawSplitKeep<'_, P>
+    where
+        P: Pattern,
+    {
+        RawSplitKeep::new(self, pat)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_last`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::CharOrInvalid;
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo\u{F6}");
+    /// assert_eq!(
+    ///     Some((CharOrInvalid::Char('\u{F6}'), RawOsStr::new("foo"))),
+    ///     raw.split_last(),
+    /// );
+    /// assert_eq!(None, RawOsStr::new("").split_last());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_last(&self) -> Option<(CharOrInvalid<'_>, &Self)> {
+        self.as_os_str()
+            .split_last()
+            .map(|(unit, string)| (unit, Self::new(string)))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_leading_digits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("64k");
+    /// assert_eq!(
+    ///     (Some("64"), RawOsStr::new("k")),
+    ///     raw.split_leading_digits(),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_leading_digits(&self) -> (Option<&str>, &Self) {
+        let (digits, suffix) = self.as_os_str().split_leading_digits();
+        (digits, Self::new(suffix))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_nul`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foo\0\0bar\0");
+    /// assert!(raw.split_nul().eq(["foo", "", "bar"]));
+    /// ```
+    #[inline]
+    pub fn split_nul(&self) -> RawSplitWith<'_, char> {
+        self.split_with('\0', SplitOptions::new().skip_trailing_empty(true))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_once`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert_eq!(
+    ///     Some((RawOsStr::new("f"), RawOsStr::new("obar"))),
+    ///     raw.split_once("o"),
+    /// );
+    /// assert_eq!(None, raw.split_once("of"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    where
+        P: Pattern,
+    {
+        self.as_os_str().split_once(pat).map(Self::from_tuple)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_once_any`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("key=value");
+    /// assert_eq!(
+    ///     Some((RawOsStr::new("key"), RawOsStr::new("value"), 0)),
+    ///     raw.split_once_any(&['=', ':']),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_once_any<P>(
+        &self,
+        pats: &[P],
+    ) -> Option<(&Self, &Self, usize)>
+    where
+        P: Clone + Pattern,
+    {
+        self.as_os_str()
+            .split_once_any(pats)
+            .map(|(prefix, suffix, index)| {
+                let (prefix, suffix) = Self::from_tuple((prefix, suffix));
+                (prefix, suffix, index)
+            })
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_once_ascii`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("--color=always");
+    /// assert_eq!(
+    ///     Some((RawOsStr::new("--color"), RawOsStr::new("always"))),
+    ///     raw.split_once_ascii(b'='),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn split_once_ascii(&self, byte: u8) -> Option<(&Self, &Self)> {
+        self.as_os_str()
+            .split_once_ascii(byte)
+            .map(Self::from_tuple)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_trailing_digits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("-j8");
+    /// assert_eq!(
+    ///     (RawOsStr::new("-j"), Some("8")),
+    ///     raw.split_trailing_digits(),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_trailing_digits(&self) -> (&Self, Option<&str>) {
+        let (prefix, digits) = self.as_os_str().split_trailing_digits();
+        (Self::new(prefix), digits)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::split_with`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    /// use os_str_bytes::SplitOptions;
+    ///
+    /// let options = SplitOptions::new().skip_empty(true);
+    /// let raw = RawOsStr::new("a//b/");
+    /// assert!(raw.split_with('/', options).eq(["a", "b"]));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn split_with<P>(
+        &self,
+        pat: P,
+        options: SplitOptions,
+    ) -> RawSplitWith<'_, P>
+    where
+        P: Pattern,
+    {
+        RawSplitWith::new(self, pat, options)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::starts_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.starts_with("foo"));
+    /// assert!(!raw.starts_with("bar"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn starts_with<P>(&self, pat: P) -> bool
+    where
         P: Pattern,
     {
-        RawRSplit::new(self, pat)
+        self.as_os_str().starts_with(pat)
     }
 
-    /// Equivalent to [`OsStrBytesExt::rsplit_once`].
+    /// Equivalent to [`OsStrBytesExt::starts_with_alphabetic`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert_eq!(
-    ///     Some((RawOsStr::new("fo"), RawOsStr::new("bar"))),
-    ///     raw.rsplit_once("o"),
-    /// );
-    /// assert_eq!(None, raw.rsplit_once("of"));
+    /// assert!(RawOsStr::new("\u{F6}").starts_with_alphabetic());
+    /// assert!(!RawOsStr::new("-f").starts_with_alphabetic());
     /// ```
     #[inline]
     #[must_use]
-    pub fn rsplit_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    pub fn starts_with_alphabetic(&self) -> bool {
+        self.as_os_str().starts_with_alphabetic()
+    }
+
+    /// Equivalent to [`OsStrBytesExt::starts_with_any`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("--foo");
+    /// assert!(raw.starts_with_any(&['-', '+']));
+    /// assert!(!raw.starts_with_any(&['/', '+']));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn starts_with_any<P>(&self, pats: &[P]) -> bool
     where
-        P: Pattern,
+        P: Clone + Pattern,
     {
-        self.as_os_str().rsplit_once(pat).map(Self::from_tuple)
+        self.as_os_str().starts_with_any(pats)
     }
 
-    /// Equivalent to [`OsStrBytesExt::split`].
+    /// Equivalent to [`OsStrBytesExt::starts_with_ascii_digit`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert!(raw.split("o").eq(["f", "", "bar"]));
+    /// assert!(RawOsStr::new("9").starts_with_ascii_digit());
+    /// assert!(!RawOsStr::new("f").starts_with_ascii_digit());
     /// ```
     #[inline]
-    #[track_caller]
-    pub fn split<P>(&self, pat: P) -> RawSplit<'_, P>
+    #[must_use]
+    pub fn starts_with_ascii_digit(&self) -> bool {
+        self.as_os_str().starts_with_ascii_digit()
+    }
+
+    if_conversions! {
+        /// Equivalent to [`OsStrBytesExt::starts_with_os`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use os_str_bytes::RawOsStr;
+        ///
+        /// let raw = RawOsStr::new("foobar");
+        /// assert!(raw.starts_with_os(RawOsStr::new("foo")));
+        /// assert!(!raw.starts_with_os(RawOsStr::new("bar")));
+        /// ```
+        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+        #[inline]
+        #[must_use]
+        pub fn starts_with_os(&self, pat: &Self) -> bool {
+            self.as_os_str().starts_with_os(pat.as_os_str())
+        }
+    }
+
+    /// Equivalent to [`OsStrBytesExt::strip_bom`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("\u{FEFF}foo");
+    /// assert_eq!("foo", raw.strip_bom());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn strip_bom(&self) -> &Self {
+        Self::new(self.as_os_str().strip_bom())
+    }
+
+    /// Equivalent to [`OsStrBytesExt::strip_circumfix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("[foo]");
+    /// assert_eq!(Some(RawOsStr::new("foo")), raw.strip_circumfix('[', ']'));
+    /// assert_eq!(None, raw.strip_circumfix('[', ')'));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn strip_circumfix<P, S>(&self, prefix: P, suffix: S) -> Option<&Self>
     where
         P: Pattern,
+        S: Pattern,
     {
-        RawSplit::new(self, pat)
+        self.as_os_str()
+            .strip_circumfix(prefix, suffix)
+            .map(Self::new)
     }
 
-    /// Equivalent to [`OsStrBytesExt::split_at`].
+    /// Equivalent to [`OsStrBytesExt::strip_prefix`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert_eq!(
-    ///     (RawOsStr::new("fo"), RawOsStr::new("obar")),
-    ///     raw.split_at(2),
-    /// );
+    /// let raw = RawOsStr::new("111foo1bar111");
+    /// assert_eq!(Some(RawOsStr::new("11foo1bar111")), raw.strip_prefix("1"));
+    /// assert_eq!(None, raw.strip_prefix("o"));
     /// ```
     #[inline]
     #[must_use]
-    #[track_caller]
-    pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
-        Self::from_tuple(self.as_os_str().split_at(mid))
+    pub fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
+    where
+        P: Pattern,
+    {
+        self.as_os_str().strip_prefix(pat).map(Self::new)
     }
 
-    /// Equivalent to [`OsStrBytesExt::split_once`].
+    /// Equivalent to [`OsStrBytesExt::strip_suffix`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("foobar");
-    /// assert_eq!(
-    ///     Some((RawOsStr::new("f"), RawOsStr::new("obar"))),
-    ///     raw.split_once("o"),
-    /// );
-    /// assert_eq!(None, raw.split_once("of"));
+    /// let raw = RawOsStr::new("111foo1bar111");
+    /// assert_eq!(Some(RawOsStr::new("111foo1bar11")), raw.strip_suffix("1"));
+    /// assert_eq!(None, raw.strip_suffix("o"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn split_once<P>(&self, pat: P) -> Option<(&Self, &Self)>
+    pub fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
     where
         P: Pattern,
     {
-        self.as_os_str().split_once(pat).map(Self::from_tuple)
+        self.as_os_str().strip_suffix(pat).map(Self::new)
     }
 
-    /// Equivalent to [`OsStrBytesExt::starts_with`].
+    /// Equivalent to [`OsStrBytes::to_io_bytes`].
     ///
     /// # Examples
     ///
@@ -574,39 +1818,197 @@ impl RawOsStr {
     /// use os_str_bytes::RawOsStr;
     ///
     /// let raw = RawOsStr::new("foobar");
-    /// assert!(raw.starts_with("foo"));
-    /// assert!(!raw.starts_with("bar"));
+    /// assert_eq!(Some(&b"foobar"[..]), raw.to_io_bytes());
     /// ```
     #[inline]
     #[must_use]
-    pub fn starts_with<P>(&self, pat: P) -> bool
-    where
-        P: Pattern,
-    {
-        self.as_os_str().starts_with(pat)
+    pub fn to_io_bytes(&self) -> Option<&'_ [u8]> {
+        self.as_os_str().to_io_bytes()
+    }
+
+    /// Equivalent to [`OsStrBytes::to_io_bytes_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("foobar");
+    /// assert_eq!(b"foobar", &*raw.to_io_bytes_lossy());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_io_bytes_lossy(&self) -> Cow<'_, [u8]> {
+        self.as_os_str().to_io_bytes_lossy()
+    }
+
+    /// Converts this representation back to a platform-native string.
+    ///
+    /// When possible, use [`RawOsStrCow::into_os_str`] for a more efficient
+    /// conversion on some platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// # use std::io;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let os_string = env::current_exe()?.into_os_string();
+    /// let raw = RawOsStr::new(&os_string);
+    /// assert_eq!(os_string, raw.to_os_str());
+    /// #
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[deprecated(since = "6.6.0", note = "use `as_os_str` instead")]
+    #[inline]
+    #[must_use]
+    pub fn to_os_str(&self) -> Cow<'_, OsStr> {
+        Cow::Borrowed(self.as_os_str())
     }
 
     if_conversions! {
-        /// Equivalent to [`OsStrBytesExt::starts_with_os`].
+        /// Equivalent to [`OsStrBytes::to_raw_bytes`].
         ///
         /// # Examples
         ///
         /// ```
         /// use os_str_bytes::RawOsStr;
         ///
-        /// let raw = RawOsStr::new("foobar");
-        /// assert!(raw.starts_with_os(RawOsStr::new("foo")));
-        /// assert!(!raw.starts_with_os(RawOsStr::new("bar")));
+        /// let string = "foobar";
+        /// let raw = RawOsStr::new(string);
+        /// assert_eq!(string.as_bytes(), &*raw.to_raw_bytes());
         /// ```
         #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
         #[inline]
         #[must_use]
-        pub fn starts_with_os(&self, pat: &Self) -> bool {
-            self.as_os_str().starts_with_os(pat.as_os_str())
+        pub fn to_raw_bytes(&self) -> Cow<'_, [u8]> {
+            self.as_os_str().to_raw_bytes()
+        }
+    }
+
+    /// Equivalent to [`OsStr::to_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let string = "foobar";
+    /// let raw = RawOsStr::new(string);
+    /// assert_eq!(Some(string), raw.to_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_str(&self) -> Option<&str> {
+        self.as_os_str().to_str()
+    }
+
+    /// Equivalent to [`OsStr::to_string_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::env;
+    /// # use std::io;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let os_string = env::current_exe()?.into_os_string();
+    /// let raw = RawOsStr::new(&os_string);
+    /// println!("{}", raw.to_str_lossy());
+    /// #
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_str_lossy(&self) -> Cow<'_, str> {
+        self.as_os_str().to_string_lossy()
+    }
+
+    #[cfg(any(target_os = "uefi", windows))]
+    if_conversions! {
+        /// Converts this string to UTF-16, which may be ill-formed.
+        ///
+        /// This method is equivalent to collecting the iterator returned by
+        /// [`OsStrExt::encode_wide`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use os_str_bytes::RawOsStr;
+        ///
+        /// let raw = RawOsStr::new("foo\u{1F4A9}");
+        /// assert_eq!([0x66, 0x6F, 0x6F, 0xD83D, 0xDCA9], *raw.to_wide());
+        /// ```
+        ///
+        /// [`OsStrExt::encode_wide`]: ::std::os::windows::ffi::OsStrExt::encode_wide
+        #[cfg_attr(
+            os_str_bytes_docs_rs,
+            doc(cfg(all(
+                feature = "conversions",
+                any(target_os = "uefi", windows),
+            )))
+        )]
+        #[inline]
+        #[must_use]
+        pub fn to_wide(&self) -> Vec<u16> {
+            let string = self.as_os_str();
+            let mut wide = Vec::with_capacity(string.len_wide());
+            wide.extend(string.encode_wide());
+            wide
         }
     }
 
-    /// Equivalent to [`OsStrBytesExt::strip_prefix`].
+    /// Equivalent to [`OsStrBytesExt::trim_end_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("111foo1bar111");
+    /// assert_eq!("111foo1bar", raw.trim_end_matches("1"));
+    /// assert_eq!("111foo1bar111", raw.trim_end_matches("o"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_end_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        Self::new(self.as_os_str().trim_end_matches(pat))
+    }
+
+    /// Equivalent to [`OsStrBytesExt::trim_end_matches_counted`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("111foo1bar111");
+    /// assert_eq!(
+    ///     (RawOsStr::new("111foo1bar"), 3),
+    ///     raw.trim_end_matches_counted("1"),
+    /// );
+    /// assert_eq!(
+    ///     (RawOsStr::new("111foo1bar111"), 0),
+    ///     raw.trim_end_matches_counted("o"),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_end_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern,
+    {
+        let (string, count) = self.as_os_str().trim_end_matches_counted(pat);
+        (Self::new(string), count)
+    }
+
+    /// Equivalent to [`OsStrBytesExt::trim_matches`].
     ///
     /// # Examples
     ///
@@ -614,181 +2016,192 @@ impl RawOsStr {
     /// use os_str_bytes::RawOsStr;
     ///
     /// let raw = RawOsStr::new("111foo1bar111");
-    /// assert_eq!(Some(RawOsStr::new("11foo1bar111")), raw.strip_prefix("1"));
-    /// assert_eq!(None, raw.strip_prefix("o"));
+    /// assert_eq!("foo1bar", raw.trim_matches("1"));
+    /// assert_eq!("111foo1bar111", raw.trim_matches("o"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn strip_prefix<P>(&self, pat: P) -> Option<&Self>
+    pub fn trim_matches<P>(&self, pat: P) -> &Self
     where
         P: Pattern,
     {
-        self.as_os_str().strip_prefix(pat).map(Self::new)
+        Self::new(self.as_os_str().trim_matches(pat))
     }
 
-    /// Equivalent to [`OsStrBytesExt::strip_suffix`].
+    /// Equivalent to [`OsStrBytesExt::trim_matches_counted`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("111foo1bar111");
-    /// assert_eq!(Some(RawOsStr::new("111foo1bar11")), raw.strip_suffix("1"));
-    /// assert_eq!(None, raw.strip_suffix("o"));
+    /// let raw = RawOsStr::new("--foo-");
+    /// assert_eq!(
+    ///     (RawOsStr::new("foo"), 2, 1),
+    ///     raw.trim_matches_counted('-'),
+    /// );
     /// ```
     #[inline]
     #[must_use]
-    pub fn strip_suffix<P>(&self, pat: P) -> Option<&Self>
+    pub fn trim_matches_counted<P>(&self, pat: P) -> (&Self, usize, usize)
     where
         P: Pattern,
     {
-        self.as_os_str().strip_suffix(pat).map(Self::new)
+        let (string, start_count, end_count) =
+            self.as_os_str().trim_matches_counted(pat);
+        (Self::new(string), start_count, end_count)
     }
 
-    /// Converts this representation back to a platform-native string.
-    ///
-    /// When possible, use [`RawOsStrCow::into_os_str`] for a more efficient
-    /// conversion on some platforms.
+    /// Equivalent to [`OsStrBytesExt::trim_quotes`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::env;
-    /// # use std::io;
-    ///
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let os_string = env::current_exe()?.into_os_string();
-    /// let raw = RawOsStr::new(&os_string);
-    /// assert_eq!(os_string, raw.to_os_str());
-    /// #
-    /// # Ok::<_, io::Error>(())
+    /// let raw = RawOsStr::new("'foo'");
+    /// assert_eq!("foo", raw.trim_quotes());
     /// ```
-    #[deprecated(since = "6.6.0", note = "use `as_os_str` instead")]
     #[inline]
     #[must_use]
-    pub fn to_os_str(&self) -> Cow<'_, OsStr> {
-        Cow::Borrowed(self.as_os_str())
+    pub fn trim_quotes(&self) -> &Self {
+        Self::new(self.as_os_str().trim_quotes())
     }
 
-    if_conversions! {
-        /// Equivalent to [`OsStrBytes::to_raw_bytes`].
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// use os_str_bytes::RawOsStr;
-        ///
-        /// let string = "foobar";
-        /// let raw = RawOsStr::new(string);
-        /// assert_eq!(string.as_bytes(), &*raw.to_raw_bytes());
-        /// ```
-        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
-        #[inline]
-        #[must_use]
-        pub fn to_raw_bytes(&self) -> Cow<'_, [u8]> {
-            self.as_os_str().to_raw_bytes()
-        }
+    /// Equivalent to [`OsStrBytesExt::trim_start_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("111foo1bar111");
+    /// assert_eq!("foo1bar111", raw.trim_start_matches("1"));
+    /// assert_eq!("111foo1bar111", raw.trim_start_matches("o"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_start_matches<P>(&self, pat: P) -> &Self
+    where
+        P: Pattern,
+    {
+        Self::new(self.as_os_str().trim_start_matches(pat))
     }
 
-    /// Equivalent to [`OsStr::to_str`].
+    /// Equivalent to [`OsStrBytesExt::trim_start_matches_counted`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let string = "foobar";
-    /// let raw = RawOsStr::new(string);
-    /// assert_eq!(Some(string), raw.to_str());
+    /// let raw = RawOsStr::new("--foo");
+    /// assert_eq!(
+    ///     (RawOsStr::new("foo"), 2),
+    ///     raw.trim_start_matches_counted('-'),
+    /// );
+    /// assert_eq!(
+    ///     (RawOsStr::new("--foo"), 0),
+    ///     raw.trim_start_matches_counted('+'),
+    /// );
     /// ```
     #[inline]
     #[must_use]
-    pub fn to_str(&self) -> Option<&str> {
-        self.as_os_str().to_str()
+    pub fn trim_start_matches_counted<P>(&self, pat: P) -> (&Self, usize)
+    where
+        P: Pattern,
+    {
+        let (string, count) = self.as_os_str().trim_start_matches_counted(pat);
+        (Self::new(string), count)
     }
 
-    /// Equivalent to [`OsStr::to_string_lossy`].
+    /// Equivalent to [`OsStrBytesExt::truncate_to_limit`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::env;
-    /// # use std::io;
-    ///
+    /// use os_str_bytes::LimitUnit;
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let os_string = env::current_exe()?.into_os_string();
-    /// let raw = RawOsStr::new(&os_string);
-    /// println!("{}", raw.to_str_lossy());
-    /// #
-    /// # Ok::<_, io::Error>(())
+    /// let raw = RawOsStr::new("foo\u{1F4A9}bar");
+    /// assert_eq!("foo", raw.truncate_to_limit(6, LimitUnit::Utf8));
+    /// assert_eq!(
+    ///     "foo\u{1F4A9}",
+    ///     raw.truncate_to_limit(5, LimitUnit::Utf16),
+    /// );
     /// ```
     #[inline]
     #[must_use]
-    pub fn to_str_lossy(&self) -> Cow<'_, str> {
-        self.as_os_str().to_string_lossy()
+    pub fn truncate_to_limit(
+        &self,
+        max_units: usize,
+        unit: LimitUnit,
+    ) -> &Self {
+        Self::new(self.as_os_str().truncate_to_limit(max_units, unit))
     }
 
-    /// Equivalent to [`OsStrBytesExt::trim_end_matches`].
+    /// Equivalent to [`OsStrBytesExt::try_rsplit`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("111foo1bar111");
-    /// assert_eq!("111foo1bar", raw.trim_end_matches("1"));
-    /// assert_eq!("111foo1bar111", raw.trim_end_matches("o"));
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.try_rsplit("o")?.eq(["bar", "", "f"]));
+    /// assert!(raw.try_rsplit("").is_err());
+    /// #
+    /// # Ok::<_, os_str_bytes::EmptyPatternError>(())
     /// ```
     #[inline]
-    #[must_use]
-    pub fn trim_end_matches<P>(&self, pat: P) -> &Self
+    pub fn try_rsplit<P>(
+        &self,
+        pat: P,
+    ) -> result::Result<RawRSplit<'_, P>, EmptyPatternError>
     where
         P: Pattern,
     {
-        Self::new(self.as_os_str().trim_end_matches(pat))
+        RawRSplit::try_new(self, pat)
     }
 
-    /// Equivalent to [`OsStrBytesExt::trim_matches`].
+    /// Equivalent to [`OsStrBytesExt::try_split`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
     ///
-    /// let raw = RawOsStr::new("111foo1bar111");
-    /// assert_eq!("foo1bar", raw.trim_matches("1"));
-    /// assert_eq!("111foo1bar111", raw.trim_matches("o"));
+    /// let raw = RawOsStr::new("foobar");
+    /// assert!(raw.try_split("o")?.eq(["f", "", "bar"]));
+    /// assert!(raw.try_split("").is_err());
+    /// #
+    /// # Ok::<_, os_str_bytes::EmptyPatternError>(())
     /// ```
     #[inline]
-    #[must_use]
-    pub fn trim_matches<P>(&self, pat: P) -> &Self
+    pub fn try_split<P>(
+        &self,
+        pat: P,
+    ) -> result::Result<RawSplit<'_, P>, EmptyPatternError>
     where
         P: Pattern,
     {
-        Self::new(self.as_os_str().trim_matches(pat))
+        RawSplit::try_new(self, pat)
     }
 
-    /// Equivalent to [`OsStrBytesExt::trim_start_matches`].
+    /// Equivalent to [`OsStrBytesExt::units`].
     ///
     /// # Examples
     ///
     /// ```
     /// use os_str_bytes::RawOsStr;
+    /// use os_str_bytes::Unit;
     ///
-    /// let raw = RawOsStr::new("111foo1bar111");
-    /// assert_eq!("foo1bar111", raw.trim_start_matches("1"));
-    /// assert_eq!("111foo1bar111", raw.trim_start_matches("o"));
+    /// let raw = RawOsStr::new("f\u{F6}");
+    /// assert!(raw.units().eq([Unit::Ascii(b'f'), Unit::Unicode('\u{F6}')]));
     /// ```
     #[inline]
-    #[must_use]
-    pub fn trim_start_matches<P>(&self, pat: P) -> &Self
-    where
-        P: Pattern,
-    {
-        Self::new(self.as_os_str().trim_start_matches(pat))
+    pub fn units(&self) -> Units<'_> {
+        Units::new(self.as_os_str())
     }
 
     /// Equivalent to [`OsStrBytesExt::utf8_chunks`].
@@ -815,6 +2228,25 @@ impl RawOsStr {
     pub fn utf8_chunks(&self) -> Utf8Chunks<'_> {
         Utf8Chunks::new(self.as_os_str())
     }
+
+    /// Equivalent to [`OsStrBytesExt::validate_boundary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsStr;
+    ///
+    /// let raw = RawOsStr::new("f\u{F6}o");
+    /// assert_eq!(Ok(()), raw.validate_boundary(1));
+    /// assert_eq!(2, raw.validate_boundary(2).unwrap_err().index());
+    /// ```
+    #[inline]
+    pub fn validate_boundary(
+        &self,
+        index: usize,
+    ) -> result::Result<(), BoundaryError> {
+        self.as_os_str().validate_boundary(index)
+    }
 }
 
 impl AsRef<Self> for RawOsStr {
@@ -831,6 +2263,13 @@ impl AsRef<OsStr> for RawOsStr {
     }
 }
 
+impl AsRef<Path> for RawOsStr {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        self.as_os_str().as_ref()
+    }
+}
+
 impl AsRef<RawOsStr> for OsStr {
     #[inline]
     fn as_ref(&self) -> &RawOsStr {
@@ -897,13 +2336,20 @@ impl ToOwned for RawOsStr {
 
     #[inline]
     fn to_owned(&self) -> Self::Owned {
-        RawOsString(self.0.to_owned())
+        RawOsString(Buffer::from_slice(&self.0), UnicodeCache::new())
     }
 }
 
 /// Extensions to [`Cow<RawOsStr>`] for additional conversions.
 ///
+/// Methods of [`RawOsStr`] that return substrings, such as
+/// [`RawOsStr::trim_matches`] and [`RawOsStr::strip_prefix`], can be applied
+/// without losing track of whether the string is borrowed by using
+/// [`map_borrowed`] and [`try_map`].
+///
 /// [`Cow<RawOsStr>`]: Cow
+/// [`map_borrowed`]: Self::map_borrowed
+/// [`try_map`]: Self::try_map
 #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
 pub trait RawOsStrCow<'a>: private::Sealed {
     /// Converts a platform-native string back to this representation, without
@@ -930,6 +2376,9 @@ pub trait RawOsStrCow<'a>: private::Sealed {
     /// Converts this representation back to a platform-native string, without
     /// copying or encoding conversion.
     ///
+    /// If this string is owned and the "small\_string" feature stored it
+    /// inline, it is copied to the heap.
+    ///
     /// # Examples
     ///
     /// ```
@@ -948,6 +2397,157 @@ pub trait RawOsStrCow<'a>: private::Sealed {
     /// ```
     #[must_use]
     fn into_os_str(self) -> Cow<'a, OsStr>;
+
+    /// Converts a path to this representation, without copying or encoding
+    /// conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use std::env;
+    /// # use std::io;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    /// use os_str_bytes::RawOsStrCow;
+    ///
+    /// let path = Cow::Owned(env::current_exe()?);
+    /// println!("{:?}", Cow::from_path(path));
+    /// #
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[must_use]
+    fn from_path(path: Cow<'a, Path>) -> Self;
+
+    /// Converts this representation to a path, without copying or encoding
+    /// conversion.
+    ///
+    /// If this string is owned and the "small\_string" feature stored it
+    /// inline, it is copied to the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use std::env;
+    /// # use std::io;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    /// use os_str_bytes::RawOsStrCow;
+    ///
+    /// let path = env::current_exe()?;
+    /// let raw = Cow::Borrowed(RawOsStr::new(&path));
+    /// assert_eq!(path, raw.into_path());
+    /// #
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[must_use]
+    fn into_path(self) -> Cow<'a, Path>;
+
+    /// Wraps a string literal, without copying or encoding conversion.
+    ///
+    /// This method is equivalent to [`RawOsStr::from_static`] but returns a
+    /// borrowed [`Cow`], which can be stored with owned values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    /// use os_str_bytes::RawOsStrCow;
+    ///
+    /// let mut names = vec![Cow::from_static("foo")];
+    /// names.push(Cow::Owned(RawOsStr::new("bar").to_owned()));
+    /// assert_eq!(Cow::Borrowed(RawOsStr::new("foo")), names[0]);
+    /// ```
+    #[must_use]
+    fn from_static(string: &'static str) -> Self;
+
+    /// Replaces this string with a substring returned by a closure.
+    ///
+    /// A borrowed string will remain borrowed for the same lifetime, so
+    /// substrings can be taken repeatedly without copying. An owned string
+    /// will be truncated if the substring is a prefix, and it will be copied
+    /// otherwise.
+    ///
+    /// Any method of [`RawOsStr`] that takes `&self` and returns `&Self` can
+    /// be used, including [`RawOsStr::slice_from`],
+    /// [`RawOsStr::slice_up_to`], [`RawOsStr::strip_bom`],
+    /// [`RawOsStr::trim_end_matches`], [`RawOsStr::trim_matches`],
+    /// [`RawOsStr::trim_quotes`], [`RawOsStr::trim_start_matches`], and
+    /// [`RawOsStr::truncate_to_limit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    /// use os_str_bytes::RawOsStrCow;
+    ///
+    /// let raw = Cow::Borrowed(RawOsStr::new("\"/foo/bar/\""));
+    /// let raw = raw
+    ///     .map_borrowed(RawOsStr::trim_quotes)
+    ///     .map_borrowed(|x| x.trim_matches('/'));
+    /// assert_eq!(Cow::Borrowed(RawOsStr::new("foo/bar")), raw);
+    /// ```
+    #[must_use]
+    fn map_borrowed<F>(self, f: F) -> Self
+    where
+        F: for<'b> FnOnce(&'b RawOsStr) -> &'b RawOsStr;
+
+    /// Equivalent to [`map_borrowed`], but the closure can fail.
+    ///
+    /// Any method of [`RawOsStr`] that takes `&self` and returns
+    /// `Option<&Self>` can be used, including [`RawOsStr::get_range`],
+    /// [`RawOsStr::strip_circumfix`], [`RawOsStr::strip_prefix`], and
+    /// [`RawOsStr::strip_suffix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use os_str_bytes::RawOsStr;
+    /// use os_str_bytes::RawOsStrCow;
+    ///
+    /// let raw = RawOsStr::new("foo.txt").to_owned();
+    /// let raw = Cow::<RawOsStr>::Owned(raw);
+    /// let raw = raw.try_map(|x| x.strip_suffix(".txt"));
+    /// assert_eq!(Some(RawOsStr::new("foo")), raw.as_deref());
+    ///
+    /// let raw = Cow::Borrowed(RawOsStr::new("foo.txt"));
+    /// assert_eq!(None, raw.try_map(|x| x.strip_prefix("bar")));
+    /// ```
+    ///
+    /// [`map_borrowed`]: Self::map_borrowed
+    #[must_use]
+    fn try_map<F>(self, f: F) -> Option<Self>
+    where
+        F: for<'b> FnOnce(&'b RawOsStr) -> Option<&'b RawOsStr>,
+        Self: Sized;
+}
+
+// Returns the length of a substring if it is a prefix of the string, which
+// allows the buffer of an owned string to be reused.
+fn prefix_length(string: &RawOsStr, substring: &RawOsStr) -> Option<usize> {
+    let string = string.as_encoded_bytes();
+    let substring = substring.as_encoded_bytes();
+    (string.as_ptr() == substring.as_ptr()).then_some(substring.len())
+}
+
+fn into_substring<F>(mut string: RawOsString, f: F) -> Option<RawOsString>
+where
+    F: for<'b> FnOnce(&'b RawOsStr) -> Option<&'b RawOsStr>,
+{
+    let substring = f(&string)?;
+    Some(if let Some(length) = prefix_length(&string, substring) {
+        string.truncate(length);
+        string
+    } else {
+        substring.to_owned()
+    })
 }
 
 impl<'a> RawOsStrCow<'a> for Cow<'a, RawOsStr> {
@@ -966,16 +2566,87 @@ impl<'a> RawOsStrCow<'a> for Cow<'a, RawOsStr> {
             Cow::Owned(string) => Cow::Owned(string.into_os_string()),
         }
     }
+
+    #[inline]
+    fn from_path(path: Cow<'a, Path>) -> Self {
+        match path {
+            Cow::Borrowed(path) => Cow::Borrowed(RawOsStr::from_path(path)),
+            Cow::Owned(path) => Cow::Owned(path.into()),
+        }
+    }
+
+    #[inline]
+    fn into_path(self) -> Cow<'a, Path> {
+        match self {
+            Cow::Borrowed(string) => Cow::Borrowed(string.as_ref()),
+            Cow::Owned(string) => Cow::Owned(string.into()),
+        }
+    }
+
+    #[inline]
+    fn from_static(string: &'static str) -> Self {
+        Cow::Borrowed(RawOsStr::from_static(string))
+    }
+
+    #[inline]
+    fn map_borrowed<F>(self, f: F) -> Self
+    where
+        F: for<'b> FnOnce(&'b RawOsStr) -> &'b RawOsStr,
+    {
+        match self {
+            Cow::Borrowed(string) => Cow::Borrowed(f(string)),
+            Cow::Owned(string) => Cow::Owned(
+                into_substring(string, |x| Some(f(x)))
+                    .expect("closure should return a substring"),
+            ),
+        }
+    }
+
+    #[inline]
+    fn try_map<F>(self, f: F) -> Option<Self>
+    where
+        F: for<'b> FnOnce(&'b RawOsStr) -> Option<&'b RawOsStr>,
+    {
+        match self {
+            Cow::Borrowed(string) => f(string).map(Cow::Borrowed),
+            Cow::Owned(string) => into_substring(string, f).map(Cow::Owned),
+        }
+    }
 }
 
 /// A container for owned byte strings converted by this crate.
 ///
 /// For more information, see [`RawOsStr`].
-#[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
-pub struct RawOsString(Vec<u8>);
+pub struct RawOsString(Buffer, UnicodeCache);
+
+impl RawOsString {
+    const fn from_vec(string: Vec<u8>) -> Self {
+        Self(Buffer::from_vec(string), UnicodeCache::new())
+    }
+
+    fn push_os_str(&mut self, string: &OsStr) {
+        let string = string.as_encoded_bytes();
+        self.1.reset();
+
+        // [OsString::push] joins surrogate pairs, so they are joined in place
+        // here.
+        #[cfg(any(target_os = "uefi", windows))]
+        let string = if let Some((ch, suffix)) =
+            raw::join_surrogates(&self.0, string)
+        {
+            self.0.truncate(self.0.len() - SURROGATE_LENGTH);
+            self.0.extend_from_slice(
+                ch.encode_utf8(&mut [0; MAX_UTF8_LENGTH]).as_bytes(),
+            );
+            suffix
+        } else {
+            string
+        };
+        self.0.extend_from_slice(string);
+    }
 
-impl RawOsString {
     /// Wraps a platform-native string, without copying or encoding conversion.
     ///
     /// # Examples
@@ -997,7 +2668,35 @@ impl RawOsString {
     where
         S: Into<OsString>,
     {
-        Self(string.into().into_encoded_bytes())
+        Self::from_vec(string.into().into_encoded_bytes())
+    }
+
+    /// Equivalent to [`OsString::with_capacity`].
+    ///
+    /// This method can be used with [`Extend`] to collect the chunks of
+    /// another string using a single allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::OsStrBytesExt;
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let os_string = OsStr::new("foo bar");
+    /// let mut raw = RawOsString::with_capacity(os_string.len());
+    /// raw.extend(
+    ///     os_string
+    ///         .utf8_chunks()
+    ///         .map(|(invalid, string)| (invalid, string.replace(' ', "_"))),
+    /// );
+    /// assert_eq!("foo_bar", raw);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Buffer::with_capacity(capacity), UnicodeCache::new())
     }
 
     /// Wraps a string, without copying or encoding conversion.
@@ -1015,33 +2714,86 @@ impl RawOsString {
     #[inline]
     #[must_use]
     pub fn from_string(string: String) -> Self {
-        Self(string.into_bytes())
+        Self::from_vec(string.into_bytes())
+    }
+
+    unchecked_fn! {
+        /// Equivalent to [`OsString::from_encoded_bytes_unchecked`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::env;
+        /// # use std::io;
+        ///
+        /// use os_str_bytes::RawOsString;
+        ///
+        /// let os_string = env::current_exe()?.into_os_string();
+        /// let raw = RawOsString::new(os_string);
+        /// let raw_bytes = raw.clone().into_encoded_vec();
+        /// assert_eq!(raw, unsafe {
+        ///     RawOsString::from_encoded_vec_unchecked(raw_bytes)
+        /// });
+        /// #
+        /// # Ok::<_, io::Error>(())
+        /// ```
+        #[allow(clippy::missing_safety_doc)]
+        #[inline]
+        #[must_use]
+        pub unsafe fn from_encoded_vec_unchecked(string: Vec<u8>) -> Self {
+            #[cfg(feature = "forbid_unsafe")]
+            unchecked::validate(&string);
+            Self::from_vec(string)
+        }
     }
 
-    /// Equivalent to [`OsString::from_encoded_bytes_unchecked`].
+    /// Equivalent to [`OsStringBytes::from_io_vec`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::env;
-    /// # use std::io;
-    ///
     /// use os_str_bytes::RawOsString;
     ///
-    /// let os_string = env::current_exe()?.into_os_string();
-    /// let raw = RawOsString::new(os_string);
-    /// let raw_bytes = raw.clone().into_encoded_vec();
-    /// assert_eq!(raw, unsafe {
-    ///     RawOsString::from_encoded_vec_unchecked(raw_bytes)
-    /// });
-    /// #
-    /// # Ok::<_, io::Error>(())
+    /// assert_eq!(
+    ///     Some(RawOsString::new("foobar")),
+    ///     RawOsString::from_io_vec(b"foobar".to_vec()),
+    /// );
     /// ```
-    #[allow(clippy::missing_safety_doc)]
     #[inline]
     #[must_use]
-    pub unsafe fn from_encoded_vec_unchecked(string: Vec<u8>) -> Self {
-        Self(string)
+    pub fn from_io_vec(string: Vec<u8>) -> Option<Self> {
+        OsString::from_io_vec(string).map(Self::new)
+    }
+
+    #[cfg(any(target_os = "uefi", windows))]
+    if_conversions! {
+        /// Converts and wraps a UTF-16 string, which may be ill-formed.
+        ///
+        /// This method is equivalent to [`OsStringExt::from_wide`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use os_str_bytes::RawOsString;
+        ///
+        /// let raw =
+        ///     RawOsString::from_wide(&[0x66, 0x6F, 0x6F, 0xD83D, 0xDCA9]);
+        /// assert_eq!("foo\u{1F4A9}", raw);
+        /// ```
+        ///
+        /// [`OsStringExt::from_wide`]: ::std::os::windows::ffi::OsStringExt::from_wide
+        #[cfg_attr(
+            os_str_bytes_docs_rs,
+            doc(cfg(all(
+                feature = "conversions",
+                any(target_os = "uefi", windows),
+            )))
+        )]
+        #[inline]
+        #[must_use]
+        pub fn from_wide(string: &[u16]) -> Self {
+            Self::new(OsString::from_wide(string))
+        }
     }
 
     if_conversions! {
@@ -1100,43 +2852,136 @@ impl RawOsString {
     }
 
     if_conversions! {
-        /// Converts and wraps a byte string.
-        ///
-        /// # Safety
-        ///
-        /// The string must be valid for the [unspecified encoding] used by
-        /// this crate.
-        ///
-        /// # Examples
-        ///
-        /// ```
-        /// use std::env;
-        /// # use std::io;
-        ///
-        /// use os_str_bytes::RawOsString;
-        ///
-        /// let os_string = env::current_exe()?.into_os_string();
-        /// let raw = RawOsString::new(os_string);
-        /// let raw_bytes = raw.clone().into_raw_vec();
-        /// assert_eq!(raw, unsafe {
-        ///     RawOsString::from_raw_vec_unchecked(raw_bytes)
-        /// });
-        /// #
-        /// # Ok::<_, io::Error>(())
-        /// ```
-        ///
-        /// [unspecified encoding]: super#encoding-conversions
-        #[deprecated(
-            since = "6.6.0",
-            note = "use `assert_from_raw_vec` or
-                    `from_encoded_vec_unchecked` instead",
-        )]
-        #[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
-        #[inline]
-        #[must_use]
-        #[track_caller]
-        pub unsafe fn from_raw_vec_unchecked(string: Vec<u8>) -> Self {
-            Self::assert_from_raw_vec(string)
+        unchecked_fn! {
+            /// Converts and wraps a byte string.
+            ///
+            /// # Safety
+            ///
+            /// The string must be valid for the [unspecified encoding] used by
+            /// this crate.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::env;
+            /// # use std::io;
+            ///
+            /// use os_str_bytes::RawOsString;
+            ///
+            /// let os_string = env::current_exe()?.into_os_string();
+            /// let raw = RawOsString::new(os_string);
+            /// let raw_bytes = raw.clone().into_raw_vec();
+            /// assert_eq!(raw, unsafe {
+            ///     RawOsString::from_raw_vec_unchecked(raw_bytes)
+            /// });
+            /// #
+            /// # Ok::<_, io::Error>(())
+            /// ```
+            ///
+            /// [unspecified encoding]: super#encoding-conversions
+            #[deprecated(
+                since = "6.6.0",
+                note = "use `assert_from_raw_vec` or
+                        `from_encoded_vec_unchecked` instead",
+            )]
+            #[cfg_attr(
+                os_str_bytes_docs_rs,
+                doc(cfg(feature = "conversions"))
+            )]
+            #[inline]
+            #[must_use]
+            #[track_caller]
+            pub unsafe fn from_raw_vec_unchecked(string: Vec<u8>) -> Self {
+                Self::assert_from_raw_vec(string)
+            }
+        }
+    }
+
+    /// Provides mutable access to this string for in-place ASCII edits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("C:/foo/bar".to_owned());
+    /// let mut raw_mut = raw.as_mut_raw();
+    /// raw_mut.make_ascii_lowercase();
+    /// raw_mut.replace_ascii('/', '\\');
+    /// assert_eq!("c:\\foo\\bar", raw);
+    /// ```
+    #[inline]
+    pub fn as_mut_raw(&mut self) -> RawOsStrMut<'_> {
+        // The cache does not need to be reset, since replacing ASCII bytes
+        // cannot change whether a string is valid UTF-8.
+        RawOsStrMut(&mut self.0)
+    }
+
+    /// Provides mutable access to this string as an [`OsString`].
+    ///
+    /// No copying or encoding conversion is performed, unless the
+    /// "small\_string" feature stored this string inline, in which case it is
+    /// copied to the heap. Changes made through the returned guard are applied
+    /// to this string when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo".to_owned());
+    /// raw.as_mut_os_string().push("bar");
+    /// assert_eq!("foobar", raw);
+    /// ```
+    #[inline]
+    pub fn as_mut_os_string(&mut self) -> OsStringMut<'_> {
+        self.1.reset();
+        let string = mem::take(&mut self.0).into_vec();
+        OsStringMut {
+            // SAFETY: This wrapper prevents violating the invariants of the
+            // internal encoding for [OsStr].
+            string: unchecked!(unchecked::os_string(string)),
+            raw: self,
+        }
+    }
+
+    /// Appends all of the given strings to the end of this string.
+    ///
+    /// This method is equivalent to [`Extend::extend`], but the iterator is
+    /// cloned to compute the total length of the strings, so that at most one
+    /// reallocation is needed. Cloning should be cheap for iterators over
+    /// slices and other collections.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let strings = ["foo", "bar", "baz"].map(OsStr::new);
+    ///
+    /// let mut raw = RawOsString::new("");
+    /// raw.extend_from_os_strs(&strings);
+    /// assert_eq!("foobarbaz", raw);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_os_strs<I>(&mut self, strings: I)
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: AsRef<OsStr>,
+    {
+        let strings = strings.into_iter();
+        self.reserve(total_len(strings.clone()));
+
+        for string in strings {
+            self.push_os_str(string.as_ref());
         }
     }
 
@@ -1160,6 +3005,7 @@ impl RawOsString {
     #[inline]
     pub fn clear(&mut self) {
         self.0.clear();
+        self.1.reset();
     }
 
     /// Equivalent to [`String::into_boxed_str`].
@@ -1176,7 +3022,30 @@ impl RawOsString {
     #[inline]
     #[must_use]
     pub fn into_box(self) -> Box<RawOsStr> {
-        self.0.into_boxed_slice().transmute_box()
+        self.0.into_vec().into_boxed_slice().transmute_box()
+    }
+
+    /// Equivalent to [`OsString::into_boxed_os_str`].
+    ///
+    /// Like [`into_box`], this method does not perform encoding conversion,
+    /// but it avoids the need to convert the result to [`OsStr`].
+    ///
+    /// [`into_box`]: Self::into_box
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let raw = RawOsString::new("foobar".to_owned());
+    /// assert_eq!(OsStr::new("foobar"), &*raw.into_boxed_os_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_boxed_os_str(self) -> Box<OsStr> {
+        self.into_os_string().into_boxed_os_str()
     }
 
     /// Equivalent to [`OsString::into_encoded_bytes`].
@@ -1200,12 +3069,47 @@ impl RawOsString {
     #[inline]
     #[must_use]
     pub fn into_encoded_vec(self) -> Vec<u8> {
-        self.0
+        self.0.into_vec()
+    }
+
+    /// Equivalent to [`OsStringBytes::into_io_vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let raw = RawOsString::new("foobar");
+    /// assert_eq!(Some(b"foobar".to_vec()), raw.into_io_vec());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_io_vec(self) -> Option<Vec<u8>> {
+        self.into_os_string().into_io_vec()
+    }
+
+    /// Equivalent to [`OsStringBytes::into_io_vec_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let raw = RawOsString::new("foobar");
+    /// assert_eq!(b"foobar".to_vec(), raw.into_io_vec_lossy());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_io_vec_lossy(self) -> Vec<u8> {
+        self.into_os_string().into_io_vec_lossy()
     }
 
     /// Converts this representation back to a platform-native string, without
     /// copying or encoding conversion.
     ///
+    /// If the "small\_string" feature stored this string inline, it is copied
+    /// to the heap.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1225,7 +3129,41 @@ impl RawOsString {
     pub fn into_os_string(self) -> OsString {
         // SAFETY: This wrapper prevents violating the invariants of the
         // internal encoding for [OsStr].
-        unsafe { OsString::from_encoded_bytes_unchecked(self.0) }
+        unchecked!(unchecked::os_string(self.0.into_vec()))
+    }
+
+    /// Decomposes this string into the pointer, length, and capacity of its
+    /// buffer.
+    ///
+    /// The buffer contains the same bytes as [`into_encoded_vec`]. It is not
+    /// copied if it is already allocated, so it can be adopted by foreign code
+    /// or another allocator. The caller becomes responsible for freeing it,
+    /// which is most easily done by passing the components to
+    /// [`from_raw_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::slice;
+    ///
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let raw = RawOsString::new("foobar");
+    /// let (pointer, length, capacity) = raw.into_raw_parts();
+    /// let bytes = unsafe { slice::from_raw_parts(pointer, length) };
+    /// assert_eq!(b"foobar", bytes);
+    /// # drop(unsafe {
+    /// #     RawOsString::from_raw_parts(pointer, length, capacity)
+    /// # });
+    /// ```
+    ///
+    /// [`from_raw_parts`]: Self::from_raw_parts
+    /// [`into_encoded_vec`]: Self::into_encoded_vec
+    #[inline]
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn into_raw_parts(self) -> (*mut u8, usize, usize) {
+        let mut string = ManuallyDrop::new(self.into_encoded_vec());
+        (string.as_mut_ptr(), string.len(), string.capacity())
     }
 
     if_conversions! {
@@ -1255,13 +3193,169 @@ impl RawOsString {
     /// ```
     /// use os_str_bytes::RawOsString;
     ///
-    /// let string = "foobar".to_owned();
-    /// let raw = RawOsString::new(string.clone());
-    /// assert_eq!(Ok(string), raw.into_string());
+    /// let string = "foobar".to_owned();
+    /// let raw = RawOsString::new(string.clone());
+    /// assert_eq!(Ok(string), raw.into_string());
+    /// ```
+    #[inline]
+    pub fn into_string(self) -> result::Result<String, Self> {
+        String::from_utf8(self.0.into_vec())
+            .map_err(|x| Self::from_vec(x.into_bytes()))
+    }
+
+    /// Equivalent to [`OsStringBytesExt::into_utf8_chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    ///
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let raw = RawOsString::new("foobar".to_owned());
+    /// assert!(raw
+    ///     .into_utf8_chunks()
+    ///     .eq([(OsString::new(), "foobar".to_owned())]));
+    /// ```
+    #[inline]
+    pub fn into_utf8_chunks(self) -> IntoUtf8Chunks {
+        self.into_os_string().into_utf8_chunks()
+    }
+
+    /// Equivalent to [`RawOsStr::is_unicode`], but the result is cached.
+    ///
+    /// The string is only validated the first time this method is called.
+    /// Later calls take constant time, until the string is modified. This
+    /// method is useful when the same long string is checked repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo");
+    /// assert!(raw.is_unicode_cached());
+    ///
+    /// raw.as_mut_os_string().push("bar");
+    /// assert!(raw.is_unicode_cached());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_unicode_cached(&self) -> bool {
+        self.1.get_or_init(|| self.is_unicode())
+    }
+
+    /// Equivalent to [`String::push`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo");
+    /// raw.push_char('\u{1F4A9}');
+    /// assert_eq!("foo\u{1F4A9}", raw);
+    /// ```
+    #[inline]
+    pub fn push_char(&mut self, ch: char) {
+        self.1.reset();
+        self.0.extend_from_slice(
+            ch.encode_utf8(&mut [0; MAX_UTF8_LENGTH]).as_bytes(),
+        );
+    }
+
+    /// Appends a UTF-16 code unit to the end of this string.
+    ///
+    /// Unpaired surrogates are allowed, since they can be represented by
+    /// [`OsString`] on these platforms. When a low surrogate is pushed
+    /// directly after a high surrogate, the two are joined into a single
+    /// character, like [`OsStringExt::from_wide`] would join them. Thus, a
+    /// string can be reassembled by pushing each of its code units in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo");
+    /// raw.push_code_unit(0xD83D);
+    /// assert_eq!(None, raw.to_str());
+    /// raw.push_code_unit(0xDCA9);
+    /// assert_eq!("foo\u{1F4A9}", raw);
+    /// ```
+    ///
+    /// [`OsStringExt::from_wide`]: ::std::os::windows::ffi::OsStringExt::from_wide
+    #[cfg_attr(
+        os_str_bytes_docs_rs,
+        doc(cfg(all(
+            feature = "raw_os_str",
+            any(target_os = "uefi", windows),
+        )))
+    )]
+    #[cfg(any(target_os = "uefi", windows))]
+    #[inline]
+    pub fn push_code_unit(&mut self, unit: u16) {
+        if let Some(ch) = char::from_u32(unit.into()) {
+            self.push_char(ch);
+        } else {
+            // Surrogate pairs are joined when pushed.
+            self.push_os_str(&OsString::from_wide(&[unit]));
+        }
+    }
+
+    /// Equivalent to [`String::reserve`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo");
+    /// raw.reserve(10);
+    /// assert_eq!("foo", raw);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Reserves capacity for appending all of the given strings.
+    ///
+    /// The total length is computed once, so that pushing the strings
+    /// afterward will not reallocate. On platforms where surrogate pairs can
+    /// be joined when strings are appended, the reserved capacity may be
+    /// slightly larger than needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let strings = [OsStr::new("foo"), OsStr::new("bar")];
+    ///
+    /// let mut raw = RawOsString::new("");
+    /// raw.reserve_for(&strings);
+    /// raw.extend(strings);
+    /// assert_eq!("foobar", raw);
     /// ```
     #[inline]
-    pub fn into_string(self) -> result::Result<String, Self> {
-        String::from_utf8(self.0).map_err(|x| Self(x.into_bytes()))
+    #[track_caller]
+    pub fn reserve_for<S>(&mut self, strings: &[S])
+    where
+        S: AsRef<OsStr>,
+    {
+        self.reserve(total_len(strings));
     }
 
     /// Equivalent to [`String::shrink_to_fit`].
@@ -1309,7 +3403,8 @@ impl RawOsString {
     pub fn split_off(&mut self, at: usize) -> Self {
         self.check_bound(at);
 
-        Self(self.0.split_off(at))
+        self.1.reset();
+        Self(self.0.split_off(at), UnicodeCache::new())
     }
 
     /// Equivalent to [`String::truncate`].
@@ -1335,6 +3430,52 @@ impl RawOsString {
         self.check_bound(new_len);
 
         self.0.truncate(new_len);
+        self.1.reset();
+    }
+
+    /// Equivalent to [`truncate`], but the length is rounded down to the
+    /// nearest [valid boundary] instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("f\u{F6}\u{F6}".to_owned());
+    /// raw.truncate_lossy(2);
+    /// assert_eq!("f", raw);
+    /// ```
+    ///
+    /// [`truncate`]: Self::truncate
+    /// [valid boundary]: OsStrBytesExt#indices
+    #[inline]
+    pub fn truncate_lossy(&mut self, new_len: usize) {
+        let new_len = self.floor_char_boundary(new_len);
+        self.0.truncate(new_len);
+        self.1.reset();
+    }
+
+    /// Equivalent to [`OsStringBytesExt::unescape_ascii`].
+    ///
+    /// # Errors
+    ///
+    /// See documentation for [`OsStringBytesExt::unescape_ascii`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// assert_eq!(
+    ///     Ok(RawOsString::new("f\u{F6}\u{F6}")),
+    ///     RawOsString::unescape_ascii(r"f\u{f6}\u{f6}"),
+    /// );
+    /// ```
+    #[inline]
+    pub fn unescape_ascii(
+        string: &str,
+    ) -> result::Result<Self, UnescapeError> {
+        OsString::unescape_ascii(string).map(Self::new)
     }
 }
 
@@ -1345,6 +3486,13 @@ impl AsRef<OsStr> for RawOsString {
     }
 }
 
+impl AsRef<Path> for RawOsString {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        (**self).as_ref()
+    }
+}
+
 impl AsRef<RawOsStr> for RawOsString {
     #[inline]
     fn as_ref(&self) -> &RawOsStr {
@@ -1366,7 +3514,101 @@ impl Deref for RawOsString {
     fn deref(&self) -> &Self::Target {
         // SAFETY: This wrapper prevents violating the invariants of the
         // internal encoding for [OsStr].
-        unsafe { RawOsStr::from_encoded_bytes_unchecked(&self.0) }
+        unchecked!(RawOsStr::from_encoded_bytes_unchecked(&self.0))
+    }
+}
+
+macro_rules! r#impl {
+    ( [ $($lifetime:lifetime)? ] $type:ty , $convert_fn:expr ) => {
+        impl$(<$lifetime>)? Extend<$type> for RawOsString {
+            #[inline]
+            fn extend<I>(&mut self, iter: I)
+            where
+                I: IntoIterator<Item = $type>,
+            {
+                for string in iter.into_iter().map($convert_fn) {
+                    self.push_os_str(string.as_ref());
+                }
+            }
+        }
+
+        impl$(<$lifetime>)? FromIterator<$type> for RawOsString {
+            #[inline]
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: IntoIterator<Item = $type>,
+            {
+                let mut string = Self::default();
+                string.extend(iter);
+                string
+            }
+        }
+    };
+}
+r#impl!(['a] &'a OsStr, |x| x);
+r#impl!(['a] &'a RawOsStr, RawOsStr::as_os_str);
+r#impl!(['a] Cow<'a, OsStr>, |x| x);
+r#impl!(['a] Cow<'a, RawOsStr>, RawOsStrCow::into_os_str);
+r#impl!([] OsString, |x| x);
+r#impl!([] RawOsString, RawOsString::into_os_string);
+
+/// Appends the items of [`Utf8Chunks`] or [`IntoUtf8Chunks`], which may have
+/// been transformed.
+///
+/// Each item is appended as its invalid sequence followed by its Unicode
+/// substring. Thus, collecting the unchanged items of either iterator
+/// reconstructs the original string.
+impl<N, S> Extend<(N, S)> for RawOsString
+where
+    N: AsRef<OsStr>,
+    S: AsRef<str>,
+{
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (N, S)>,
+    {
+        for (invalid, valid) in iter {
+            self.push_os_str(invalid.as_ref());
+            self.push_os_str(valid.as_ref().as_ref());
+        }
+    }
+}
+
+/// Collects the items of [`Utf8Chunks`] or [`IntoUtf8Chunks`], which may
+/// have been transformed.
+///
+/// Each item is appended as its invalid sequence followed by its Unicode
+/// substring.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::OsStrBytesExt;
+/// use os_str_bytes::RawOsString;
+///
+/// let os_string = OsStr::new("foo");
+/// let raw: RawOsString = os_string
+///     .utf8_chunks()
+///     .map(|(invalid, string)| (invalid, string.to_uppercase()))
+///     .collect();
+/// assert_eq!("FOO", raw);
+/// ```
+impl<N, S> FromIterator<(N, S)> for RawOsString
+where
+    N: AsRef<OsStr>,
+    S: AsRef<str>,
+{
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (N, S)>,
+    {
+        let mut string = Self::default();
+        string.extend(iter);
+        string
     }
 }
 
@@ -1380,7 +3622,7 @@ impl From<RawOsString> for Box<RawOsStr> {
 impl From<Box<RawOsStr>> for RawOsString {
     #[inline]
     fn from(value: Box<RawOsStr>) -> Self {
-        Self(value.transmute_box::<[_]>().into_vec())
+        Self::from_vec(value.transmute_box::<[_]>().into_vec())
     }
 }
 
@@ -1405,6 +3647,20 @@ impl From<RawOsString> for OsString {
     }
 }
 
+impl From<PathBuf> for RawOsString {
+    #[inline]
+    fn from(value: PathBuf) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<RawOsString> for PathBuf {
+    #[inline]
+    fn from(value: RawOsString) -> Self {
+        value.into_os_string().into()
+    }
+}
+
 impl From<String> for RawOsString {
     #[inline]
     fn from(value: String) -> Self {
@@ -1422,6 +3678,18 @@ macro_rules! r#impl {
                     .finish()
             }
         }
+
+        // This implementation must be the same as for [OsStr], so that
+        // [Equivalent] can be implemented.
+        impl Hash for $type {
+            #[inline]
+            fn hash<H>(&self, state: &mut H)
+            where
+                H: Hasher,
+            {
+                self.as_os_str().hash(state);
+            }
+        }
     };
 }
 r#impl!(RawOsStr);
@@ -1439,6 +3707,256 @@ where
     }
 }
 
+#[cfg(feature = "equivalent")]
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "equivalent")))]
+impl Equivalent<RawOsString> for OsStr {
+    #[inline]
+    fn equivalent(&self, key: &RawOsString) -> bool {
+        self == key.as_os_str()
+    }
+}
+
+#[cfg(feature = "equivalent")]
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "equivalent")))]
+impl Equivalent<RawOsString> for &OsStr {
+    #[inline]
+    fn equivalent(&self, key: &RawOsString) -> bool {
+        (**self).equivalent(key)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "zeroize")))]
+impl Zeroize for RawOsString {
+    /// Overwrites the string with zeros and clears it.
+    ///
+    /// Only the current buffer is overwritten. Copies made by previous
+    /// reallocations or conversions are not affected.
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+        self.1.reset();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "zeroize")))]
+impl ZeroizeOnDrop for RawOsString {}
+
+#[track_caller]
+fn check_ascii(ch: char) -> u8 {
+    assert!(ch.is_ascii(), "character is not ASCII: {:?}", ch);
+    ch as u8
+}
+
+#[track_caller]
+fn total_len<I>(strings: I) -> usize
+where
+    I: IntoIterator,
+    I::Item: AsRef<OsStr>,
+{
+    strings
+        .into_iter()
+        .try_fold(0_usize, |length, x| length.checked_add(x.as_ref().len()))
+        .expect("capacity overflow")
+}
+
+/// A mutable reference to a [`RawOsString`] that only allows ASCII edits.
+///
+/// ASCII bytes are never part of other characters in any supported encoding,
+/// so replacing them with other ASCII bytes cannot make the string invalid.
+/// This struct allows such edits to be made without reallocating.
+///
+/// This struct is created by [`RawOsString::as_mut_raw`].
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+pub struct RawOsStrMut<'a>(&'a mut [u8]);
+
+impl RawOsStrMut<'_> {
+    /// Returns the string as an immutable reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo".to_owned());
+    /// assert_eq!("foo", raw.as_mut_raw().as_raw_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_raw_str(&self) -> &RawOsStr {
+        // SAFETY: This wrapper prevents violating the invariants of the
+        // internal encoding for [OsStr].
+        unchecked!(RawOsStr::from_encoded_bytes_unchecked(self.0))
+    }
+
+    /// Returns a mutable reference to a substring, which only allows ASCII
+    /// edits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not a [valid boundary].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo/bar".to_owned());
+    /// raw.as_mut_raw().index_mut(4..).make_ascii_uppercase();
+    /// assert_eq!("foo/BAR", raw);
+    /// ```
+    ///
+    /// [valid boundary]: OsStrBytesExt#indices
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn index_mut<I>(&mut self, index: I) -> RawOsStrMut<'_>
+    where
+        I: SliceIndex,
+    {
+        // SAFETY: This wrapper prevents violating the invariants of the
+        // internal encoding for [OsStr].
+        RawOsStrMut(unchecked!(index.__index_mut(self.0)))
+    }
+
+    /// Equivalent to [`str::make_ascii_lowercase`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("FOO".to_owned());
+    /// raw.as_mut_raw().make_ascii_lowercase();
+    /// assert_eq!("foo", raw);
+    /// ```
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.make_ascii_lowercase();
+    }
+
+    /// Equivalent to [`str::make_ascii_uppercase`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo".to_owned());
+    /// raw.as_mut_raw().make_ascii_uppercase();
+    /// assert_eq!("FOO", raw);
+    /// ```
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.make_ascii_uppercase();
+    }
+
+    /// Replaces all occurrences of an ASCII character with another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either character is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("foo/bar".to_owned());
+    /// raw.as_mut_raw().replace_ascii('/', '\\');
+    /// assert_eq!("foo\\bar", raw);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn replace_ascii(&mut self, from: char, to: char) {
+        let from = check_ascii(from);
+        let to = check_ascii(to);
+
+        for byte in self.0.iter_mut().filter(|x| **x == from) {
+            *byte = to;
+        }
+    }
+
+    /// Replaces the ASCII character at an index with another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either character is not ASCII or the index is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let mut raw = RawOsString::new("c:\\foo".to_owned());
+    /// raw.as_mut_raw().set_ascii(0, 'C');
+    /// assert_eq!("C:\\foo", raw);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn set_ascii(&mut self, index: usize, ch: char) {
+        let byte = &mut self.0[index];
+        assert!(
+            byte.is_ascii(),
+            "byte index {} is not an ASCII character",
+            index,
+        );
+        *byte = check_ascii(ch);
+    }
+}
+
+impl Debug for RawOsStrMut<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawOsStrMut")
+            .field(&self.as_raw_str())
+            .finish()
+    }
+}
+
+/// A guard providing mutable access to a [`RawOsString`] as an [`OsString`].
+///
+/// This struct is created by [`RawOsString::as_mut_os_string`]. The original
+/// string is updated when it is dropped.
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+pub struct OsStringMut<'a> {
+    raw: &'a mut RawOsString,
+    string: OsString,
+}
+
+impl Debug for OsStringMut<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OsStringMut").field(&self.string).finish()
+    }
+}
+
+impl Deref for OsStringMut<'_> {
+    type Target = OsString;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.string
+    }
+}
+
+impl DerefMut for OsStringMut<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.string
+    }
+}
+
+impl Drop for OsStringMut<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.raw.0 =
+            Buffer::from_vec(mem::take(&mut self.string).into_encoded_bytes());
+    }
+}
+
 macro_rules! r#impl {
     ( $type:ty , $other_type:ty ) => {
         impl PartialEq<$other_type> for $type {
@@ -1472,3 +3990,47 @@ r#impl!(RawOsString, OsString);
 r#impl!(RawOsString, str);
 r#impl!(RawOsString, &str);
 r#impl!(RawOsString, String);
+
+// These comparisons are equivalent to those between [OsStr] and [Path], which
+// compare paths by their components.
+macro_rules! r#impl {
+    ( $type:ty , $other_type:ty ) => {
+        impl PartialEq<$other_type> for $type {
+            #[inline]
+            fn eq(&self, other: &$other_type) -> bool {
+                let raw: &Path = self.as_ref();
+                let other: &Path = other.as_ref();
+                raw == other
+            }
+        }
+
+        impl PartialEq<$type> for $other_type {
+            #[inline]
+            fn eq(&self, other: &$type) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<$other_type> for $type {
+            #[inline]
+            fn partial_cmp(&self, other: &$other_type) -> Option<Ordering> {
+                let raw: &Path = self.as_ref();
+                let other: &Path = other.as_ref();
+                raw.partial_cmp(other)
+            }
+        }
+
+        impl PartialOrd<$type> for $other_type {
+            #[inline]
+            fn partial_cmp(&self, other: &$type) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+r#impl!(RawOsStr, Path);
+r#impl!(RawOsStr, PathBuf);
+r#impl!(&RawOsStr, PathBuf);
+r#impl!(RawOsString, Path);
+r#impl!(RawOsString, &Path);
+r#impl!(RawOsString, PathBuf);
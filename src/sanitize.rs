@@ -0,0 +1,350 @@
+//! Sanitization of file names.
+//!
+//! Names that are valid on one platform may be rejected by filesystems on
+//! another. The [`Sanitizer`] provided by this module rewrites names to avoid
+//! the restrictions imposed by Windows, which are the strictest in common use.
+//! Since they are applied on all platforms, the result can be used portably.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::ffi::OsStr;
+//!
+//! use os_str_bytes::sanitize;
+//!
+//! assert_eq!("foo_bar", &*sanitize::sanitize(OsStr::new("foo:bar")));
+//! assert_eq!("_CON.txt", &*sanitize::sanitize(OsStr::new("CON.txt")));
+//! assert_eq!("foo", &*sanitize::sanitize(OsStr::new("foo. ")));
+//! ```
+
+#![cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "raw_os_str")))]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use super::ext;
+use super::OsStrBytesExt;
+use super::OsStringBytesExt;
+
+const DEFAULT_REPLACEMENT: char = '_';
+
+const RESERVED_NAMES: &[&str] = &[
+    "AUX", "CON", "CONIN$", "CONOUT$", "NUL", "PRN", "COM0", "COM1", "COM2",
+    "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9", "LPT0", "LPT1",
+    "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+fn is_reserved_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '\0'..='\x1F' | '"' | '*' | '/' | ':' | '<' | '>' | '?' | '\\' | '|',
+    )
+}
+
+fn is_reserved_name(string: &OsStr) -> bool {
+    let stem = string.split(".").next().unwrap_or(string);
+    stem.trim_end_matches(" ").to_str().is_some_and(|stem| {
+        RESERVED_NAMES
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(stem))
+    })
+}
+
+fn trim_end(string: &OsStr) -> usize {
+    let mut string = string;
+    while let Some(prefix) = string
+        .strip_suffix('.')
+        .or_else(|| string.strip_suffix(' '))
+    {
+        string = prefix;
+    }
+    string.as_encoded_bytes().len()
+}
+
+// Copies the string lazily, so that it is only allocated once a replacement
+// is made.
+struct Output<'a> {
+    string: &'a OsStr,
+    index: usize,
+    result: Option<OsString>,
+}
+
+impl<'a> Output<'a> {
+    const fn new(string: &'a OsStr) -> Self {
+        Self {
+            string,
+            index: 0,
+            result: None,
+        }
+    }
+
+    fn substring(&self, length: usize) -> &'a OsStr {
+        let string = self.string.as_encoded_bytes();
+        // SAFETY: Callers only pass lengths of complete UTF-8 sequences or
+        // invalid chunks, which end at valid boundaries.
+        unchecked!(ext::os_str(&string[self.index..self.index + length]))
+    }
+
+    fn keep(&mut self, length: usize) {
+        let substring = self.substring(length);
+        if let Some(result) = &mut self.result {
+            result.push(substring);
+        }
+        self.index += length;
+    }
+
+    fn replace(&mut self, length: usize, replacement: Option<char>) {
+        let prefix = self.string.as_encoded_bytes();
+        // SAFETY: The index is always a valid boundary.
+        let prefix = unchecked!(ext::os_str(&prefix[..self.index]));
+        let result = self.result.get_or_insert_with(|| prefix.to_owned());
+        if let Some(replacement) = replacement {
+            result.push(replacement.encode_utf8(&mut [0; 4]));
+        }
+        self.index += length;
+    }
+
+    fn finish(self) -> Cow<'a, OsStr> {
+        internal_assert_eq!(self.string.len(), self.index);
+        self.result.map_or(Cow::Borrowed(self.string), Cow::Owned)
+    }
+}
+
+/// A configurable sanitizer for file names.
+///
+/// By default, all of the following are applied:
+/// - Characters reserved by Windows (`<>:"/\|?*` and ASCII control
+///   characters) are replaced with `'_'`.
+/// - Trailing dots and spaces are removed.
+/// - Names reserved for devices on Windows (e.g., "CON" and "NUL.txt") are
+///   prefixed with `'_'`.
+///
+/// Each of these steps can be configured using the builder methods.
+/// Optionally, portions of the name that are not valid Unicode can also be
+/// replaced.
+///
+/// The result may be empty, which is not a valid name, if the original name
+/// only consists of removed characters.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::sanitize::Sanitizer;
+///
+/// let sanitizer = Sanitizer::new().replacement(None).trim_end(false);
+/// assert_eq!("foobar.", &*sanitizer.sanitize(OsStr::new("foo?bar.")));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct Sanitizer {
+    replacement: Option<char>,
+    non_unicode_replacement: Option<char>,
+    reserved_names: bool,
+    trim_end: bool,
+}
+
+impl Sanitizer {
+    /// Creates a sanitizer with the default configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::sanitize::Sanitizer;
+    ///
+    /// assert_eq!(Sanitizer::default(), Sanitizer::new());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            replacement: Some(DEFAULT_REPLACEMENT),
+            non_unicode_replacement: None,
+            reserved_names: true,
+            trim_end: true,
+        }
+    }
+
+    /// Sets the character used to replace portions of the name that are not
+    /// valid Unicode.
+    ///
+    /// Each chunk returned by [`OsStrBytesExt::utf8_chunks`] is replaced by
+    /// a single character. When [`None`] is given, which is the default,
+    /// these portions are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::sanitize::Sanitizer;
+    ///
+    /// let sanitizer = Sanitizer::new().non_unicode_replacement(Some('?'));
+    /// ```
+    #[inline]
+    pub const fn non_unicode_replacement(
+        mut self,
+        replacement: Option<char>,
+    ) -> Self {
+        self.non_unicode_replacement = replacement;
+        self
+    }
+
+    /// Sets the character used to replace reserved characters.
+    ///
+    /// When [`None`] is given, reserved characters are removed. The default
+    /// is `Some('_')`. This character is also used to prefix reserved names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::sanitize::Sanitizer;
+    ///
+    /// let sanitizer = Sanitizer::new().replacement(Some('-'));
+    /// assert_eq!("foo-bar", &*sanitizer.sanitize(OsStr::new("foo/bar")));
+    /// ```
+    #[inline]
+    pub const fn replacement(mut self, replacement: Option<char>) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// Sets whether names reserved for devices on Windows should be
+    /// prefixed.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::sanitize::Sanitizer;
+    ///
+    /// let sanitizer = Sanitizer::new().reserved_names(false);
+    /// assert_eq!("nul", &*sanitizer.sanitize(OsStr::new("nul")));
+    /// ```
+    #[inline]
+    pub const fn reserved_names(mut self, reserved_names: bool) -> Self {
+        self.reserved_names = reserved_names;
+        self
+    }
+
+    /// Sets whether trailing dots and spaces should be removed.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::sanitize::Sanitizer;
+    ///
+    /// let sanitizer = Sanitizer::new().trim_end(false);
+    /// assert_eq!("foo.", &*sanitizer.sanitize(OsStr::new("foo.")));
+    /// ```
+    #[inline]
+    pub const fn trim_end(mut self, trim_end: bool) -> Self {
+        self.trim_end = trim_end;
+        self
+    }
+
+    /// Sanitizes a file name using this configuration.
+    ///
+    /// The name is only copied when it needs to be modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use std::ffi::OsStr;
+    ///
+    /// use os_str_bytes::sanitize::Sanitizer;
+    ///
+    /// let sanitizer = Sanitizer::new();
+    /// assert_eq!("a_b", &*sanitizer.sanitize(OsStr::new("a*b")));
+    /// assert!(matches!(
+    ///     sanitizer.sanitize(OsStr::new("foo.txt")),
+    ///     Cow::Borrowed(_),
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn sanitize<'a>(&self, string: &'a OsStr) -> Cow<'a, OsStr> {
+        let mut output = Output::new(string);
+        for (invalid, valid) in string.utf8_chunks() {
+            let invalid_length = invalid.as_os_str().len();
+            if invalid_length != 0 {
+                if self.non_unicode_replacement.is_some() {
+                    output
+                        .replace(invalid_length, self.non_unicode_replacement);
+                } else {
+                    output.keep(invalid_length);
+                }
+            }
+
+            for ch in valid.chars() {
+                if is_reserved_char(ch) {
+                    output.replace(ch.len_utf8(), self.replacement);
+                } else {
+                    output.keep(ch.len_utf8());
+                }
+            }
+        }
+        let mut result = output.finish();
+
+        if self.trim_end {
+            let length = trim_end(&result);
+            match &mut result {
+                Cow::Borrowed(string) => {
+                    // SAFETY: Only ASCII characters were removed.
+                    *string = unchecked!(ext::os_str(
+                        &string.as_encoded_bytes()[..length]
+                    ));
+                }
+                Cow::Owned(string) => string.truncate_at_boundary(length),
+            }
+        }
+
+        if self.reserved_names && is_reserved_name(&result) {
+            let mut string = OsString::new();
+            string.push(
+                self.replacement
+                    .unwrap_or(DEFAULT_REPLACEMENT)
+                    .encode_utf8(&mut [0; 4]),
+            );
+            string.push(&result);
+            result = Cow::Owned(string);
+        }
+
+        result
+    }
+}
+
+impl Default for Sanitizer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sanitizes a file name using the default configuration.
+///
+/// For more information, see [`Sanitizer`].
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::sanitize;
+///
+/// assert_eq!("foo_bar", &*sanitize::sanitize(OsStr::new("foo|bar")));
+/// ```
+#[inline]
+#[must_use]
+pub fn sanitize(string: &OsStr) -> Cow<'_, OsStr> {
+    Sanitizer::new().sanitize(string)
+}
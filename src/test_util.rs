@@ -0,0 +1,243 @@
+//! Adversarial platform strings for testing.
+//!
+//! This crate tests its own methods against strings that are difficult to
+//! handle correctly, such as those containing unpaired surrogates on Windows
+//! or partial UTF-8 sequences on Unix. The same strings are provided here, so
+//! that other crates can test their handling of platform strings without
+//! copying byte sequences from this crate.
+//!
+//! Which strings are generated depends on the platform, since each can
+//! represent different invalid sequences. On platforms where platform strings
+//! must be valid UTF-8, only valid strings are generated.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::borrow::Cow;
+//!
+//! use os_str_bytes::test_util;
+//!
+//! for string in test_util::adversarial_os_strings(2) {
+//!     let lossy = string.to_string_lossy();
+//!     let is_valid = matches!(lossy, Cow::Borrowed(_));
+//!     assert_eq!(string.to_str().is_some(), is_valid);
+//! }
+//! ```
+
+#![cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "test_util")))]
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+#[cfg(target_os = "uefi")]
+use std::os::uefi::ffi::OsStringExt;
+#[cfg(windows)]
+use std::os::windows::ffi::OsStringExt;
+
+use fastrand::Rng;
+
+use super::OsStrBytes;
+#[cfg(not(any(
+    all(target_family = "wasm", target_os = "unknown"),
+    target_os = "uefi",
+    windows,
+)))]
+use super::OsStringBytes;
+
+/// A string containing a character encoded as an unpaired surrogate followed
+/// by a character requiring 4 bytes in UTF-8.
+///
+/// This string uses the [unspecified encoding] of this crate, so it should
+/// only be passed to methods such as [`OsStrBytes::assert_from_raw_bytes`].
+/// Use [`wtf8_os_string`] to convert it on platforms where it is invalid.
+///
+/// [unspecified encoding]: super#encoding-conversions
+pub const WTF8_STRING: &[u8] = b"foo\xED\xA0\xBD\xF0\x9F\x92\xA9bar";
+
+/// Returns [`WTF8_STRING`] as a platform string.
+///
+/// # Panics
+///
+/// Panics if unpaired surrogates are not representable in platform strings,
+/// such as on targets where platform strings must be valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+/// # {
+/// use os_str_bytes::test_util;
+///
+/// let string = test_util::wtf8_os_string();
+/// assert_eq!(None, string.to_str());
+/// # }
+/// ```
+#[inline]
+#[must_use]
+#[track_caller]
+pub fn wtf8_os_string() -> Cow<'static, OsStr> {
+    OsStr::assert_from_raw_bytes(WTF8_STRING)
+}
+
+#[cfg(any(target_os = "uefi", windows))]
+type Unit = u16;
+
+#[cfg(not(any(target_os = "uefi", windows)))]
+type Unit = u8;
+
+#[cfg(any(target_os = "uefi", windows))]
+const PIECES: &[&[Unit]] = &[
+    &[0x61],
+    &[0x37],
+    &[0x00],
+    &[0xF6],
+    &[0xD83D],
+    &[0xDCA9],
+    &[0xD800],
+    &[0xDFFF],
+    &[0xFEFF],
+];
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+const PIECES: &[&[Unit]] = &[
+    b"a",
+    b"7",
+    b"\0",
+    b"\xC3\xB6",
+    b"\xF0\x9F\x92\xA9",
+    b"\xEF\xBB\xBF",
+];
+
+#[cfg(not(any(
+    all(target_family = "wasm", target_os = "unknown"),
+    target_os = "uefi",
+    windows,
+)))]
+const PIECES: &[&[Unit]] = &[
+    b"a",
+    b"7",
+    b"\0",
+    b"\xC3",
+    b"\xB6",
+    b"\xED\xA0\xBD",
+    b"\xF0\x9F",
+    b"\x92\xA9",
+    b"\xEF\xBB\xBF",
+];
+
+fn from_units(units: Vec<Unit>) -> OsString {
+    #[cfg(any(target_os = "uefi", windows))]
+    {
+        OsString::from_wide(&units)
+    }
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    {
+        String::from_utf8(units)
+            .expect("pieces should be valid UTF-8")
+            .into()
+    }
+    #[cfg(not(any(
+        all(target_family = "wasm", target_os = "unknown"),
+        target_os = "uefi",
+        windows,
+    )))]
+    {
+        OsString::assert_from_raw_vec(units)
+    }
+}
+
+/// Returns an iterator over every concatenation of at most `max_pieces`
+/// short sequences that are difficult to handle.
+///
+/// The sequences include ASCII characters, NUL, byte order marks, and
+/// sequences that are invalid in isolation, such as unpaired surrogates and
+/// partial UTF-8 sequences. Therefore, every way that they can be adjacent is
+/// covered, including when concatenation forms a valid character.
+///
+/// The number of strings grows exponentially with `max_pieces`, so values
+/// larger than 4 are rarely useful.
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::test_util;
+///
+/// let mut strings = test_util::adversarial_os_strings(1);
+/// assert_eq!(Some(""), strings.next().as_deref().and_then(|x| x.to_str()));
+/// assert_eq!(Some("a"), strings.next().as_deref().and_then(|x| x.to_str()));
+/// ```
+#[inline]
+pub fn adversarial_os_strings(
+    max_pieces: u32,
+) -> impl Iterator<Item = OsString> {
+    (0..=max_pieces).flat_map(|length| {
+        let count = PIECES.len().pow(length);
+        (0..count).map(move |mut index| {
+            let mut units = Vec::new();
+            for _ in 0..length {
+                units.extend_from_slice(PIECES[index % PIECES.len()]);
+                index /= PIECES.len();
+            }
+            from_units(units)
+        })
+    })
+}
+
+fn random_os_string_with(rng: &mut Rng, length: usize) -> OsString {
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    {
+        (0..length).map(|_| rng.char(..)).collect::<String>().into()
+    }
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    {
+        #[cfg(any(target_os = "uefi", windows))]
+        let units = (0..length).map(|_| rng.u16(..)).collect();
+        #[cfg(not(any(target_os = "uefi", windows)))]
+        let units = (0..length).map(|_| rng.u8(..)).collect();
+        from_units(units)
+    }
+}
+
+/// Returns a platform string of random units.
+///
+/// On Windows, the units are arbitrary UTF-16 code units, which will often
+/// include unpaired surrogates. On Unix, they are arbitrary bytes. On
+/// platforms where platform strings must be valid UTF-8, they are random
+/// characters.
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::test_util;
+///
+/// let string = test_util::random_os_string(16);
+/// assert!(!string.is_empty());
+/// ```
+#[inline]
+#[must_use]
+pub fn random_os_string(length: usize) -> OsString {
+    random_os_string_with(&mut Rng::new(), length)
+}
+
+/// Equivalent to [`random_os_string`] but reproducible for the same seed.
+///
+/// The generated string may change between versions of this crate, but it
+/// will not change between runs of the same program.
+///
+/// # Examples
+///
+/// ```
+/// use os_str_bytes::test_util;
+///
+/// assert_eq!(
+///     test_util::seeded_random_os_string(16, 42),
+///     test_util::seeded_random_os_string(16, 42),
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn seeded_random_os_string(length: usize, seed: u64) -> OsString {
+    random_os_string_with(&mut Rng::with_seed(seed), length)
+}
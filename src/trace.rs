@@ -0,0 +1,57 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use tracing::debug;
+
+// The number of bytes included on each side of the failure. Events record
+// only this window and the length, since the full string may be a user path
+// or a secret.
+const CONTEXT_LENGTH: usize = 8;
+
+// Formats the bytes surrounding an index as hexadecimal, with the byte at the
+// index enclosed in brackets.
+struct Context<'a> {
+    string: &'a [u8],
+    index: usize,
+}
+
+impl Display for Context<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let start = self.index.saturating_sub(CONTEXT_LENGTH);
+        let end = self.string.len().min(self.index + CONTEXT_LENGTH + 1);
+        for (index, byte) in (start..end).zip(&self.string[start..end]) {
+            if index != start {
+                f.write_str(" ")?;
+            }
+            if index == self.index {
+                write!(f, "[{:02X}]", byte)?;
+            } else {
+                write!(f, "{:02X}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn io_failure(string: &[u8], index: usize) {
+    debug!(
+        index,
+        length = string.len(),
+        context = %Context { string, index },
+        "byte string is not IO-safe",
+    );
+}
+
+if_conversions! {
+    pub(crate) fn conversion_failure(string: &[u8], index: usize) {
+        debug!(
+            index,
+            length = string.len(),
+            context = %Context { string, index },
+            "byte string could not be converted",
+        );
+    }
+}
@@ -0,0 +1,143 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+// This module implements the Two-Way string matching algorithm by Crochemore
+// and Perrin, which runs in linear time and constant space. It is only used
+// when the "memchr" feature is disabled.
+//
+// The implementation is based on the one used by [str::find], but it is
+// generic over the direction of the search, so that [rfind] can reuse it.
+
+use std::cmp;
+
+trait Bytes: Copy {
+    fn len(self) -> usize;
+
+    fn get(self, index: usize) -> u8;
+}
+
+#[derive(Clone, Copy)]
+struct Forward<'a>(&'a [u8]);
+
+impl Bytes for Forward<'_> {
+    fn len(self) -> usize {
+        self.0.len()
+    }
+
+    fn get(self, index: usize) -> u8 {
+        self.0[index]
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Reverse<'a>(&'a [u8]);
+
+impl Bytes for Reverse<'_> {
+    fn len(self) -> usize {
+        self.0.len()
+    }
+
+    fn get(self, index: usize) -> u8 {
+        self.0[self.0.len() - index - 1]
+    }
+}
+
+// Returns the start index and period of the maximal suffix of the needle,
+// using the given ordering of bytes.
+fn maximal_suffix<B>(needle: B, reversed_order: bool) -> (usize, usize)
+where
+    B: Bytes,
+{
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+
+    while right + offset < needle.len() {
+        let x = needle.get(right + offset);
+        let y = needle.get(left + offset);
+        if (x < y && !reversed_order) || (x > y && reversed_order) {
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if x == y {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+    (left, period)
+}
+
+fn search<B>(haystack: B, needle: B) -> Option<usize>
+where
+    B: Bytes,
+{
+    let needle_len = needle.len();
+    if needle_len == 0 {
+        return Some(0);
+    }
+
+    let suffix = maximal_suffix(needle, false);
+    let reversed_suffix = maximal_suffix(needle, true);
+    let (critical_position, period) = if suffix.0 > reversed_suffix.0 {
+        suffix
+    } else {
+        reversed_suffix
+    };
+    let short_period = (0..critical_position)
+        .all(|x| needle.get(x) == needle.get(x + period));
+    let period = if short_period {
+        period
+    } else {
+        cmp::max(critical_position, needle_len - critical_position) + 1
+    };
+
+    // When the period is short, this is the length of the prefix of the
+    // needle that is already known to match at the current position.
+    let mut memory = 0;
+    let mut position = 0;
+    'search: while position + needle_len <= haystack.len() {
+        let start = if short_period {
+            cmp::max(critical_position, memory)
+        } else {
+            critical_position
+        };
+        for i in start..needle_len {
+            if needle.get(i) != haystack.get(position + i) {
+                position += i - critical_position + 1;
+                memory = 0;
+                continue 'search;
+            }
+        }
+
+        let start = if short_period { memory } else { 0 };
+        for i in (start..critical_position).rev() {
+            if needle.get(i) != haystack.get(position + i) {
+                position += period;
+                if short_period {
+                    memory = needle_len - period;
+                }
+                continue 'search;
+            }
+        }
+        return Some(position);
+    }
+    None
+}
+
+pub(super) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    search(Forward(haystack), Forward(needle))
+}
+
+pub(super) fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    search(Reverse(haystack), Reverse(needle))
+        .map(|x| haystack.len() - x - needle.len())
+}
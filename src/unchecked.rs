@@ -0,0 +1,156 @@
+// This module contains operations that the compiler cannot check. When the
+// "forbid_unsafe" feature is enabled, it is the only one where unsafe code is
+// not forbidden. Its functions then validate their arguments and are safe to
+// call, and unsafe code is only used where the language provides no safe
+// equivalent: casting to the transparent wrappers declared by this crate and
+// referencing substrings of platform strings on Windows.
+
+#![cfg_attr(feature = "forbid_unsafe", allow(unsafe_code))]
+
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::mem;
+use std::str;
+
+#[cfg(feature = "forbid_unsafe")]
+use super::imp::raw;
+use super::NonUnicodeOsStr;
+use super::RawOsStr;
+use super::RawOsString;
+
+#[cfg(feature = "forbid_unsafe")]
+const INVALID_MESSAGE: &str = "bytes do not use the internal encoding";
+
+#[allow(clippy::missing_safety_doc)]
+pub(super) unsafe trait TransmuteBox {
+    fn transmute_box<R>(self: Box<Self>) -> Box<R>
+    where
+        R: ?Sized + TransmuteBox,
+    {
+        let value = Box::into_raw(self);
+        // SAFETY: This trait is only implemented for types that can be
+        // transmuted.
+        unsafe { Box::from_raw(mem::transmute_copy(&value)) }
+    }
+}
+
+// SAFETY: This struct has a layout that makes this operation safe.
+unsafe impl TransmuteBox for RawOsStr {}
+unsafe impl TransmuteBox for [u8] {}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(super) fn validate(string: &[u8]) {
+    assert!(raw::is_valid(string), "{}", INVALID_MESSAGE);
+}
+
+#[cfg(not(feature = "forbid_unsafe"))]
+pub(super) unsafe fn os_str(string: &[u8]) -> &OsStr {
+    // SAFETY: This function has equivalent safety requirements.
+    unsafe { OsStr::from_encoded_bytes_unchecked(string) }
+}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(super) fn os_str(string: &[u8]) -> &OsStr {
+    #[cfg(any(target_os = "uefi", windows))]
+    {
+        validate(string);
+        // SAFETY: The bytes were validated to use the internal encoding.
+        unsafe { OsStr::from_encoded_bytes_unchecked(string) }
+    }
+    #[cfg(not(any(target_os = "uefi", windows)))]
+    {
+        raw::os_str(string).expect(INVALID_MESSAGE)
+    }
+}
+
+#[cfg(not(feature = "forbid_unsafe"))]
+pub(super) unsafe fn os_string(string: Vec<u8>) -> OsString {
+    // SAFETY: This function has equivalent safety requirements.
+    unsafe { OsString::from_encoded_bytes_unchecked(string) }
+}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(super) fn os_string(string: Vec<u8>) -> OsString {
+    raw::os_string(string).expect(INVALID_MESSAGE)
+}
+
+#[cfg(not(feature = "forbid_unsafe"))]
+pub(super) unsafe fn raw_os_str(string: &[u8]) -> &RawOsStr {
+    // SAFETY: This struct has a layout that makes this operation safe.
+    unsafe { mem::transmute(string) }
+}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(super) fn raw_os_str(string: &[u8]) -> &RawOsStr {
+    validate(string);
+    // SAFETY: The bytes were validated to use the internal encoding, and this
+    // struct has a layout that makes this operation safe.
+    unsafe { mem::transmute(string) }
+}
+
+pub(super) fn os_str_as_raw_os_str(string: &OsStr) -> &RawOsStr {
+    // SAFETY: [OsStr] prevents violating the invariants of its internal
+    // encoding, and this struct has a layout that makes this operation safe.
+    unsafe { mem::transmute(string.as_encoded_bytes()) }
+}
+
+pub(super) const fn str_as_raw_os_str(string: &str) -> &RawOsStr {
+    // SAFETY: The internal encoding of [OsStr] is a superset of UTF-8, and
+    // this struct has a layout that makes this operation safe.
+    unsafe { mem::transmute::<&[u8], &RawOsStr>(string.as_bytes()) }
+}
+
+pub(super) fn non_unicode_os_str(string: &OsStr) -> &NonUnicodeOsStr {
+    // SAFETY: This struct has a layout that makes this operation safe.
+    unsafe { mem::transmute(string) }
+}
+
+#[cfg(not(feature = "forbid_unsafe"))]
+pub(super) unsafe fn utf8(string: &[u8]) -> &str {
+    // SAFETY: This function has equivalent safety requirements.
+    unsafe { str::from_utf8_unchecked(string) }
+}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(super) fn utf8(string: &[u8]) -> &str {
+    str::from_utf8(string).expect("invalid UTF-8")
+}
+
+impl RawOsString {
+    /// Creates a string from the components returned by [`into_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// The components must satisfy the requirements of [`Vec::from_raw_parts`]
+    /// for a vector of bytes. Additionally, the bytes must use the internal
+    /// encoding of [`OsStr`], as described by [`from_encoded_vec_unchecked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_str_bytes::RawOsString;
+    ///
+    /// let raw = RawOsString::new("foobar");
+    /// let (pointer, length, capacity) = raw.clone().into_raw_parts();
+    /// assert_eq!(raw, unsafe {
+    ///     RawOsString::from_raw_parts(pointer, length, capacity)
+    /// });
+    /// ```
+    ///
+    /// [`from_encoded_vec_unchecked`]: Self::from_encoded_vec_unchecked
+    /// [`into_raw_parts`]: Self::into_raw_parts
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_raw_parts(
+        pointer: *mut u8,
+        length: usize,
+        capacity: usize,
+    ) -> Self {
+        // SAFETY: The caller guarantees that these components describe a
+        // vector of bytes.
+        let string = unsafe { Vec::from_raw_parts(pointer, length, capacity) };
+        // SAFETY: The caller guarantees that the bytes use the internal
+        // encoding.
+        unchecked!(Self::from_encoded_vec_unchecked(string))
+    }
+}
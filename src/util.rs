@@ -1,3 +1,15 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+#[cfg(all(
+    feature = "checked_conversions",
+    any(
+        all(target_family = "wasm", target_os = "unknown"),
+        target_os = "uefi",
+        windows,
+    ),
+))]
+use std::ops::Range;
+
 pub(super) const BYTE_SHIFT: u8 = 6;
 
 pub(super) const CONT_MASK: u8 = (1 << BYTE_SHIFT) - 1;
@@ -9,3 +21,29 @@ pub(super) const fn is_continuation(byte: u8) -> bool {
 }
 
 pub(super) const MAX_UTF8_LENGTH: usize = 4;
+
+// Returns the range of the concatenation of two strings that contains the
+// last sequence of the first and the continuation bytes that start the second.
+#[cfg(all(
+    feature = "checked_conversions",
+    any(
+        all(target_family = "wasm", target_os = "unknown"),
+        target_os = "uefi",
+        windows,
+    ),
+))]
+pub(super) fn joint(string: &[u8], other: &[u8]) -> Range<usize> {
+    let tail = &string[string.len().saturating_sub(MAX_UTF8_LENGTH)..];
+    let start = string.len()
+        - tail
+            .iter()
+            .rposition(|&x| !is_continuation(x))
+            .map_or(tail.len(), |x| tail.len() - x);
+    let end = string.len()
+        + other
+            .iter()
+            .take(MAX_UTF8_LENGTH)
+            .take_while(|&&x| is_continuation(x))
+            .count();
+    start..end
+}
@@ -9,9 +9,25 @@ use std::result;
 use std::str;
 use std::str::Utf8Error;
 
+#[cfg(feature = "tracing")]
+use crate::trace;
+
+if_checked_conversions! {
+    use crate::util;
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) struct EncodingError(Utf8Error);
 
+impl EncodingError {
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn new(string: &[u8], error: Utf8Error) -> Self {
+        #[cfg(feature = "tracing")]
+        trace::conversion_failure(string, error.valid_up_to());
+        Self(error)
+    }
+}
+
 impl Display for EncodingError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -23,30 +39,64 @@ impl Error for EncodingError {}
 pub(crate) type Result<T> = result::Result<T, EncodingError>;
 
 macro_rules! expect_utf8 {
-    ( $result:expr ) => {
-        $result.expect(
+    ( $result:expr , $fallback:expr $(,)? ) => {
+        internal_expect!(
+            $result,
+            $fallback,
             "platform string contains invalid UTF-8, which should not be \
              possible",
         )
     };
 }
 
+if_checked_conversions! {
+    pub(crate) fn complete_length(string: &[u8]) -> Result<usize> {
+        match str::from_utf8(string) {
+            Ok(string) => Ok(string.len()),
+            Err(error) if error.error_len().is_none() => {
+                Ok(error.valid_up_to())
+            }
+            Err(error) => Err(EncodingError::new(string, error)),
+        }
+    }
+
+    pub(crate) fn concat(string: &[u8], other: &[u8]) -> Result<Vec<u8>> {
+        let result = [string, other].concat();
+        if str::from_utf8(&result[util::joint(string, other)]).is_err() {
+            if let Err(error) = str::from_utf8(&result) {
+                return Err(EncodingError::new(&result, error));
+            }
+        }
+        Ok(result)
+    }
+}
+
 pub(crate) fn os_str_from_bytes(string: &[u8]) -> Result<Cow<'_, OsStr>> {
     str::from_utf8(string)
         .map(|x| Cow::Borrowed(OsStr::new(x)))
-        .map_err(EncodingError)
+        .map_err(|x| EncodingError::new(string, x))
 }
 
 pub(crate) fn os_str_to_bytes(string: &OsStr) -> Cow<'_, [u8]> {
-    Cow::Borrowed(expect_utf8!(string.to_str()).as_bytes())
+    Cow::Borrowed(expect_utf8!(
+        string.to_str().map(str::as_bytes),
+        string.as_encoded_bytes(),
+    ))
 }
 
 pub(crate) fn os_string_from_vec(string: Vec<u8>) -> Result<OsString> {
     String::from_utf8(string)
         .map(Into::into)
-        .map_err(|x| EncodingError(x.utf8_error()))
+        .map_err(|x| EncodingError::new(x.as_bytes(), x.utf8_error()))
 }
 
 pub(crate) fn os_string_into_vec(string: OsString) -> Vec<u8> {
-    expect_utf8!(string.into_string()).into_bytes()
+    match string.into_string() {
+        Ok(string) => string.into_bytes(),
+        Err(string) => expect_utf8!(None, string.into_encoded_bytes()),
+    }
+}
+
+pub(crate) fn valid_length(string: &[u8]) -> usize {
+    str::from_utf8(string).map_or_else(|x| x.valid_up_to(), str::len)
 }
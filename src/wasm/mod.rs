@@ -1,11 +1,13 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
 #[path = "../windows/convert_io.rs"]
 pub(super) mod convert_io;
 
 if_conversions! {
     pub(super) mod convert;
+}
 
-    if_raw_str! {
-        #[path = "../common/raw.rs"]
-        pub(super) mod raw;
-    }
+if_raw_str! {
+    #[path = "../common/raw.rs"]
+    pub(super) mod raw;
 }
@@ -0,0 +1,131 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
+use std::char;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[cfg(target_os = "uefi")]
+use std::os::uefi::ffi::OsStringExt;
+#[cfg(windows)]
+use std::os::windows::ffi::OsStringExt;
+
+/// The error that occurs when a UTF-16 string contains an unpaired surrogate.
+///
+/// This error is returned by [`validate_wide`] and [`from_wide_strict`].
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WideError {
+    index: usize,
+    unit: u16,
+}
+
+impl WideError {
+    /// Returns the index of the unpaired surrogate, in code units.
+    #[inline]
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the unpaired surrogate.
+    #[inline]
+    #[must_use]
+    pub fn unit(&self) -> u16 {
+        self.unit
+    }
+}
+
+impl Display for WideError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unpaired surrogate {:#X} at code unit index {}",
+            self.unit, self.index,
+        )
+    }
+}
+
+impl Error for WideError {}
+
+/// Checks whether a UTF-16 string is well-formed.
+///
+/// [`OsStringExt::from_wide`] accepts any sequence of code units on Windows,
+/// including unpaired surrogates, which cannot be represented by many
+/// filesystems or formats. This function can be used to reject those strings
+/// before they are converted. It is available on all platforms, since UTF-16
+/// can also be received from other sources, such as serialized data.
+///
+/// # Errors
+///
+/// Returns an error describing the first unpaired surrogate, if any exist.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(Ok(()), os_str_bytes::validate_wide(&[0x66, 0xD83D, 0xDCA9]));
+///
+/// let error = os_str_bytes::validate_wide(&[0x66, 0xDCA9]).unwrap_err();
+/// assert_eq!(1, error.index());
+/// assert_eq!(0xDCA9, error.unit());
+/// ```
+///
+/// [`OsStringExt::from_wide`]: https://doc.rust-lang.org/std/os/windows/ffi/trait.OsStringExt.html#tymethod.from_wide
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+pub fn validate_wide(string: &[u16]) -> Result<(), WideError> {
+    let mut index = 0;
+    for ch in char::decode_utf16(string.iter().copied()) {
+        match ch {
+            Ok(ch) => index += ch.len_utf16(),
+            Err(error) => {
+                return Err(WideError {
+                    index,
+                    unit: error.unpaired_surrogate(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Converts a UTF-16 string into a platform string, if it is well-formed.
+///
+/// Unlike [`OsStringExt::from_wide`], this function is available on all
+/// platforms and never creates strings containing unpaired surrogates.
+///
+/// # Errors
+///
+/// Returns the same error as [`validate_wide`].
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     Ok("f\u{1F4A9}".into()),
+///     os_str_bytes::from_wide_strict(&[0x66, 0xD83D, 0xDCA9]),
+/// );
+/// assert!(os_str_bytes::from_wide_strict(&[0x66, 0xD83D]).is_err());
+/// ```
+///
+/// [`OsStringExt::from_wide`]: https://doc.rust-lang.org/std/os/windows/ffi/trait.OsStringExt.html#tymethod.from_wide
+#[cfg_attr(os_str_bytes_docs_rs, doc(cfg(feature = "conversions")))]
+pub fn from_wide_strict(string: &[u16]) -> Result<OsString, WideError> {
+    validate_wide(string)?;
+
+    #[cfg(any(target_os = "uefi", windows))]
+    {
+        Ok(OsString::from_wide(string))
+    }
+    #[cfg(not(any(target_os = "uefi", windows)))]
+    {
+        Ok(internal_expect!(
+            String::from_utf16(string).ok(),
+            String::from_utf16_lossy(string),
+            "validated string should be well-formed",
+        )
+        .into())
+    }
+}
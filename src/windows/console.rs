@@ -0,0 +1,64 @@
+use std::ffi::c_void;
+use std::io;
+use std::os::windows::io::RawHandle;
+use std::ptr;
+
+// The console may not accept very large buffers, so this is the same limit
+// used by the standard library.
+const MAX_BUFFER_LENGTH: usize = 8192;
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetConsoleMode(console_handle: RawHandle, mode: *mut u32) -> i32;
+
+    fn WriteConsoleW(
+        console_output: RawHandle,
+        buffer: *const c_void,
+        number_of_chars_to_write: u32,
+        number_of_chars_written: *mut u32,
+        reserved: *mut c_void,
+    ) -> i32;
+}
+
+pub(crate) fn is_console(handle: RawHandle) -> bool {
+    let mut mode = 0;
+    // SAFETY: The mode pointer is valid for writes, and invalid handles are
+    // reported as errors.
+    unsafe { GetConsoleMode(handle, &mut mode) != 0 }
+}
+
+pub(crate) fn write_wide(
+    handle: RawHandle,
+    mut string: &[u16],
+) -> io::Result<()> {
+    while !string.is_empty() {
+        let mut length = string.len().min(MAX_BUFFER_LENGTH);
+        // Avoid splitting surrogate pairs between calls.
+        if length < string.len()
+            && (0xD800..0xDC00).contains(&string[length - 1])
+        {
+            length -= 1;
+        }
+
+        let mut written = 0;
+        // SAFETY: The buffer is valid for the given number of code units,
+        // which fits in a `u32` because of the above limit.
+        let result = unsafe {
+            WriteConsoleW(
+                handle,
+                string.as_ptr().cast(),
+                length as u32,
+                &mut written,
+                ptr::null_mut(),
+            )
+        };
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if written == 0 {
+            return Err(io::ErrorKind::WriteZero.into());
+        }
+        string = &string[written as usize..];
+    }
+    Ok(())
+}
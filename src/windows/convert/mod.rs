@@ -16,8 +16,16 @@ use std::str;
 use super::os::ffi::OsStrExt;
 use super::os::ffi::OsStringExt;
 
+#[cfg(feature = "tracing")]
+use crate::trace;
+
+if_checked_conversions! {
+    use crate::util;
+}
+
 mod wtf8;
 use wtf8::DecodeWide;
+pub use wtf8::WideEncoder;
 
 if_raw_str! {
     if_conversions! {
@@ -30,20 +38,37 @@ if_raw_str! {
 mod tests;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub(crate) enum EncodingError {
+pub(crate) enum ErrorKind {
     Byte(u8),
     CodePoint(u32),
     End(),
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct EncodingError {
+    kind: ErrorKind,
+    // The index of the invalid sequence, which may start before the byte
+    // described by [kind].
+    index: usize,
+}
+
 impl EncodingError {
+    fn new(string: &[u8], kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            index: valid_length(string),
+        }
+    }
+
     fn position(&self) -> Cow<'_, str> {
-        match self {
-            Self::Byte(byte) => Cow::Owned(format!("byte b'\\x{:02X}'", byte)),
-            Self::CodePoint(code_point) => {
+        match self.kind {
+            ErrorKind::Byte(byte) => {
+                Cow::Owned(format!("byte b'\\x{:02X}'", byte))
+            }
+            ErrorKind::CodePoint(code_point) => {
                 Cow::Owned(format!("code point U+{:04X}", code_point))
             }
-            Self::End() => Cow::Borrowed("end of string"),
+            ErrorKind::End() => Cow::Borrowed("end of string"),
         }
     }
 }
@@ -53,8 +78,9 @@ impl Display for EncodingError {
         write!(
             f,
             "byte sequence is not representable in the platform encoding; \
-             error at {}",
+             error at {} in the sequence starting at index {}",
             self.position(),
+            self.index,
         )
     }
 }
@@ -64,20 +90,42 @@ impl Error for EncodingError {}
 pub(crate) type Result<T> = result::Result<T, EncodingError>;
 
 fn from_bytes(string: &[u8]) -> Result<Option<OsString>> {
+    let result =
+        encode_bytes(string).map_err(|x| EncodingError::new(string, x));
+    #[cfg(feature = "tracing")]
+    if let Err(error) = &result {
+        trace::conversion_failure(string, error.index);
+    }
+    result
+}
+
+fn encode_bytes(string: &[u8]) -> wtf8::Result<Option<OsString>> {
+    // The string is converted in chunks, to avoid storing an intermediate
+    // wide string of the full length. Surrogate pairs split between chunks
+    // are joined by [OsString::push].
+    const CHUNK_LENGTH: usize = 1024;
+
     let mut encoder = wtf8::encode_wide(string);
 
-    // Collecting an iterator into a result ignores the size hint:
-    // https://github.com/rust-lang/rust/issues/48994
-    let mut encoded_string = Vec::with_capacity(encoder.size_hint().0);
-    for wchar in &mut encoder {
-        encoded_string.push(wchar?);
+    let mut encoded_string = OsString::with_capacity(string.len());
+    let mut chunk = [0; CHUNK_LENGTH];
+    loop {
+        let mut length = 0;
+        for wchar in encoder.by_ref().take(CHUNK_LENGTH) {
+            chunk[length] = wchar?;
+            length += 1;
+        }
+        encoded_string.push(OsString::from_wide(&chunk[..length]));
+        if length < CHUNK_LENGTH {
+            break;
+        }
     }
 
-    debug_assert_eq!(str::from_utf8(string).is_ok(), encoder.is_still_utf8());
-    Ok(encoder
-        .is_still_utf8()
-        .not()
-        .then(|| OsString::from_wide(&encoded_string)))
+    internal_assert_eq!(
+        str::from_utf8(string).is_ok(),
+        encoder.is_still_utf8(),
+    );
+    Ok(encoder.is_still_utf8().not().then_some(encoded_string))
 }
 
 fn to_bytes(string: &OsStr) -> Vec<u8> {
@@ -88,13 +136,88 @@ fn to_bytes(string: &OsStr) -> Vec<u8> {
     string
 }
 
+if_checked_conversions! {
+    pub(crate) fn complete_length(string: &[u8]) -> Result<usize> {
+        let (mut length, error) = wtf8::validate(string);
+        match error {
+            None | Some(ErrorKind::End()) => {}
+            Some(kind) => {
+                let error = EncodingError {
+                    kind,
+                    index: length,
+                };
+                #[cfg(feature = "tracing")]
+                trace::conversion_failure(string, length);
+                return Err(error);
+            }
+        }
+
+        // A trailing high surrogate is excluded, since it would be invalid
+        // for a low surrogate to follow it.
+        if let [.., 0xED, 0xA0..=0xAF, _] = string[..length] {
+            length -= 3;
+        }
+        Ok(length)
+    }
+
+    fn decode_surrogate(second: u8, third: u8) -> u16 {
+        0xD000
+            | (u16::from(second & util::CONT_MASK) << util::BYTE_SHIFT)
+            | u16::from(third & util::CONT_MASK)
+    }
+
+    pub(crate) fn concat(string: &[u8], other: &[u8]) -> Result<Vec<u8>> {
+        let mut joined = Vec::with_capacity(string.len() + other.len());
+
+        // A surrogate pair split between the strings must be joined, since
+        // it would be invalid for a low surrogate to follow a high surrogate.
+        if let (
+            [prefix @ .., 0xED, high @ 0xA0..=0xAF, high_last @ 0x80..=0xBF],
+            [0xED, low @ 0xB0..=0xBF, low_last @ 0x80..=0xBF, suffix @ ..],
+        ) = (string, other)
+        {
+            let ch = internal_expect!(
+                char::decode_utf16([
+                    decode_surrogate(*high, *high_last),
+                    decode_surrogate(*low, *low_last),
+                ])
+                .next()
+                .and_then(result::Result::ok),
+                char::REPLACEMENT_CHARACTER,
+                "surrogates should form a pair",
+            );
+
+            joined.extend_from_slice(prefix);
+            joined.extend_from_slice(
+                ch.encode_utf8(&mut [0; util::MAX_UTF8_LENGTH]).as_bytes(),
+            );
+            joined.extend_from_slice(suffix);
+            return Ok(joined);
+        }
+
+        joined.extend_from_slice(string);
+        joined.extend_from_slice(other);
+        if wtf8::validate(&joined[util::joint(string, other)]).1.is_some() {
+            if let (index, Some(kind)) = wtf8::validate(&joined) {
+                let error = EncodingError { kind, index };
+                #[cfg(feature = "tracing")]
+                trace::conversion_failure(&joined, index);
+                return Err(error);
+            }
+        }
+        Ok(joined)
+    }
+}
+
 pub(crate) fn os_str_from_bytes(string: &[u8]) -> Result<Cow<'_, OsStr>> {
     from_bytes(string).map(|result| {
         result.map(Cow::Owned).unwrap_or_else(|| {
+            #[cfg(feature = "forbid_unsafe")]
+            let string = str::from_utf8(string).expect("invalid UTF-8");
             // SAFETY: This slice was validated to be UTF-8.
-            Cow::Borrowed(OsStr::new(unsafe {
-                str::from_utf8_unchecked(string)
-            }))
+            #[cfg(not(feature = "forbid_unsafe"))]
+            let string = unsafe { str::from_utf8_unchecked(string) };
+            Cow::Borrowed(OsStr::new(string))
         })
     })
 }
@@ -106,8 +229,12 @@ pub(crate) fn os_str_to_bytes(string: &OsStr) -> Cow<'_, [u8]> {
 pub(crate) fn os_string_from_vec(string: Vec<u8>) -> Result<OsString> {
     from_bytes(&string).map(|result| {
         result.unwrap_or_else(|| {
+            #[cfg(feature = "forbid_unsafe")]
+            let string = String::from_utf8(string).expect("invalid UTF-8");
             // SAFETY: This slice was validated to be UTF-8.
-            unsafe { String::from_utf8_unchecked(string) }.into()
+            #[cfg(not(feature = "forbid_unsafe"))]
+            let string = unsafe { String::from_utf8_unchecked(string) };
+            string.into()
         })
     })
 }
@@ -115,3 +242,7 @@ pub(crate) fn os_string_from_vec(string: Vec<u8>) -> Result<OsString> {
 pub(crate) fn os_string_into_vec(string: OsString) -> Vec<u8> {
     to_bytes(&string)
 }
+
+pub(crate) fn valid_length(string: &[u8]) -> usize {
+    wtf8::validate(string).0
+}
@@ -1,14 +1,15 @@
 use super::EncodingError;
+use super::ErrorKind;
 
 #[test]
 fn test_invalid() {
-    use EncodingError::Byte;
-    use EncodingError::CodePoint;
-    use EncodingError::End;
+    use ErrorKind::Byte;
+    use ErrorKind::CodePoint;
+    use ErrorKind::End;
 
     #[track_caller]
-    fn test(error: EncodingError, string: &[u8]) {
-        assert_eq!(Err(error), super::from_bytes(string));
+    fn test(error: ErrorKind, string: &[u8]) {
+        assert_eq!(Err(error), super::encode_bytes(string));
     }
 
     test(Byte(b'\x83'), b"\x0C\x83\xD7\x3E");
@@ -47,3 +48,64 @@ fn test_invalid() {
     test(CodePoint(0x3C_0000), b"\xFF\x80\x80\x80");
     test(CodePoint(0x3C_6143), b"\xFF\x86\x85\x83");
 }
+
+#[test]
+fn test_error_index() {
+    #[track_caller]
+    fn test(kind: ErrorKind, index: usize, string: &[u8]) {
+        assert_eq!(
+            Err(EncodingError { kind, index }),
+            super::from_bytes(string),
+        );
+    }
+
+    test(ErrorKind::Byte(b'\x83'), 1, b"\x0C\x83\xD7\x3E");
+    test(ErrorKind::Byte(b'\x00'), 3, b"foo\xC2\x00");
+    test(
+        ErrorKind::CodePoint(0xDC00),
+        6,
+        b"foo\xED\xA0\x80\xED\xB0\x80",
+    );
+    test(ErrorKind::End(), 3, b"foo\xF1\x80");
+
+    let mut string = "a".repeat(2000).into_bytes();
+    string.push(b'\xFF');
+    test(ErrorKind::End(), 2000, &string);
+}
+
+#[test]
+fn test_split_surrogate_pair() {
+    use std::ffi::OsString;
+
+    use super::OsStringExt;
+
+    // Each string starts with a lone surrogate, so that it is not UTF-8.
+    for length in 1020..1030 {
+        let mut string = b"\xED\xA0\xBD".to_vec();
+        let mut wide_string = vec![0xD83D];
+        for ch in "a".repeat(length).chars().chain(['\u{1F4A9}', '\u{F6}']) {
+            string.extend(ch.encode_utf8(&mut [0; 4]).bytes());
+            wide_string.extend(ch.encode_utf16(&mut [0; 2]).iter());
+        }
+        assert_eq!(
+            Ok(OsString::from_wide(&wide_string)),
+            super::os_string_from_vec(string),
+        );
+    }
+}
+
+#[test]
+fn test_valid_length() {
+    #[track_caller]
+    fn test(length: usize, string: &[u8]) {
+        assert_eq!(length, super::valid_length(string));
+    }
+
+    test(0, b"");
+    test(3, b"foo");
+    test(3, b"foo\x80");
+    test(3, b"foo\xF0\x9F\x92");
+    test(6, b"foo\xED\xA0\xBD");
+    test(6, b"foo\xED\xA0\xBD\xED\xB2\xA9");
+    test(13, b"foo\xED\xA0\xBD\xF0\x9F\x92\xA9bar");
+}
@@ -6,7 +6,7 @@ use crate::util::is_continuation;
 use crate::util::BYTE_SHIFT;
 use crate::util::CONT_MASK;
 
-use super::EncodingError;
+use super::ErrorKind;
 use super::Result;
 
 pub(super) struct CodePoints<I>
@@ -38,19 +38,19 @@ where
     }
 
     fn consume_next(&mut self, code_point: &mut u32) -> Result<()> {
-        let &byte = self.iter.peek().ok_or(EncodingError::End())?;
+        let &byte = self.iter.peek().ok_or(ErrorKind::End())?;
 
         if !is_continuation(byte) {
             self.surrogate = false;
             // Not consuming this byte will be useful if this crate ever offers
             // a way to encode lossily.
-            return Err(EncodingError::Byte(byte));
+            return Err(ErrorKind::Byte(byte));
         }
         *code_point =
             (*code_point << BYTE_SHIFT) | u32::from(byte & CONT_MASK);
 
         let removed = self.iter.next();
-        debug_assert_eq!(Some(byte), removed);
+        internal_assert_eq!(Some(byte), removed);
 
         Ok(())
     }
@@ -65,6 +65,22 @@ impl<I> FusedIterator for CodePoints<I> where
 {
 }
 
+// Returns the length of the longest prefix that is valid for the encoding and
+// the error that prevents it from being extended, if any.
+pub(in super::super) fn validate(string: &[u8]) -> (usize, Option<ErrorKind>) {
+    let mut code_points = CodePoints::new(string.iter().copied());
+    let mut length = 0;
+    loop {
+        match code_points.next() {
+            Some(Ok(_)) => {
+                length = string.len() - code_points.inner_size_hint().0;
+            }
+            Some(Err(error)) => return (length, Some(error)),
+            None => return (length, None),
+        }
+    }
+}
+
 impl<I> Iterator for CodePoints<I>
 where
     I: Iterator<Item = u8>,
@@ -88,7 +104,7 @@ where
         let mut invalid = false;
         if !byte.is_ascii() {
             if byte < 0xC2 {
-                return Some(Err(EncodingError::Byte(byte)));
+                return Some(Err(ErrorKind::Byte(byte)));
             }
 
             if byte < 0xE0 {
@@ -121,7 +137,7 @@ where
             consume_next!();
         }
         if invalid {
-            return Some(Err(EncodingError::CodePoint(code_point)));
+            return Some(Err(ErrorKind::CodePoint(code_point)));
         }
 
         Some(Ok(code_point))
@@ -1,7 +1,13 @@
 use std::char;
 use std::char::DecodeUtf16;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::iter::Copied;
 use std::iter::FusedIterator;
 use std::num::NonZeroU16;
+use std::slice;
 
 use crate::util::BYTE_SHIFT;
 use crate::util::CONT_MASK;
@@ -153,13 +159,17 @@ where
                     .map(|offset| {
                         static_assert!(MIN_LOW_SURROGATE != 0);
 
+                        let surrogate =
+                            (offset & 0x3FF) as u16 | MIN_LOW_SURROGATE;
+                        #[cfg(feature = "forbid_unsafe")]
+                        let surrogate = NonZeroU16::new(surrogate)
+                            .expect("zero surrogate");
                         // SAFETY: The above static assertion guarantees that
                         // this value will not be zero.
-                        self.surrogate = Some(unsafe {
-                            NonZeroU16::new_unchecked(
-                                (offset & 0x3FF) as u16 | MIN_LOW_SURROGATE,
-                            )
-                        });
+                        #[cfg(not(feature = "forbid_unsafe"))]
+                        let surrogate =
+                            unsafe { NonZeroU16::new_unchecked(surrogate) };
+                        self.surrogate = Some(surrogate);
                         (offset >> 10) as u16 | MIN_HIGH_SURROGATE
                     })
                     .unwrap_or(code_point as u16)
@@ -179,6 +189,75 @@ where
 
 pub(in super::super) fn encode_wide(
     string: &[u8],
-) -> EncodeWide<impl '_ + Iterator<Item = u8>> {
+) -> EncodeWide<Copied<slice::Iter<'_, u8>>> {
     EncodeWide::new(string.iter().copied())
 }
+
+/// An encoder that converts a platform-native string to UTF-16 in chunks.
+///
+/// This struct is useful for writing very long strings to fixed-size buffers,
+/// such as those passed to Windows APIs. Surrogate pairs may be split across
+/// calls to [`encode`], so no buffer needs to be large enough to store any
+/// specific character.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use os_str_bytes::WideEncoder;
+///
+/// let mut encoder = WideEncoder::new(OsStr::new("foo\u{1F4A9}"));
+/// let mut buffer = [0; 4];
+/// assert_eq!(4, encoder.encode(&mut buffer));
+/// assert_eq!([0x66, 0x6F, 0x6F, 0xD83D], buffer);
+/// assert_eq!(1, encoder.encode(&mut buffer));
+/// assert_eq!(0xDCA9, buffer[0]);
+/// assert_eq!(0, encoder.encode(&mut buffer));
+/// ```
+///
+/// [`encode`]: Self::encode
+#[cfg_attr(
+    os_str_bytes_docs_rs,
+    doc(cfg(all(
+        feature = "conversions",
+        any(target_os = "uefi", windows)
+    )))
+)]
+pub struct WideEncoder<'a>(EncodeWide<Copied<slice::Iter<'a, u8>>>);
+
+impl<'a> WideEncoder<'a> {
+    /// Creates an encoder for a platform-native string.
+    #[inline]
+    #[must_use]
+    pub fn new(string: &'a OsStr) -> Self {
+        Self(encode_wide(string.as_encoded_bytes()))
+    }
+
+    /// Encodes as much of the remaining string as possible into a buffer.
+    ///
+    /// The returned value is the number of code units written. It will only
+    /// be smaller than the length of the buffer when the entire string has
+    /// been encoded.
+    #[inline]
+    pub fn encode(&mut self, buffer: &mut [u16]) -> usize {
+        let mut length = 0;
+        for (wchar, result) in buffer.iter_mut().zip(&mut self.0) {
+            *wchar = internal_expect!(
+                result.ok(),
+                char::REPLACEMENT_CHARACTER as u16,
+                "platform string contains invalid WTF-8, which should not be \
+                 possible",
+            );
+            length += 1;
+        }
+        length
+    }
+}
+
+impl Debug for WideEncoder<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WideEncoder").finish_non_exhaustive()
+    }
+}
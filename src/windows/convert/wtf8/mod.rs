@@ -1,15 +1,20 @@
 // This module implements the WTF-8 encoding specification:
 // https://simonsapin.github.io/wtf-8/
 
-use super::EncodingError;
-use super::Result;
+use std::result;
+
+use super::ErrorKind;
+
+pub(super) type Result<T> = result::Result<T, ErrorKind>;
 
 mod code_points;
+pub(super) use code_points::validate;
 use code_points::CodePoints;
 
 mod convert;
 pub(super) use convert::encode_wide;
 pub(super) use convert::DecodeWide;
+pub use convert::WideEncoder;
 
 if_raw_str! {
     if_conversions! {
@@ -1,23 +1,28 @@
+use crate::imp::SURROGATE_LENGTH;
 use crate::util;
 
-const SURROGATE_LENGTH: usize = 3;
-
 pub(crate) fn ends_with(string: &[u8], mut suffix: &[u8]) -> bool {
     let Some(index) = string.len().checked_sub(suffix.len()) else {
         return false;
     };
     if let Some(&byte) = string.get(index) {
         if util::is_continuation(byte) {
-            let index = expect_encoded!(index.checked_sub(1));
+            let index = internal_expect!(
+                index.checked_sub(1),
+                return false,
+                "invalid raw bytes",
+            );
             let mut wide_surrogate =
                 if let Some(surrogate) = suffix.get(..SURROGATE_LENGTH) {
                     super::encode_wide(surrogate)
                 } else {
                     return false;
                 };
-            let surrogate_wchar = wide_surrogate
-                .next()
-                .expect("failed decoding non-empty suffix");
+            let surrogate_wchar = internal_expect!(
+                wide_surrogate.next(),
+                return false,
+                "failed decoding non-empty suffix",
+            );
 
             if wide_surrogate.next().is_some()
                 || super::encode_wide(&string[index..])
@@ -42,16 +47,20 @@ pub(crate) fn starts_with(string: &[u8], mut prefix: &[u8]) -> bool {
             };
             let (substring, surrogate) = prefix.split_at(index);
             let mut wide_surrogate = super::encode_wide(surrogate);
-            let surrogate_wchar = wide_surrogate
-                .next()
-                .expect("failed decoding non-empty prefix");
+            let surrogate_wchar = internal_expect!(
+                wide_surrogate.next(),
+                return false,
+                "failed decoding non-empty prefix",
+            );
+            let substring_wchar = internal_expect!(
+                super::encode_wide(&string[index..]).next(),
+                return false,
+                "failed decoding non-empty substring",
+            );
 
             if surrogate_wchar.is_err()
                 || wide_surrogate.next().is_some()
-                || super::encode_wide(&string[index..])
-                    .next()
-                    .expect("failed decoding non-empty substring")
-                    != surrogate_wchar
+                || substring_wchar != surrogate_wchar
             {
                 return false;
             }
@@ -1,10 +1,42 @@
 use std::borrow::Cow;
+use std::char::REPLACEMENT_CHARACTER;
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::str;
 
+#[cfg(feature = "tracing")]
+use crate::trace;
+
+// Surrogate code points are the only invalid sequences in the internal
+// encoding, and they are always encoded using this many bytes.
+pub(crate) const SURROGATE_LENGTH: usize = 3;
+
+const _: () = assert!(
+    REPLACEMENT_CHARACTER.len_utf8() == SURROGATE_LENGTH,
+    "replacing surrogates would change the length of strings",
+);
+
+fn replace_surrogates(string: &mut [u8], mut index: usize) {
+    let mut replacement = [0; SURROGATE_LENGTH];
+    let _ = REPLACEMENT_CHARACTER.encode_utf8(&mut replacement);
+
+    loop {
+        let end = index + SURROGATE_LENGTH;
+        string[index..end].copy_from_slice(&replacement);
+        match str::from_utf8(&string[end..]) {
+            Ok(_) => break,
+            Err(error) => index = end + error.valid_up_to(),
+        }
+    }
+}
+
 pub(crate) fn os_str_from_bytes(string: &[u8]) -> Option<&OsStr> {
-    str::from_utf8(string).map(OsStr::new).ok()
+    let result = str::from_utf8(string);
+    #[cfg(feature = "tracing")]
+    if let Err(error) = &result {
+        trace::io_failure(string, error.valid_up_to());
+    }
+    result.map(OsStr::new).ok()
 }
 
 pub(crate) fn os_str_to_bytes(string: &OsStr) -> Option<&'_ [u8]> {
@@ -12,14 +44,24 @@ pub(crate) fn os_str_to_bytes(string: &OsStr) -> Option<&'_ [u8]> {
 }
 
 pub(crate) fn os_str_to_bytes_lossy(string: &OsStr) -> Cow<'_, [u8]> {
-    match string.to_string_lossy() {
-        Cow::Borrowed(string) => Cow::Borrowed(string.as_bytes()),
-        Cow::Owned(string) => Cow::Owned(string.into_bytes()),
+    let string = string.as_encoded_bytes();
+    match str::from_utf8(string) {
+        Ok(_) => Cow::Borrowed(string),
+        Err(error) => {
+            let mut string = string.to_owned();
+            replace_surrogates(&mut string, error.valid_up_to());
+            Cow::Owned(string)
+        }
     }
 }
 
 pub(crate) fn os_string_from_vec(string: Vec<u8>) -> Option<OsString> {
-    String::from_utf8(string).ok().map(Into::into)
+    let result = String::from_utf8(string);
+    #[cfg(feature = "tracing")]
+    if let Err(error) = &result {
+        trace::io_failure(error.as_bytes(), error.utf8_error().valid_up_to());
+    }
+    result.ok().map(Into::into)
 }
 
 pub(crate) fn os_string_into_vec(string: OsString) -> Option<Vec<u8>> {
@@ -27,11 +69,9 @@ pub(crate) fn os_string_into_vec(string: OsString) -> Option<Vec<u8>> {
 }
 
 pub(crate) fn os_string_into_vec_lossy(string: OsString) -> Vec<u8> {
-    let string = string.into_encoded_bytes();
-    match String::from_utf8_lossy(&string) {
-        // SAFETY: This slice was validated to be UTF-8.
-        Cow::Borrowed(_) => unsafe { String::from_utf8_unchecked(string) },
-        Cow::Owned(string) => string,
+    let mut string = string.into_encoded_bytes();
+    if let Err(error) = str::from_utf8(&string) {
+        replace_surrogates(&mut string, error.valid_up_to());
     }
-    .into_bytes()
+    string
 }
@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "forbid_unsafe", forbid(unsafe_code))]
+
 if_conversions! {
     #[cfg(target_os = "uefi")]
     use std::os::uefi as os;
@@ -5,12 +7,17 @@ if_conversions! {
     use std::os::windows as os;
 }
 
+#[cfg(all(feature = "console", not(feature = "forbid_unsafe"), windows))]
+pub(super) mod console;
+
 pub(super) mod convert_io;
 
 if_conversions! {
     pub(super) mod convert;
+}
+
+if_raw_str! {
+    pub(crate) use convert_io::SURROGATE_LENGTH;
 
-    if_raw_str! {
-        pub(super) mod raw;
-    }
+    pub(super) mod raw;
 }
@@ -1,2 +1,261 @@
-pub(crate) use super::convert::ends_with;
-pub(crate) use super::convert::starts_with;
+use std::char;
+use std::ffi::OsStr;
+#[cfg(feature = "forbid_unsafe")]
+use std::ffi::OsString;
+use std::ops::Range;
+#[cfg(target_os = "uefi")]
+use std::os::uefi::ffi::OsStrExt;
+#[cfg(all(feature = "forbid_unsafe", target_os = "uefi"))]
+use std::os::uefi::ffi::OsStringExt;
+#[cfg(windows)]
+use std::os::windows::ffi::OsStrExt;
+#[cfg(all(feature = "forbid_unsafe", windows))]
+use std::os::windows::ffi::OsStringExt;
+#[cfg(feature = "forbid_unsafe")]
+use std::str;
+
+use crate::ext;
+use crate::util::BYTE_SHIFT;
+use crate::util::CONT_MASK;
+use crate::util::CONT_TAG;
+use crate::util::MAX_UTF8_LENGTH;
+
+use super::SURROGATE_LENGTH;
+
+if_conversions! {
+    pub(crate) use super::convert::ends_with;
+    pub(crate) use super::convert::starts_with;
+}
+
+const MIN_LOW_SURROGATE: u16 = 0xDC00;
+
+// Returns the WTF-8 encoding of a string, computed from its code units, which
+// are guaranteed by the standard library unlike the internal encoding of
+// [OsStr].
+pub(crate) fn canonical_bytes(
+    string: &OsStr,
+) -> impl '_ + Iterator<Item = u8> {
+    char::decode_utf16(string.encode_wide()).flat_map(|ch| {
+        let mut buffer = [0; MAX_UTF8_LENGTH];
+        let length = match ch {
+            Ok(ch) => ch.encode_utf8(&mut buffer).len(),
+            Err(error) => {
+                let surrogate = error.unpaired_surrogate();
+                buffer[..SURROGATE_LENGTH].copy_from_slice(&[
+                    0xE0 | (surrogate >> (2 * BYTE_SHIFT)) as u8,
+                    CONT_TAG | ((surrogate >> BYTE_SHIFT) as u8 & CONT_MASK),
+                    CONT_TAG | (surrogate as u8 & CONT_MASK),
+                ]);
+                SURROGATE_LENGTH
+            }
+        };
+        buffer.into_iter().take(length)
+    })
+}
+
+fn decode(string: &[u8]) -> u32 {
+    string[1..].iter().fold(
+        u32::from(string[0] & (0xFF >> (string.len() + 1))),
+        |code_point, &byte| {
+            (code_point << BYTE_SHIFT) | u32::from(byte & CONT_MASK)
+        },
+    )
+}
+
+// Returns the lone surrogate at the start of a string.
+fn lone_surrogate(string: &[u8]) -> Option<u16> {
+    let string = string.get(..SURROGATE_LENGTH)?;
+    (string[0] == 0xED && string[1] >= 0xA0).then(|| decode(string) as u16)
+}
+
+// Returns the character formed by a high surrogate at the end of a string and
+// a low surrogate at the start of another, followed by the rest of the other
+// string.
+pub(crate) fn join_surrogates<'a>(
+    string: &[u8],
+    other: &'a [u8],
+) -> Option<(char, &'a [u8])> {
+    let high = string
+        .len()
+        .checked_sub(SURROGATE_LENGTH)
+        .and_then(|x| lone_surrogate(&string[x..]))
+        .filter(|&x| x < MIN_LOW_SURROGATE)?;
+    let low = lone_surrogate(other).filter(|&x| x >= MIN_LOW_SURROGATE)?;
+    let ch = char::decode_utf16([high, low]).next()?.ok()?;
+    Some((ch, &other[SURROGATE_LENGTH..]))
+}
+
+// Returns the surrogates that would encode the supplementary character at the
+// start of a string.
+fn surrogate_pair(string: &[u8]) -> Option<(u16, u16)> {
+    let string = string.get(..SURROGATE_LENGTH + 1)?;
+    (string[0] >= 0xF0).then(|| {
+        let offset = decode(string) - 0x10000;
+        (
+            (offset >> 10) as u16 | 0xD800,
+            (offset & 0x3FF) as u16 | MIN_LOW_SURROGATE,
+        )
+    })
+}
+
+// Returns the length of the character at the end of a string, if it is or
+// contains a surrogate.
+fn ends_with_surrogate(string: &[u8], surrogate: u16) -> Option<usize> {
+    let index = string.len();
+    index
+        .checked_sub(SURROGATE_LENGTH)
+        .and_then(|x| lone_surrogate(&string[x..]))
+        .filter(|&x| x == surrogate)
+        .map(|_| SURROGATE_LENGTH)
+        .or_else(|| {
+            index
+                .checked_sub(SURROGATE_LENGTH + 1)
+                .and_then(|x| surrogate_pair(&string[x..]))
+                .filter(|&(_, low)| low == surrogate)
+                .map(|_| SURROGATE_LENGTH + 1)
+        })
+}
+
+// Returns the length of the character at the start of a string, if it is or
+// contains a surrogate.
+fn starts_with_surrogate(string: &[u8], surrogate: u16) -> Option<usize> {
+    lone_surrogate(string)
+        .filter(|&x| x == surrogate)
+        .map(|_| SURROGATE_LENGTH)
+        .or_else(|| {
+            surrogate_pair(string)
+                .filter(|&(high, _)| high == surrogate)
+                .map(|_| SURROGATE_LENGTH + 1)
+        })
+}
+
+// Passes each code unit of a string to a function, or returns [None] if the
+// bytes do not use the internal encoding of [OsStr].
+#[cfg(feature = "forbid_unsafe")]
+fn for_each_unit<F>(mut string: &[u8], mut f: F) -> Option<()>
+where
+    F: FnMut(u16),
+{
+    let mut follows_high_surrogate = false;
+    while !string.is_empty() {
+        let (valid, invalid) = match str::from_utf8(string) {
+            Ok(valid) => (valid, &[][..]),
+            Err(error) => {
+                let (valid, invalid) = string.split_at(error.valid_up_to());
+                (str::from_utf8(valid).ok()?, invalid)
+            }
+        };
+        if !valid.is_empty() {
+            valid.encode_utf16().for_each(&mut f);
+            follows_high_surrogate = false;
+        }
+        if invalid.is_empty() {
+            break;
+        }
+
+        if !matches!(invalid, [0xED, 0xA0..=0xBF, 0x80..=0xBF, ..]) {
+            return None;
+        }
+        let surrogate = decode(&invalid[..SURROGATE_LENGTH]) as u16;
+        let is_high = surrogate < MIN_LOW_SURROGATE;
+        // Surrogate pairs must be encoded as supplementary characters.
+        if follows_high_surrogate && !is_high {
+            return None;
+        }
+        follows_high_surrogate = is_high;
+        f(surrogate);
+        string = &invalid[SURROGATE_LENGTH..];
+    }
+    Some(())
+}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(crate) fn is_valid(string: &[u8]) -> bool {
+    for_each_unit(string, |_| ()).is_some()
+}
+
+#[cfg(feature = "forbid_unsafe")]
+pub(crate) fn os_string(string: Vec<u8>) -> Option<OsString> {
+    let mut units = Vec::with_capacity(string.len());
+    for_each_unit(&string, |x| units.push(x))?;
+    Some(OsString::from_wide(&units))
+}
+
+fn code_point_length(byte: u8) -> usize {
+    match byte {
+        0x00..=0x7F => 1,
+        0x80..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xFF => 4,
+    }
+}
+
+pub(crate) fn contains(string: &[u8], pat: &[u8]) -> bool {
+    find_os(string, pat, 0).is_some()
+}
+
+// The pattern cannot be searched for directly, since it may begin with a low
+// surrogate or end with a high surrogate. The string may contain these
+// surrogates as part of a supplementary character, which is encoded
+// differently. Therefore, they are removed from the pattern and checked
+// separately for each match of the remaining substring. When they are part of
+// a supplementary character, the returned range includes that character.
+pub(crate) fn find_os(
+    string: &[u8],
+    mut pat: &[u8],
+    start: usize,
+) -> Option<Range<usize>> {
+    let low_surrogate =
+        lone_surrogate(pat).filter(|&x| x >= MIN_LOW_SURROGATE);
+    if low_surrogate.is_some() {
+        pat = &pat[SURROGATE_LENGTH..];
+    }
+    let high_surrogate = pat
+        .len()
+        .checked_sub(SURROGATE_LENGTH)
+        .and_then(|x| lone_surrogate(&pat[x..]))
+        .filter(|&x| x < MIN_LOW_SURROGATE);
+    if high_surrogate.is_some() {
+        pat = &pat[..pat.len() - SURROGATE_LENGTH];
+    }
+    if low_surrogate.is_none() && high_surrogate.is_none() {
+        return ext::find(&string[start..], pat).map(|x| {
+            let x = start + x;
+            x..x + pat.len()
+        });
+    }
+
+    let find_match = |index: usize| {
+        let mut range = index..index + pat.len();
+        if let Some(surrogate) = low_surrogate {
+            range.start -= ends_with_surrogate(&string[..index], surrogate)?;
+        }
+        if let Some(surrogate) = high_surrogate {
+            range.end +=
+                starts_with_surrogate(&string[range.end..], surrogate)?;
+        }
+        (range.start >= start).then_some(range)
+    };
+
+    let mut index = start;
+    if pat.is_empty() {
+        while string.get(index).is_some_and(|&x| x & 0xC0 == 0x80) {
+            index += 1;
+        }
+        loop {
+            if let Some(range) = find_match(index) {
+                return Some(range);
+            }
+            let &byte = string.get(index)?;
+            index += code_point_length(byte);
+        }
+    }
+    while let Some(offset) = ext::find(&string[index..], pat) {
+        index += offset;
+        if let Some(range) = find_match(index) {
+            return Some(range);
+        }
+        index += 1;
+    }
+    None
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "icu")]
+
+use std::ffi::OsStr;
+
+use icu_collator::Collator;
+use icu_collator::CollatorOptions;
+use icu_collator::Strength;
+
+fn collator(strength: Strength) -> Collator {
+    let mut options = CollatorOptions::new();
+    options.strength = Some(strength);
+    Collator::try_new(&Default::default(), options).unwrap()
+}
+
+#[test]
+fn test_unicode() {
+    let collator = collator(Strength::Tertiary);
+    let mut strings = ["b", "B", "\u{E4}", "a", "A", ""].map(OsStr::new);
+    strings.sort_by(|x, y| os_str_bytes::collate(x, y, &collator));
+    assert_eq!(["", "a", "A", "\u{E4}", "b", "B"].map(OsStr::new), strings);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_unicode() {
+    use std::cmp::Ordering;
+    use std::os::unix::ffi::OsStrExt;
+
+    #[track_caller]
+    fn test(result: Ordering, string: &[u8], other: &[u8]) {
+        let collator = collator(Strength::Primary);
+        let string = OsStr::from_bytes(string);
+        let other = OsStr::from_bytes(other);
+        assert_eq!(result, os_str_bytes::collate(string, other, &collator));
+        assert_eq!(
+            result.reverse(),
+            os_str_bytes::collate(other, string, &collator),
+        );
+    }
+
+    test(Ordering::Less, b"a\xFF", b"B");
+    test(Ordering::Less, b"A", b"a\xFF");
+    test(Ordering::Less, b"a\xFE", b"A\xFF");
+    test(Ordering::Equal, b"a\xFF", b"A\xFF");
+    test(Ordering::Less, b"a\xFFb", b"a\xFFc");
+}
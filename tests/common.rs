@@ -36,8 +36,6 @@ if_checked_conversions! {
     pub(crate) type Result<T> = result::Result<T, EncodingError>;
 }
 
-pub(crate) const WTF8_STRING: &[u8] = b"foo\xED\xA0\xBD\xF0\x9F\x92\xA9bar";
-
 if_checked_conversions! {
     #[track_caller]
     fn test_from_bytes<'a, T, U, S>(result: &Result<U>, string: S)
@@ -1,10 +1,15 @@
 #![cfg(feature = "checked_conversions")]
 
+use std::ffi::CStr;
+use std::ffi::OsStr;
 use std::str;
 
+use os_str_bytes::test_util::WTF8_STRING;
+use os_str_bytes::OsStrBytes;
+use os_str_bytes::RawDecoder;
+
 mod common;
 use common::Result;
-use common::WTF8_STRING;
 
 fn assert_string_is_invalid_utf8(string: &[u8]) {
     assert!(str::from_utf8(string).is_err());
@@ -18,6 +23,24 @@ fn assert_invalid_result(result: &Result<()>) {
     }
 }
 
+#[test]
+fn test_c_str() {
+    #[track_caller]
+    fn test(result: bool, string: &[u8]) {
+        let string = CStr::from_bytes_with_nul(string).unwrap();
+        let os_string = OsStr::from_c_str(string);
+        assert_eq!(result, os_string.is_ok());
+        if let Ok(os_string) = os_string {
+            assert_eq!(string.to_bytes(), &*os_string.to_raw_bytes());
+        }
+    }
+
+    test(true, b"\0");
+    test(true, b"foobar\0");
+    test(cfg!(not(windows)), b"\xF1foo\xF1\x80bar\0");
+    test(cfg!(not(windows)), b"foo\xED\xA0\x80bar\0");
+}
+
 #[test]
 fn test_empty() {
     common::test_utf8_bytes("");
@@ -53,3 +76,87 @@ fn test_wtf8() {
         assert_eq!(Ok(()), common::test_vec(string));
     }
 }
+
+#[test]
+fn test_concat_raw() -> Result<()> {
+    const HIGH_SURROGATE: &[u8] = b"\xED\xA0\x80";
+    const LOW_SURROGATE: &[u8] = b"\xED\xB0\x80";
+
+    #[track_caller]
+    fn test(string: &[u8], other: &[u8]) -> Result<()> {
+        let mut os_string = OsStr::from_raw_bytes(string)?.into_owned();
+        os_string.push(OsStr::from_raw_bytes(other)?);
+        assert_eq!(
+            &*os_string.to_raw_bytes(),
+            os_str_bytes::concat_raw(string, other)?,
+        );
+        Ok(())
+    }
+
+    test(b"", b"")?;
+    test(b"foo", b"")?;
+    test(b"", b"bar")?;
+    test(b"f\xC3\xB6", b"\xC3\xB6o")?;
+    test(WTF8_STRING, WTF8_STRING)?;
+    test(HIGH_SURROGATE, LOW_SURROGATE)?;
+    test(LOW_SURROGATE, HIGH_SURROGATE)?;
+    test(HIGH_SURROGATE, HIGH_SURROGATE)?;
+
+    assert_eq!(
+        "foo\u{1F4A9}".as_bytes(),
+        os_str_bytes::concat_raw(b"foo\xF0\x9F", b"\x92\xA9")?,
+    );
+    assert_invalid_result(
+        &os_str_bytes::concat_raw(b"foo\xF0\x9F", b"\x92").map(drop),
+    );
+    assert_invalid_result(
+        &os_str_bytes::concat_raw(b"foo", b"\x92\xA9bar").map(drop),
+    );
+    Ok(())
+}
+
+#[test]
+fn test_partial() {
+    #[track_caller]
+    fn test(prefix_length: usize, string: &[u8]) {
+        let (os_string, remainder) = OsStr::from_raw_bytes_partial(string);
+        let (prefix, suffix) = string.split_at(prefix_length);
+        assert_eq!(prefix, &*os_string.to_raw_bytes());
+        assert_eq!(suffix, remainder);
+    }
+
+    const STRING: &[u8] = b"\xF1foo\xF1\x80bar\xF1\x80\x80baz";
+
+    test(0, b"");
+    test(6, b"foobar");
+    test(WTF8_STRING.len(), WTF8_STRING);
+    test(if cfg!(windows) { 0 } else { STRING.len() }, STRING);
+    test(if cfg!(windows) { 3 } else { 6 }, b"foo\xF0\x9F\x92");
+}
+
+#[test]
+fn test_decoder() {
+    #[track_caller]
+    fn test(string: &[u8]) {
+        let expected = OsStr::from_raw_bytes(string);
+        for index in 0..=string.len() {
+            let (prefix, suffix) = string.split_at(index);
+            let mut decoder = RawDecoder::new();
+            let result = decoder.feed(prefix).and_then(|mut os_string| {
+                os_string.push(decoder.feed(suffix)?);
+                os_string.push(decoder.finish()?);
+                Ok(os_string)
+            });
+            assert_eq!(expected.is_ok(), result.is_ok());
+            if let Ok(expected) = &expected {
+                assert_eq!(expected, &result.unwrap());
+            }
+        }
+    }
+
+    test(b"");
+    test(b"foo\xF0\x9F\x92\xA9bar");
+    test(WTF8_STRING);
+    test(b"\xED\xA0\x80\xED\xB0\x80");
+    test(b"\xF1foo\xF1\x80bar\xF1\x80\x80baz");
+}
@@ -43,3 +43,29 @@ fn test_lossless() {
         }
     }
 }
+
+#[test]
+fn test_wide() {
+    let mut rng = Rng::new();
+    for _ in 0..ITERATIONS {
+        let string: Vec<_> = (0..SMALL_LENGTH)
+            .map(|_| {
+                if rng.bool() {
+                    rng.u16(0xD800..=0xDFFF)
+                } else {
+                    rng.u16(..)
+                }
+            })
+            .collect();
+        let result = os_str_bytes::from_wide_strict(&string);
+        match String::from_utf16(&string) {
+            Ok(expected) => assert_eq!(Ok(expected.into()), result),
+            Err(_) => {
+                let error = result.unwrap_err();
+                assert_eq!(string[error.index()], error.unit());
+                assert!(String::from_utf16(&string[..error.index()]).is_ok());
+                assert_eq!(Err(error), os_str_bytes::validate_wide(&string));
+            }
+        }
+    }
+}
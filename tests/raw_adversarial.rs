@@ -0,0 +1,308 @@
+#![cfg(feature = "raw_os_str")]
+
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use os_str_bytes::test_util;
+use os_str_bytes::CharOrInvalid;
+use os_str_bytes::LimitUnit;
+use os_str_bytes::OsStrBytesExt;
+use os_str_bytes::OsStringBytesExt;
+use os_str_bytes::Unit;
+
+const MAX_PIECES: u32 = 4;
+
+// Every sequence of the adversarial pieces is tested, which includes every way that
+// surrogates and partial UTF-8 sequences can be adjacent.
+fn strings() -> impl Iterator<Item = OsString> {
+    test_util::adversarial_os_strings(MAX_PIECES)
+}
+
+fn push_unit(result: &mut OsString, unit: CharOrInvalid<'_>) {
+    match unit {
+        CharOrInvalid::Char(ch) => result.push(ch.encode_utf8(&mut [0; 4])),
+        CharOrInvalid::Invalid(invalid) => result.push(invalid.as_os_str()),
+    }
+}
+
+#[test]
+fn test_utf8_chunks() {
+    for string in strings() {
+        let mut result = OsString::new();
+        for (invalid, valid) in string.utf8_chunks() {
+            let invalid = invalid.as_os_str();
+            assert!(
+                invalid.is_empty() || !invalid.is_unicode(),
+                "{:?}",
+                string,
+            );
+            result.push(invalid);
+            result.push(valid);
+        }
+        assert_eq!(string, result);
+    }
+}
+
+#[test]
+fn test_split_first_last() {
+    for string in strings() {
+        let mut substring: &OsStr = &string;
+        let mut result = OsString::new();
+        while let Some((unit, suffix)) = substring.split_first() {
+            push_unit(&mut result, unit);
+            substring = suffix;
+        }
+        assert_eq!(string, result);
+
+        let mut substring: &OsStr = &string;
+        let mut units = Vec::new();
+        while let Some((unit, prefix)) = substring.split_last() {
+            units.push(unit);
+            substring = prefix;
+        }
+        let mut result = OsString::new();
+        for unit in units.into_iter().rev() {
+            push_unit(&mut result, unit);
+        }
+        assert_eq!(string, result);
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    for string in strings() {
+        let length = string.byte_len();
+        for index in 0..=length + 1 {
+            let valid = string.validate_boundary(index).is_ok();
+            assert_eq!(
+                valid,
+                string.split_at_checked(index).is_some(),
+                "{:?} {}",
+                string,
+                index,
+            );
+
+            if index > length {
+                assert!(!valid);
+                continue;
+            }
+            let floor = string.floor_char_boundary(index);
+            let ceil = string.ceil_char_boundary(index);
+            assert!(floor <= index && index <= ceil, "{:?}", string);
+            assert!(string.validate_boundary(floor).is_ok());
+            assert!(string.validate_boundary(ceil).is_ok());
+            if valid {
+                assert_eq!(index, floor);
+                assert_eq!(index, ceil);
+
+                let (prefix, suffix) = string.split_at(index);
+                let mut result = prefix.to_owned();
+                result.push(suffix);
+                assert_eq!(string, result);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_slice() {
+    for string in strings() {
+        let length = string.byte_len();
+        for index in 0..=length + 1 {
+            let prefix = string.slice_up_to(index);
+            let suffix = string.slice_from(index);
+            assert_eq!(string.floor_char_boundary(index), prefix.byte_len());
+            assert_eq!(
+                length - string.ceil_char_boundary(index),
+                suffix.byte_len(),
+            );
+            if string.validate_boundary(index).is_ok() {
+                assert_eq!(
+                    Some((prefix, suffix)),
+                    string.split_at_checked(index),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_chunk_by_size() {
+    for string in strings() {
+        for size in 1..=4 {
+            let chunks = string.chunk_by_size(size);
+            let (min_count, max_count) = chunks.size_hint();
+
+            let mut count = 0;
+            let mut result = OsString::new();
+            for chunk in chunks {
+                assert!(!chunk.is_empty());
+                result.push(chunk);
+                count += 1;
+            }
+            assert_eq!(string, result);
+            assert!(min_count <= count);
+            assert!(max_count.is_some_and(|x| count <= x));
+        }
+    }
+}
+
+#[test]
+fn test_truncate_to_limit() {
+    for string in strings() {
+        for unit in [LimitUnit::Utf8, LimitUnit::Utf16] {
+            for max_units in 0..=8 {
+                let prefix = string.truncate_to_limit(max_units, unit);
+                assert!(
+                    string
+                        .as_encoded_bytes()
+                        .starts_with(prefix.as_encoded_bytes()),
+                    "{:?}",
+                    string,
+                );
+                assert!(string.validate_boundary(prefix.byte_len()).is_ok());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_escape_ascii() {
+    for string in strings() {
+        assert_eq!(
+            Some(&string),
+            OsString::unescape_ascii(&string.escape_ascii())
+                .ok()
+                .as_ref(),
+        );
+    }
+}
+
+#[test]
+fn test_lossy() {
+    for string in strings() {
+        let lossy = string.to_string_lossy();
+        assert_eq!(string.is_unicode(), matches!(lossy, Cow::Borrowed(_)));
+        assert_eq!(string.to_str(), string.is_unicode().then_some(&*lossy));
+    }
+}
+
+#[test]
+fn test_units() {
+    for string in strings() {
+        let lossy: String = string
+            .units()
+            .map(|unit| match unit {
+                Unit::Ascii(byte) => byte.into(),
+                Unit::Unicode(ch) => {
+                    assert!(!ch.is_ascii());
+                    ch
+                }
+                Unit::Invalid(_) | Unit::Surrogate(_) => '\u{FFFD}',
+            })
+            .collect();
+        assert_eq!(string.to_string_lossy(), lossy);
+    }
+}
+
+#[test]
+fn test_split_nul() {
+    for string in strings() {
+        let mut result = OsString::new();
+        for (i, record) in string.split_nul().enumerate() {
+            if i != 0 {
+                result.push("\0");
+            }
+            assert!(!record.contains('\0'));
+            result.push(record);
+        }
+        if string.ends_with('\0') {
+            result.push("\0");
+        }
+        assert_eq!(string, result);
+    }
+}
+
+#[test]
+fn test_strip_bom() {
+    for string in strings() {
+        let stripped = string.strip_bom();
+        assert_eq!(
+            string.strip_prefix('\u{FEFF}').unwrap_or(&string),
+            stripped,
+        );
+    }
+}
+
+#[test]
+fn test_split_digits() {
+    for string in strings() {
+        let (digits, suffix) = string.split_leading_digits();
+        let digits = digits.unwrap_or_default();
+        assert!(digits.bytes().all(|x| x.is_ascii_digit()));
+        assert!(!suffix.starts_with_ascii_digit());
+        let mut result = OsString::from(digits);
+        result.push(suffix);
+        assert_eq!(string, result);
+
+        let (prefix, digits) = string.split_trailing_digits();
+        let digits = digits.unwrap_or_default();
+        assert!(digits.bytes().all(|x| x.is_ascii_digit()));
+        let mut result = prefix.to_owned();
+        result.push(digits);
+        assert_eq!(string, result);
+    }
+}
+
+#[test]
+fn test_find_any_of() {
+    fn test<const N: usize>(string: &OsStr, bytes: &[u8; N]) {
+        let string_bytes = string.as_encoded_bytes();
+        assert_eq!(
+            string_bytes.iter().position(|x| bytes.contains(x)),
+            string.find_any_of(bytes),
+        );
+        assert_eq!(
+            string_bytes.iter().rposition(|x| bytes.contains(x)),
+            string.rfind_any_of(bytes),
+        );
+    }
+
+    for string in strings() {
+        test(&string, &[]);
+        test(&string, b"7");
+        test(&string, b"a7");
+        test(&string, b"\0a7");
+        test(&string, b"/\0a7");
+    }
+}
+
+#[test]
+fn test_match_indices_os() {
+    let pats: Vec<_> = test_util::adversarial_os_strings(2).collect();
+    for string in strings() {
+        for pat in &pats {
+            let matches: Vec<_> = string.match_indices_os(pat).collect();
+            assert_eq!(
+                string.contains_os(pat),
+                !matches.is_empty(),
+                "{:?} {:?}",
+                string,
+                pat,
+            );
+
+            let string_bytes = string.as_encoded_bytes();
+            for &(index, substring) in &matches {
+                assert!(substring.contains_os(pat));
+                assert!(string_bytes[index..]
+                    .starts_with(substring.as_encoded_bytes()));
+            }
+            assert!(matches.windows(2).all(|x| x[0].0 <= x[1].0));
+            assert!(
+                matches.len()
+                    <= string.match_indices_os(pat).overlapping().count(),
+            );
+        }
+    }
+}
@@ -11,9 +11,8 @@ if_conversions! {
 
     use lazy_static::lazy_static;
 
+    use os_str_bytes::test_util::WTF8_STRING;
     use os_str_bytes::OsStrBytes;
-
-    use common::WTF8_STRING;
 }
 
 if_conversions! {
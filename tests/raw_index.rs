@@ -5,11 +5,14 @@ use std::panic;
 use std::panic::UnwindSafe;
 
 use os_str_bytes::OsStrBytesExt;
+use os_str_bytes::RawOsString;
 
 #[macro_use]
 mod raw_common;
 
 if_conversions! {
+    use std::ops::Range;
+
     use raw_common::WTF8_OS_STRING;
 }
 
@@ -32,6 +35,64 @@ if_conversions! {
         test(13);
     }
 
+    #[test]
+    fn test_validate_boundary() {
+        #[track_caller]
+        fn test(range: Option<Range<usize>>, index: usize) {
+            let error = WTF8_OS_STRING
+                .validate_boundary(index)
+                .expect_err("index is a valid boundary");
+            assert_eq!(index, error.index());
+            assert_eq!(range, error.code_point_range());
+        }
+
+        let surrogate_range = if cfg!(windows) { Some(3..6) } else { None };
+        test(surrogate_range.clone(), 4);
+        test(surrogate_range, 5);
+        test(Some(6..10), 7);
+        test(Some(6..10), 9);
+        test(None, 14);
+
+        assert_eq!(Ok(()), WTF8_OS_STRING.validate_boundary(6));
+        assert_eq!(Ok(()), WTF8_OS_STRING.validate_boundary(13));
+    }
+
+    #[test]
+    fn test_get_range() {
+        for index in 0..=WTF8_OS_STRING.len() + 1 {
+            let valid = WTF8_OS_STRING.validate_boundary(index).is_ok();
+            assert_eq!(valid, WTF8_OS_STRING.get_range(index..).is_some());
+            assert_eq!(valid, WTF8_OS_STRING.get_range(..index).is_some());
+            if valid {
+                assert_eq!(
+                    Some(WTF8_OS_STRING.index(index..)),
+                    WTF8_OS_STRING.get_range(index..),
+                );
+            }
+        }
+        assert_eq!(None, WTF8_OS_STRING.get_range(..=usize::MAX));
+    }
+
+    #[test]
+    fn test_get_unit() {
+        let mut joined = Vec::new();
+        let mut index = 0;
+        while let Some(unit) = WTF8_OS_STRING.get_range(index) {
+            assert_eq!(unit, WTF8_OS_STRING.index(index));
+            let (_, suffix) =
+                WTF8_OS_STRING.index(index..).split_first().unwrap();
+            assert_eq!(
+                WTF8_OS_STRING.len(),
+                index + unit.len() + suffix.len(),
+            );
+
+            joined.extend_from_slice(unit.as_encoded_bytes());
+            index += unit.len();
+        }
+        assert_eq!(WTF8_OS_STRING.as_encoded_bytes(), joined);
+        assert_eq!(None, WTF8_OS_STRING.get_range(7));
+    }
+
     macro_rules! test {
         ( $name:ident , $index:literal ) => {
             // https://github.com/rust-lang/rust/issues/88430
@@ -74,4 +135,9 @@ fn test_panics() {
     test(|| string.index(..1));
     test(|| string.index(..=0));
     test(|| string.split_at(1));
+    test(|| string.index(1));
+    test(|| string.index(2));
+
+    let mut string = RawOsString::new(string);
+    test(move || string.as_mut_raw().index_mut(1..).make_ascii_uppercase());
 }
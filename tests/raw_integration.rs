@@ -3,17 +3,68 @@
 use std::ffi::OsStr;
 
 use os_str_bytes::OsStrBytesExt;
+use os_str_bytes::RawOsString;
 
 #[macro_use]
 mod raw_common;
 
 if_conversions! {
+    use std::borrow::Cow;
+    use std::cmp::Ordering;
+    use std::ffi::OsString;
+
     use os_str_bytes::OsStrBytes;
+    use os_str_bytes::OsStringBytes;
+    use os_str_bytes::OsStringBytesExt;
+    use os_str_bytes::sanitize::Sanitizer;
 
     use raw_common::WTF8_OS_STRING;
 }
 
 if_conversions! {
+    #[test]
+    fn test_contains_os() {
+        #[track_caller]
+        fn test(result: bool, pat: &[u8]) {
+            let pat = OsStr::assert_from_raw_bytes(pat);
+            assert_eq!(result, WTF8_OS_STRING.contains_os(&pat));
+        }
+
+        test(true, b"");
+        test(true, b"oo");
+        test(true, b"o\xED\xA0\xBD");
+        test(true, b"\xED\xA0\xBD\xF0\x9F\x92\xA9");
+        test(true, b"\xF0\x9F\x92\xA9ba");
+        test(cfg!(windows), b"\xED\xA0\xBD\xED\xA0\xBD");
+        test(cfg!(windows), b"\xED\xB2\xA9b");
+        test(cfg!(not(windows)), b"\x9F\x92");
+
+        test(false, b"\xED\xB2\xA9\xED\xA0\xBD");
+        test(false, b"of");
+    }
+
+    #[test]
+    fn test_cross_platform_cmp() {
+        #[track_caller]
+        fn test(result: Ordering, other: &str) {
+            assert_eq!(
+                result,
+                WTF8_OS_STRING.cross_platform_cmp(OsStr::new(other)),
+            );
+        }
+
+        test(Ordering::Greater, "foo");
+        test(Ordering::Less, "fop");
+        test(
+            if cfg!(windows) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            },
+            "foo\u{FFFF}",
+        );
+    }
+
     #[test]
     fn test_ends_with() {
         #[track_caller]
@@ -57,6 +108,84 @@ if_conversions! {
         test(false, "ar");
     }
 
+    #[test]
+    fn test_is_unicode() {
+        assert!(!OsStrBytesExt::is_ascii(&**WTF8_OS_STRING));
+        assert!(!WTF8_OS_STRING.is_unicode());
+        assert!(WTF8_OS_STRING.has_invalid_sequences());
+
+        let (prefix, _) = WTF8_OS_STRING.split_at(3);
+        assert!(OsStrBytesExt::is_ascii(prefix));
+        assert!(prefix.is_unicode());
+        assert!(!prefix.has_invalid_sequences());
+    }
+
+    #[test]
+    fn test_len() {
+        let (chars, wide) = if cfg!(windows) { (8, 9) } else { (10, 11) };
+        assert_eq!(chars, WTF8_OS_STRING.len_chars());
+        assert_eq!(wide, WTF8_OS_STRING.len_wide());
+    }
+
+    #[test]
+    fn test_match_indices_os() {
+        #[track_caller]
+        fn test(result: &[(usize, &[u8])], pat: &[u8]) {
+            let pat = OsStr::assert_from_raw_bytes(pat);
+            assert!(WTF8_OS_STRING
+                .match_indices_os(&pat)
+                .map(|(index, x)| (index, x.to_raw_bytes()))
+                .eq(result.iter().map(|&(index, x)| (index, x.into()))));
+        }
+
+        test(&[(1, b"o"), (2, b"o")], b"o");
+        test(&[(6, b"\xF0\x9F\x92\xA9")], b"\xF0\x9F\x92\xA9");
+        if cfg!(windows) {
+            test(
+                &[(3, b"\xED\xA0\xBD\xF0\x9F\x92\xA9")],
+                b"\xED\xA0\xBD\xED\xA0\xBD",
+            );
+            test(&[(6, b"\xF0\x9F\x92\xA9b")], b"\xED\xB2\xA9b");
+        } else {
+            test(&[(6, b"\xF0\x9F\x92\xA9")], b"\x9F\x92");
+        }
+
+        test(&[], b"of");
+    }
+
+    #[test]
+    fn test_nt_case_eq() {
+        #[track_caller]
+        fn test(result: bool, other: &[u8]) {
+            let other = OsStr::assert_from_raw_bytes(other);
+            assert_eq!(result, WTF8_OS_STRING.nt_case_eq(&other));
+        }
+
+        test(true, b"foo\xED\xA0\xBD\xF0\x9F\x92\xA9bar");
+        test(true, b"FOO\xED\xA0\xBD\xF0\x9F\x92\xA9BAR");
+        test(true, b"FoO\xED\xA0\xBD\xF0\x9F\x92\xA9bAr");
+
+        test(false, b"FOO\xED\xA0\xBE\xF0\x9F\x92\xA9BAR");
+        test(false, b"FOO\xED\xA0\xBDBAR");
+    }
+
+    #[test]
+    fn test_sanitize() {
+        let mut os_string = OsString::from("nul. <");
+        os_string.push(&*WTF8_OS_STRING);
+        os_string.push(". .");
+
+        let sanitizer = Sanitizer::new().non_unicode_replacement(Some('?'));
+        assert_eq!(
+            "_nul. _foo?\u{1F4A9}bar",
+            &*sanitizer.sanitize(&os_string),
+        );
+        assert!(matches!(
+            Sanitizer::new().sanitize(&WTF8_OS_STRING),
+            Cow::Borrowed(_),
+        ));
+    }
+
     #[test]
     fn test_starts_with() {
         #[track_caller]
@@ -101,6 +230,122 @@ if_conversions! {
     }
 }
 
+if_conversions! {
+    #[test]
+    fn test_code_points() {
+        let surrogate: &[_] = if cfg!(windows) {
+            &[Ok(0xD83D)]
+        } else {
+            &[Err(0xED), Err(0xA0), Err(0xBD)]
+        };
+        let code_points: Vec<_> = [Ok(0x66), Ok(0x6F), Ok(0x6F)]
+            .iter()
+            .chain(surrogate)
+            .chain(&[Ok(0x1F4A9), Ok(0x62), Ok(0x61), Ok(0x72)])
+            .copied()
+            .collect();
+        assert!(WTF8_OS_STRING.code_points().eq(code_points));
+    }
+
+    #[test]
+    fn test_io_lossy() {
+        let string = WTF8_OS_STRING.to_io_bytes_lossy();
+        if cfg!(windows) {
+            assert_eq!(b"foo\xEF\xBF\xBD\xF0\x9F\x92\xA9bar", &*string);
+        }
+        assert_eq!(
+            string,
+            WTF8_OS_STRING.clone().into_owned().into_io_vec_lossy(),
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(0x01B52C9074A81092, WTF8_OS_STRING.fingerprint());
+    }
+
+    #[test]
+    fn test_map_utf8() {
+        let result = WTF8_OS_STRING.map_utf8(|x| x.to_uppercase().into());
+        assert_eq!(
+            b"FOO\xED\xA0\xBD\xF0\x9F\x92\xA9BAR",
+            &*result.to_raw_bytes(),
+        );
+        assert!(matches!(
+            WTF8_OS_STRING.map_utf8(|x| x.to_lowercase().into()),
+            Cow::Borrowed(_),
+        ));
+    }
+
+    #[test]
+    fn test_io_into() {
+        let mut buffer = b"baz".to_vec();
+        let result = WTF8_OS_STRING.to_io_bytes_into(&mut buffer).is_ok();
+        assert_eq!(result, WTF8_OS_STRING.to_io_bytes().is_some());
+        if result {
+            assert_eq!(WTF8_OS_STRING.to_io_bytes(), Some(&*buffer));
+        } else {
+            assert_eq!(b"baz", &*buffer);
+        }
+
+        let mut os_buffer = OsString::from("baz");
+        assert_eq!(
+            result,
+            OsStr::from_io_bytes_into(&buffer, &mut os_buffer).is_ok(),
+        );
+        if result {
+            assert_eq!(**WTF8_OS_STRING, os_buffer);
+        }
+    }
+
+    #[cfg(feature = "equivalent")]
+    #[test]
+    fn test_equivalent() {
+        use std::collections::hash_map::RandomState;
+        use std::hash::BuildHasher;
+
+        use equivalent::Equivalent;
+
+        let raw = RawOsString::new(WTF8_OS_STRING.clone().into_owned());
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(&raw), state.hash_one(&**WTF8_OS_STRING));
+        assert!((**WTF8_OS_STRING).equivalent(&raw));
+        assert!(!OsStr::new("foo").equivalent(&raw));
+    }
+
+    #[cfg(feature = "console")]
+    #[test]
+    fn test_write_lossless() -> std::io::Result<()> {
+        use os_str_bytes::console;
+
+        let mut buffer = Vec::new();
+        console::write_lossless(&WTF8_OS_STRING, &mut buffer)?;
+        assert_eq!(WTF8_OS_STRING.to_io_bytes_lossy(), &*buffer);
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_into_pattern() {
+        use os_str_bytes::RawOsStr;
+        use os_str_bytes::TryIntoPattern;
+
+        let raw = RawOsStr::new(&*WTF8_OS_STRING);
+        assert!(raw.try_into_pattern().is_err());
+        assert_eq!(Ok("foo"), raw[..3].try_into_pattern());
+    }
+
+    #[test]
+    fn test_retain_unicode() {
+        let mut os_string = WTF8_OS_STRING.clone().into_owned();
+        os_string.retain_unicode();
+        assert_eq!("foo\u{1F4A9}bar", os_string);
+
+        let mut os_string = WTF8_OS_STRING.clone().into_owned();
+        os_string.clear_non_unicode();
+        assert_eq!("", os_string);
+    }
+}
+
 if_conversions! {
     #[should_panic = "cannot split using an empty pattern"]
     #[test]
@@ -109,8 +354,598 @@ if_conversions! {
     }
 }
 
+#[test]
+fn test_read_nul_delimited() -> std::io::Result<()> {
+    use os_str_bytes::io;
+
+    let records: Vec<_> = io::read_nul_delimited(&b"foo\0\0bar"[..])
+        .collect::<std::io::Result<_>>()?;
+    assert_eq!(["foo", "", "bar"], &*records);
+
+    let mut records = io::read_nul_delimited(&b"\xFF\0foo\0"[..]);
+    assert_eq!(cfg!(windows), records.next().unwrap().is_err());
+    assert_eq!("foo", records.next().unwrap()?);
+    assert!(records.next().is_none());
+    Ok(())
+}
+
 #[should_panic = "cannot split using an empty pattern"]
 #[test]
 fn test_split_empty_by_empty() {
     let _ = OsStr::new("").split("");
 }
+
+#[test]
+fn test_try_split_by_empty() {
+    let string = OsStr::new("foo");
+    assert_eq!(
+        "cannot split using an empty pattern",
+        string.try_split("").unwrap_err().to_string(),
+    );
+    assert!(string.try_rsplit("").is_err());
+    assert!(string.try_split('o').is_ok());
+}
+
+#[test]
+fn test_split_remainder() {
+    let mut split = OsStr::new("foo,bar,baz").split(",");
+    assert_eq!("foo,bar,baz", split.as_os_str());
+    assert_eq!(Some(OsStr::new("foo")), split.next());
+    assert_eq!("bar,baz", split.as_os_str());
+    assert_eq!(2, split.by_ref().count());
+    assert_eq!("", split.as_os_str());
+
+    let mut split = OsStr::new("foo,bar,baz").rsplit(",");
+    assert_eq!(Some(OsStr::new("baz")), split.next());
+    assert_eq!("foo,bar", split.as_os_str());
+}
+
+#[test]
+fn test_split_indices() {
+    let string = OsStr::new("\u{1F4A9}ab\u{1F4A9}\u{1F4A9}c\u{1F4A9}");
+    let mut count = 0;
+    for (index, substring) in string.split_indices('\u{1F4A9}') {
+        assert_eq!(substring, string.index(index..index + substring.len()));
+        count += 1;
+    }
+    assert_eq!(string.split('\u{1F4A9}').count(), count);
+}
+
+#[test]
+fn test_split_offset() {
+    use os_str_bytes::iter::MatchIndicesOs;
+    use os_str_bytes::iter::RSplit;
+    use os_str_bytes::iter::Split;
+
+    let string = OsStr::new("foo,b\u{E9}r,baz");
+
+    let mut split = string.split(",");
+    assert_eq!(0, split.offset());
+    assert_eq!(Some(OsStr::new("foo")), split.next());
+    assert_eq!(4, split.offset());
+    let resumed = Split::with_offset(string, ",", split.offset()).unwrap();
+    assert!(resumed.eq(split.by_ref()));
+    assert_eq!(string.len(), split.offset());
+
+    let mut split = string.rsplit(",");
+    assert_eq!(string.len(), split.offset());
+    assert_eq!(Some(OsStr::new("baz")), split.next());
+    assert_eq!(8, split.offset());
+    let resumed = RSplit::with_offset(string, ",", split.offset()).unwrap();
+    assert!(resumed.eq(split.by_ref()));
+    assert_eq!(0, split.offset());
+
+    assert!(Split::with_offset(string, ",", 6).is_err());
+    assert!(Split::with_offset(string, ",", string.len() + 1).is_err());
+    assert!(Split::with_offset(string, ",", string.len())
+        .unwrap()
+        .eq([OsStr::new("")]));
+
+    let pat = OsStr::new(",");
+    let mut matches = string.match_indices_os(pat);
+    assert_eq!(Some((3, pat)), matches.next());
+    let resumed =
+        MatchIndicesOs::with_offset(string, pat, matches.offset()).unwrap();
+    assert!(resumed.eq(matches.by_ref()));
+    assert_eq!(string.len(), matches.offset());
+    assert!(MatchIndicesOs::with_offset(string, pat, 6).is_err());
+}
+
+#[test]
+fn test_split_keep() {
+    use os_str_bytes::iter::SplitItem;
+
+    for string in ["", "\u{1F4A9}", "\u{1F4A9}ab\u{1F4A9}\u{1F4A9}c\u{1F4A9}"]
+    {
+        let string = OsStr::new(string);
+        let mut joined = Vec::new();
+        for item in string.split_keep('\u{1F4A9}') {
+            joined.extend_from_slice(match item {
+                SplitItem::Field(field) => field.as_encoded_bytes(),
+                SplitItem::Separator(separator) => separator.as_bytes(),
+            });
+        }
+        assert_eq!(string.as_encoded_bytes(), joined);
+        assert_eq!(
+            string.split('\u{1F4A9}').count() * 2 - 1,
+            string.split_keep('\u{1F4A9}').count(),
+        );
+    }
+}
+
+#[test]
+fn test_chunk_by_size() {
+    let string = OsStr::new("\u{1F4A9}a\u{F6}\u{1F4A9}bc");
+    for size in 1..=string.len() {
+        let mut joined = Vec::new();
+        for chunk in string.chunk_by_size(size) {
+            assert!(chunk.len() <= size.max(4));
+            assert!(!chunk.is_empty());
+            joined.extend_from_slice(chunk.as_encoded_bytes());
+        }
+        assert_eq!(string.as_encoded_bytes(), joined);
+    }
+    assert!(string.chunk_by_size(1).eq([
+        "\u{1F4A9}",
+        "a",
+        "\u{F6}",
+        "\u{1F4A9}",
+        "b",
+        "c",
+    ]));
+    assert_eq!(0, OsStr::new("").chunk_by_size(1).count());
+}
+
+#[test]
+fn test_unescape_ascii_invalid() {
+    use std::ffi::OsString;
+
+    use os_str_bytes::OsStringBytesExt;
+
+    for (index, string) in [
+        (1, r"f\"),
+        (1, r"f\n"),
+        (0, r"\x6"),
+        (0, r"\x+6"),
+        (2, r"fo\u{}"),
+        (0, r"\u{1234567}"),
+        (0, r"\u{110000}"),
+        (0, r"\u66"),
+    ] {
+        assert_eq!(
+            Err(index),
+            OsString::unescape_ascii(string).map_err(|x| x.index()),
+            "{:?}",
+            string,
+        );
+    }
+
+    let surrogate = OsString::unescape_ascii(r"\u{d83d}");
+    assert_eq!(cfg!(windows), surrogate.is_ok());
+    let byte = OsString::unescape_ascii(r"\xff");
+    assert_eq!(cfg!(unix), byte.is_ok());
+}
+
+#[test]
+fn test_extend() {
+    use std::borrow::Cow;
+
+    let mut raw: RawOsString =
+        [Cow::Borrowed(OsStr::new("foo")), Cow::Owned("bar".into())]
+            .into_iter()
+            .collect();
+    raw.extend([RawOsString::new("baz")]);
+    raw.extend([Cow::Borrowed(OsStr::new("qux"))]);
+    assert_eq!("foobarbazqux", raw);
+}
+
+#[test]
+fn test_replace_prefix() {
+    use std::borrow::Cow;
+
+    let string = OsStr::new("\u{1F4A9}foo");
+    assert!(matches!(
+        string.replace_prefix(OsStr::new("bar"), OsStr::new("baz")),
+        Cow::Borrowed(_),
+    ));
+    assert_eq!(
+        "barfoo",
+        &*string.replace_prefix(OsStr::new("\u{1F4A9}"), OsStr::new("bar")),
+    );
+    assert_eq!(
+        "bar\u{1F4A9}foo",
+        &*string.replace_prefix(OsStr::new(""), OsStr::new("bar")),
+    );
+    assert_eq!("", &*string.replace_prefix(string, OsStr::new("")));
+}
+
+#[test]
+fn test_split_with() {
+    use os_str_bytes::SplitOptions;
+
+    let options = SplitOptions::new();
+    for string in ["", "/", "a/b", "/a//b/"] {
+        let string = OsStr::new(string);
+        assert!(string.split_with('/', options).eq(string.split('/')));
+    }
+
+    let options = options.skip_trailing_empty(true);
+    assert_eq!(0, OsStr::new("").split_with('/', options).count());
+    assert!(OsStr::new("/").split_with('/', options).eq([""]));
+
+    let options = SplitOptions::new().skip_leading_empty(true);
+    assert_eq!(0, OsStr::new("").split_with('/', options).count());
+    assert!(OsStr::new("/").split_with('/', options).eq([""]));
+}
+
+#[test]
+fn test_string_storage() {
+    for length in [0, 1, 21, 22, 23, 64] {
+        let string = "a".repeat(length);
+        let mut raw = RawOsString::new(string.clone());
+        assert_eq!(string, raw.clone());
+        assert_eq!(string.as_bytes(), raw.as_encoded_bytes());
+
+        let suffix = raw.split_off(length / 2);
+        assert_eq!(&string[..length / 2], raw);
+        assert_eq!(&string[length / 2..], suffix);
+
+        raw.truncate(0);
+        assert!(raw.is_empty());
+        assert_eq!(Ok(string.clone()), RawOsString::new(string).into_string());
+    }
+}
+
+#[test]
+fn test_reserve() {
+    for length in [0, 1, 21, 22, 23, 64] {
+        let string = "a".repeat(length);
+        for additional in [0, 1, 22, 64] {
+            let mut raw = RawOsString::new(string.clone());
+            raw.reserve(additional);
+            assert_eq!(string, raw);
+
+            let suffix = "b".repeat(additional);
+            raw.extend_from_os_strs([OsStr::new(&suffix)]);
+            assert_eq!(string.clone() + &suffix, raw);
+        }
+    }
+
+    let strings = ["foo", "", "bar"].map(OsStr::new);
+    let mut raw = RawOsString::new("");
+    raw.reserve_for(&strings);
+    raw.extend_from_os_strs(&strings);
+    assert_eq!("foobar", raw);
+}
+
+#[test]
+fn test_unicode_cache() {
+    let mut raw = RawOsString::new("foo\u{F6}");
+    assert!(raw.is_unicode_cached());
+    assert_eq!(raw, raw.clone());
+
+    let suffix = raw.split_off(3);
+    assert!(raw.is_unicode_cached());
+    assert!(suffix.is_unicode_cached());
+
+    raw.as_mut_raw().replace_ascii('f', 'b');
+    assert!(raw.is_unicode_cached());
+    assert_eq!("boo", raw);
+
+    #[cfg(unix)]
+    {
+        use std::ffi::OsString;
+        use std::os::unix::ffi::OsStringExt;
+
+        raw.as_mut_os_string().push(OsString::from_vec(vec![0xF0]));
+        assert!(!raw.is_unicode_cached());
+        assert!(!raw.is_unicode_cached());
+
+        raw.truncate(3);
+        assert!(raw.is_unicode_cached());
+    }
+}
+
+#[test]
+fn test_iter_traits() {
+    use std::fmt::Debug;
+
+    use os_str_bytes::iter::CodePoints;
+    use os_str_bytes::iter::IntoUtf8Chunks;
+    use os_str_bytes::iter::RSplit;
+    use os_str_bytes::iter::RawRSplit;
+    use os_str_bytes::iter::RawSplit;
+    use os_str_bytes::iter::RawSplitIndices;
+    use os_str_bytes::iter::Split;
+    use os_str_bytes::iter::SplitIndices;
+    use os_str_bytes::iter::Units;
+    use os_str_bytes::iter::Utf8Chunks;
+    use os_str_bytes::Pattern;
+
+    fn test<T>()
+    where
+        T: Clone + Debug + Send + Sync,
+    {
+    }
+
+    fn test_default<T>()
+    where
+        T: Clone + Debug + Default + Iterator + Send + Sync,
+    {
+        test::<T>();
+        assert!(T::default().next().is_none());
+    }
+
+    fn test_pattern<P>()
+    where
+        P: Pattern,
+    {
+        test::<RSplit<'_, P>>();
+        test::<RawRSplit<'_, P>>();
+        test::<RawSplit<'_, P>>();
+        test::<RawSplitIndices<'_, P>>();
+        test::<Split<'_, P>>();
+        test::<SplitIndices<'_, P>>();
+    }
+
+    test_default::<CodePoints<'_>>();
+    test_default::<IntoUtf8Chunks>();
+    test_default::<Units<'_>>();
+    test_default::<Utf8Chunks<'_>>();
+    test_pattern::<char>();
+    test_pattern::<&str>();
+    test_pattern::<&Box<str>>();
+    test_pattern::<std::borrow::Cow<'_, str>>();
+}
+
+#[test]
+fn test_split_once_any() {
+    #[track_caller]
+    fn test(pats: &[&str]) {
+        let string = OsStr::new("foo=bar:baz=qux");
+        let expected = pats
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &pat)| Some((string.find(pat)?, i)))
+            .min()
+            .map(|(_, i)| {
+                let (prefix, suffix) = string.split_once(pats[i]).unwrap();
+                (prefix, suffix, i)
+            });
+        assert_eq!(expected, string.split_once_any(pats));
+    }
+
+    test(&[]);
+    test(&["="]);
+    test(&[":", "="]);
+    test(&["=b", "="]);
+    test(&["=", "=b"]);
+    test(&[":baz", "z="]);
+    test(&[",", ";"]);
+    test(&["", "="]);
+}
+
+#[test]
+fn test_dyn_pattern() {
+    use os_str_bytes::DynPattern;
+
+    let pats: Vec<DynPattern> = vec!['\u{F6}'.into(), "--".into()];
+    let os_string = OsStr::new("foo\u{F6}bar--baz");
+    assert!(os_string.split(&pats[0]).eq(["foo", "bar--baz"]));
+    assert_eq!(
+        Some((OsStr::new("foo\u{F6}bar"), OsStr::new("baz"))),
+        os_string.split_once(&pats[1]),
+    );
+    assert_eq!(
+        Ok(&pats[1]),
+        DynPattern::try_from(OsStr::new("--")).as_ref(),
+    );
+}
+
+#[test]
+fn test_path_comparisons() {
+    use std::cmp::Ordering;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use os_str_bytes::RawOsStr;
+
+    let raw = RawOsStr::new("foo/bar");
+    let path = Path::new("foo//bar/");
+    assert_eq!(raw, path);
+    assert_eq!(path, raw);
+    assert_eq!(raw, &path.to_owned());
+    assert_eq!(raw.to_owned(), path);
+    assert_eq!(raw.to_owned(), PathBuf::from("foo/bar"));
+
+    let path = Path::new("foo/baz");
+    assert_ne!(raw, path);
+    assert_eq!(Some(Ordering::Less), raw.partial_cmp(path));
+    assert_eq!(Some(Ordering::Greater), path.partial_cmp(raw));
+    let ordering = raw.to_owned().partial_cmp(&path.to_owned());
+    assert_eq!(Some(Ordering::Less), ordering);
+}
+
+#[test]
+fn test_cow_map() {
+    use std::borrow::Cow;
+
+    use os_str_bytes::RawOsStr;
+    use os_str_bytes::RawOsStrCow;
+
+    let string = RawOsStr::new("  foo.txt  ");
+    let raw = Cow::Borrowed(string)
+        .map_borrowed(|x| x.trim_matches(' '))
+        .try_map(|x| x.strip_suffix(".txt"));
+    assert!(matches!(raw, Some(Cow::Borrowed(x)) if x == "foo"));
+
+    let raw = Cow::<RawOsStr>::Owned(string.to_owned())
+        .map_borrowed(|x| x.trim_end_matches(' '))
+        .try_map(|x| x.strip_prefix("  "));
+    assert!(matches!(raw, Some(Cow::Owned(ref x)) if x == "foo.txt"));
+
+    let raw = Cow::<RawOsStr>::Owned(string.to_owned())
+        .map_borrowed(|_| RawOsStr::new("bar"));
+    assert_eq!(RawOsStr::new("bar"), &*raw);
+
+    assert_eq!(None, Cow::Borrowed(string).try_map(|x| x.strip_prefix('.')));
+}
+
+#[test]
+fn test_has_extension() {
+    #[track_caller]
+    fn test(result: bool, string: &str, ext: &str) {
+        let string = OsStr::new(string);
+        assert_eq!(result, string.has_extension(ext, false));
+        assert_eq!(
+            result,
+            string.has_extension(&ext.to_ascii_uppercase(), true),
+        );
+    }
+
+    test(true, "foo.txt", "txt");
+    test(true, "foo.txt", ".txt");
+    test(true, "foo.tar.gz", "tar.gz");
+    test(true, "foo.txt/", "txt");
+    test(true, "bar/foo.txt", "txt");
+    test(true, ".foo.txt", "txt");
+    test(true, "foo.", "");
+    test(cfg!(windows), "foo.txt\\", "txt");
+
+    test(false, "foo.txt", "tx");
+    test(false, "footxt", "txt");
+    test(false, "foo.txt/bar", "txt");
+    test(false, ".txt", "txt");
+    test(false, ".tar.gz", "tar.gz");
+    test(false, "..", "");
+    test(false, "", "");
+
+    assert!(!OsStr::new("foo.TXT").has_extension("txt", false));
+}
+
+#[test]
+fn test_owned_patterns() {
+    use std::borrow::Cow;
+
+    let os_string = OsStr::new("foo--bar");
+    let pat: Box<str> = "--".into();
+    assert_eq!(Some(3), os_string.find(&pat));
+    assert_eq!(Some(3), os_string.find(Cow::Borrowed("--")));
+    assert!(os_string
+        .split(Cow::Owned("-".to_owned()))
+        .eq(["foo", "", "bar"]));
+}
+
+#[test]
+fn test_eq_os_ignoring_trailing_separators() {
+    let separators = ['/', '\\'];
+    let test = |string: &str, other: &str| {
+        OsStr::new(string)
+            .eq_os_ignoring_trailing_separators(OsStr::new(other), &separators)
+    };
+
+    assert!(test("", ""));
+    assert!(test("/", "//"));
+    assert!(test("/", "/\\"));
+    assert!(!test("/", ""));
+    assert!(!test("/", "\\"));
+    assert!(test("foo", "foo///"));
+    assert!(test("/foo\\", "/foo/"));
+    assert!(!test("/foo", "foo"));
+    assert!(!test("foo/bar", "foo\\bar"));
+
+    assert!(OsStr::new("foo--")
+        .eq_os_ignoring_trailing_separators(OsStr::new("foo"), &['-']));
+    assert!(OsStr::new("foo")
+        .eq_os_ignoring_trailing_separators(OsStr::new("foo"), &[]));
+}
+
+#[test]
+fn test_find_long_pattern() {
+    let os_string = OsStr::new("-f");
+    for pat in ["--foo", "-f\u{F6}", "\u{1F4A9}"] {
+        assert_eq!(None, os_string.find(pat));
+        assert_eq!(None, os_string.rfind(pat));
+        assert!(!os_string.contains(pat));
+        assert_eq!(None, os_string.split_once(pat));
+    }
+    assert_eq!(Some(0), os_string.find("-f"));
+    assert_eq!(Some(0), os_string.rfind('-'));
+    assert_eq!(Some(1), os_string.find('f'));
+}
+
+#[test]
+fn test_split_once_ascii() {
+    for string in ["", "=", "a=b", "a=b=c", "\u{F6}=\u{F6}", "abc"] {
+        let string = OsStr::new(string);
+        assert_eq!(string.split_once('='), string.split_once_ascii(b'='));
+    }
+}
+
+#[should_panic = "byte is not ASCII"]
+#[test]
+fn test_split_once_non_ascii() {
+    let _ = OsStr::new("\u{F6}").split_once_ascii(0xC3);
+}
+
+#[should_panic = "byte is not ASCII"]
+#[test]
+fn test_find_any_of_non_ascii() {
+    let _ = OsStr::new("\u{F6}").find_any_of(&[b'/', 0xC3]);
+}
+
+#[test]
+fn test_first_last_char() {
+    for string in ["", "a", "\u{F6}", "f\u{F6}\u{F6}", "\u{1F4A9}x\u{1F4A9}"] {
+        let os_string = OsStr::new(string);
+        assert_eq!(string.chars().next(), os_string.first_char());
+        assert_eq!(string.chars().next_back(), os_string.last_char());
+    }
+}
+
+if_conversions! {
+    #[test]
+    fn test_first_last_char_invalid() {
+        assert_eq!(None, WTF8_OS_STRING.index(3..).first_char());
+        assert_eq!(None, WTF8_OS_STRING.index(..6).last_char());
+    }
+}
+
+#[test]
+fn test_string_storage_push() {
+    let mut string = String::new();
+    let mut raw = RawOsString::default();
+    for ch in "foo\u{1F4A9}bar".chars().cycle().take(32) {
+        string.push(ch);
+        raw.push_char(ch);
+        assert_eq!(string, raw);
+    }
+
+    let mut raw = RawOsString::with_capacity(8);
+    raw.extend(["foo", "bar"].map(OsStr::new));
+    assert_eq!("foobar", raw);
+    raw.extend_from_os_strs(["baz"; 8].map(OsStr::new));
+    assert_eq!(format!("foobar{}", "baz".repeat(8)), raw);
+}
+
+#[test]
+fn test_starts_ends_with_any() {
+    // More patterns are used than are compared together.
+    let pats: Vec<_> = (0..100).map(|x| x.to_string()).collect();
+    let pats: Vec<_> = pats.iter().map(String::as_str).collect();
+
+    for string in ["", "x", "7", "42", "99x", "x99", "100", "x10"] {
+        let os_string = OsStr::new(string);
+        assert_eq!(
+            pats.iter().any(|x| string.starts_with(x)),
+            os_string.starts_with_any(&pats),
+        );
+        assert_eq!(
+            pats.iter().any(|x| string.ends_with(x)),
+            os_string.ends_with_any(&pats),
+        );
+    }
+
+    assert!(OsStr::new("").starts_with_any(&["foo", ""]));
+    assert!(!OsStr::new("foo").ends_with_any::<&str>(&[]));
+}
@@ -1,16 +1,263 @@
 #![cfg(feature = "raw_os_str")]
 
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use os_str_bytes::CharOrInvalid;
+use os_str_bytes::LimitUnit;
+use os_str_bytes::OsStrBytesExt;
+use os_str_bytes::OsStringBytesExt;
+
 #[macro_use]
 mod common;
 
-if_conversions! {
-    use std::ffi::OsStr;
+mod random_common;
+use random_common::ITERATIONS;
+use random_common::SMALL_LENGTH;
+
+#[test]
+fn test_split_first_last() {
+    for _ in 0..ITERATIONS {
+        let string = random_common::fastrand_os_string(SMALL_LENGTH);
+
+        let mut units = Vec::new();
+        for (invalid, valid) in string.utf8_chunks() {
+            if !invalid.as_os_str().is_empty() {
+                units.push(CharOrInvalid::Invalid(invalid));
+            }
+            units.extend(valid.chars().map(CharOrInvalid::Char));
+        }
+
+        let mut substring: &OsStr = &string;
+        let mut first_units = Vec::new();
+        while let Some((unit, suffix)) = substring.split_first() {
+            first_units.push(unit);
+            substring = suffix;
+        }
+        assert_eq!(units, first_units, "{:?}", string);
+
+        let mut substring: &OsStr = &string;
+        let mut last_units = Vec::new();
+        while let Some((unit, prefix)) = substring.split_last() {
+            last_units.push(unit);
+            substring = prefix;
+        }
+        last_units.reverse();
+        assert_eq!(units, last_units, "{:?}", string);
+    }
+}
+
+#[test]
+fn test_char_boundary() {
+    for _ in 0..ITERATIONS {
+        let string = random_common::fastrand_os_string(SMALL_LENGTH);
+        let length = string.len();
+        let boundaries: Vec<_> = (0..=length)
+            .filter(|&x| string.validate_boundary(x).is_ok())
+            .collect();
+        for index in 0..=length + 1 {
+            let floor = string.floor_char_boundary(index);
+            let ceil = string.ceil_char_boundary(index);
+            if index > length {
+                assert_eq!(length, floor);
+                assert_eq!(length, ceil);
+                continue;
+            }
+            assert_eq!(
+                boundaries.iter().rev().find(|&&x| x <= index),
+                Some(&floor),
+                "{:?}",
+                string,
+            );
+            assert_eq!(
+                boundaries.iter().find(|&&x| x >= index),
+                Some(&ceil),
+                "{:?}",
+                string,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_truncate_to_limit() {
+    fn len_utf16(string: &OsStr) -> usize {
+        string
+            .code_points()
+            .map(|x| if matches!(x, Ok(0x10000..)) { 2 } else { 1 })
+            .sum()
+    }
 
-    use os_str_bytes::OsStrBytesExt;
+    for _ in 0..ITERATIONS {
+        let string = random_common::fastrand_os_string(SMALL_LENGTH);
+        let length = string.len();
+        for max_units in 0..=length + 1 {
+            let prefix = string.truncate_to_limit(max_units, LimitUnit::Utf8);
+            assert!(prefix.len() <= max_units);
+            assert_eq!(prefix, string.index(..prefix.len()));
 
-    mod random_common;
-    use random_common::ITERATIONS;
-    use random_common::SMALL_LENGTH;
+            let prefix = string.truncate_to_limit(max_units, LimitUnit::Utf16);
+            assert!(len_utf16(prefix) <= max_units);
+            assert_eq!(prefix, string.index(..prefix.len()));
+        }
+        assert_eq!(string, string.truncate_to_limit(length, LimitUnit::Utf8));
+    }
+}
+
+#[test]
+fn test_eq_constant_time() {
+    for _ in 0..ITERATIONS {
+        let string = random_common::fastrand_os_string(SMALL_LENGTH);
+        let other = random_common::fastrand_os_string(SMALL_LENGTH);
+        assert!(string.eq_constant_time(&string));
+        assert_eq!(string == other, string.eq_constant_time(&other));
+    }
+}
+
+#[test]
+fn test_builder() {
+    use os_str_bytes::OsStrBuilder;
+
+    for _ in 0..ITERATIONS {
+        let mut builder = OsStrBuilder::new();
+        let mut expected = OsString::new();
+        for _ in 0..4 {
+            let string = random_common::fastrand_os_string(SMALL_LENGTH);
+            builder.push(&string);
+            expected.push(&string);
+        }
+
+        let (string, segments) = builder.finish();
+        assert_eq!(expected, string);
+        assert_eq!(4, segments.len());
+        let mut end = 0;
+        for segment in segments {
+            assert_eq!(end, segment.start);
+            end = segment.end;
+            assert!(string.validate_boundary(segment.start).is_ok());
+            assert!(string.validate_boundary(segment.end).is_ok());
+        }
+        assert_eq!(string.len(), end);
+    }
+}
+
+#[test]
+fn test_find() {
+    fn random_string(rng: &mut fastrand::Rng, max_length: usize) -> String {
+        (0..rng.usize(..=max_length))
+            .map(|_| rng.choice(['a', 'b']).expect("empty alphabet"))
+            .collect()
+    }
+
+    let mut rng = fastrand::Rng::new();
+    for _ in 0..ITERATIONS {
+        let string = random_string(&mut rng, SMALL_LENGTH);
+        let pat = random_string(&mut rng, 4);
+        let os_string = OsStr::new(&string);
+        assert_eq!(string.find(&pat), os_string.find(&*pat), "{:?}", pat);
+        assert_eq!(string.rfind(&pat), os_string.rfind(&*pat), "{:?}", pat);
+    }
+}
+
+#[test]
+fn test_escape_ascii() {
+    for _ in 0..ITERATIONS {
+        let string = random_common::fastrand_os_string(SMALL_LENGTH);
+        let escaped = string.escape_ascii();
+        assert!(escaped.bytes().all(|x| matches!(x, 0x20..=0x7E)));
+        assert_eq!(Ok(&string), OsString::unescape_ascii(&escaped).as_ref());
+    }
+}
+
+#[cfg(feature = "collections")]
+#[test]
+fn test_longest_prefix_of() {
+    use os_str_bytes::collections::OsStrTrieMap;
+
+    for _ in 0..ITERATIONS {
+        let key = random_common::fastrand_os_string(SMALL_LENGTH);
+        let mut map = OsStrTrieMap::new();
+        let prefixes: Vec<_> = (0..=key.len())
+            .filter_map(|x| Some(key.split_at_checked(x)?.0))
+            .collect();
+        for prefix in &prefixes {
+            assert_eq!(None, map.insert(prefix, prefix.len()));
+        }
+        assert_eq!(prefixes.len(), map.len());
+
+        for prefix in &prefixes {
+            let mut string = OsString::from(prefix);
+            string.push(random_common::fastrand_os_string(SMALL_LENGTH));
+
+            let expected = prefixes
+                .iter()
+                .copied()
+                .filter(|&x| {
+                    string.split_at_checked(x.len()).is_some_and(|y| x == y.0)
+                })
+                .max_by_key(|x| x.len())
+                .expect("missing empty prefix");
+            assert_eq!(
+                Some((expected, &expected.len())),
+                map.longest_prefix_of(&string),
+            );
+        }
+
+        for prefix in &prefixes {
+            assert_eq!(Some(prefix.len()), map.remove(prefix));
+        }
+        assert!(map.is_empty());
+    }
+}
+
+#[test]
+fn test_components_naive() {
+    use os_str_bytes::SplitOptions;
+
+    let options = SplitOptions::new().skip_empty(true);
+    for _ in 0..ITERATIONS {
+        let string = random_common::fastrand_os_string(SMALL_LENGTH);
+        let Some(separator) = string.first_char() else {
+            continue;
+        };
+        assert!(
+            string
+                .components_naive(&[separator])
+                .eq(string.split_with(separator, options)),
+            "{:?}",
+            string,
+        );
+    }
+}
+
+#[cfg(feature = "collections")]
+#[test]
+fn test_prefix_range() {
+    use os_str_bytes::collections;
+
+    for _ in 0..ITERATIONS {
+        let mut strings: Vec<_> = (0..8)
+            .map(|_| random_common::fastrand_os_string(SMALL_LENGTH))
+            .collect();
+        strings.sort();
+
+        let string = &strings[fastrand::usize(..strings.len())];
+        for index in 0..=string.len() {
+            let Some((prefix, _)) = string.split_at_checked(index) else {
+                continue;
+            };
+            let range = collections::prefix_range(&strings, prefix);
+            let prefix = prefix.as_encoded_bytes();
+            for (i, x) in strings.iter().enumerate() {
+                assert_eq!(
+                    range.contains(&i),
+                    x.as_encoded_bytes().starts_with(prefix),
+                    "{:?}",
+                    prefix,
+                );
+            }
+        }
+    }
 }
 
 if_conversions! {
@@ -43,3 +290,38 @@ if_conversions! {
         }
     }
 }
+
+#[cfg(windows)]
+if_conversions! {
+    #[test]
+    fn test_wide() {
+        use std::os::windows::ffi::OsStrExt;
+
+        use os_str_bytes::RawOsStr;
+        use os_str_bytes::RawOsString;
+
+        for _ in 0..ITERATIONS {
+            let string = random_common::fastrand_os_string(SMALL_LENGTH);
+            let wide = RawOsStr::new(&string).to_wide();
+            assert!(string.encode_wide().eq(wide.iter().copied()));
+            assert_eq!(string, RawOsString::from_wide(&wide));
+        }
+    }
+}
+
+#[cfg(windows)]
+#[test]
+fn test_push_code_unit() {
+    use std::os::windows::ffi::OsStrExt;
+
+    use os_str_bytes::RawOsString;
+
+    for _ in 0..ITERATIONS {
+        let string = random_common::fastrand_os_string(SMALL_LENGTH);
+        let mut raw = RawOsString::default();
+        for unit in string.encode_wide() {
+            raw.push_code_unit(unit);
+        }
+        assert_eq!(string, raw);
+    }
+}
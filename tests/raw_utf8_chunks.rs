@@ -3,6 +3,8 @@
 use std::ffi::OsStr;
 
 use os_str_bytes::OsStrBytesExt;
+use os_str_bytes::OsStringBytesExt;
+use os_str_bytes::RawOsString;
 
 #[macro_use]
 mod raw_common;
@@ -21,6 +23,38 @@ fn test(result: &[(&OsStr, &str)], string: &OsStr) {
             .map(|(invalid, valid)| (invalid.as_os_str(), valid))
             .collect::<Vec<_>>(),
     );
+    assert_eq!(
+        result,
+        string
+            .to_owned()
+            .into_utf8_chunks()
+            .collect::<Vec<_>>()
+            .iter()
+            .map(|(invalid, valid)| (&**invalid, &**valid))
+            .collect::<Vec<_>>(),
+    );
+
+    let mut chunks = result.iter();
+    let mut length = 0;
+    assert_eq!(
+        None::<()>,
+        string.find_map_chunks(|index, invalid, valid| {
+            assert_eq!(length, index);
+            assert_eq!(chunks.next(), Some(&(invalid.as_os_str(), valid)));
+            length += invalid.as_os_str().len() + valid.len();
+            None
+        }),
+    );
+    assert_eq!(None, chunks.next());
+
+    assert_eq!(string, string.utf8_chunks().collect::<RawOsString>());
+    assert_eq!(
+        string,
+        string
+            .to_owned()
+            .into_utf8_chunks()
+            .collect::<RawOsString>(),
+    );
 }
 
 #[test]
@@ -0,0 +1,31 @@
+#![cfg(feature = "test_util")]
+
+use std::ffi::OsStr;
+
+use os_str_bytes::test_util;
+use os_str_bytes::OsStrBytes;
+
+#[test]
+fn test_adversarial_os_strings() {
+    let strings: Vec<_> = test_util::adversarial_os_strings(2).collect();
+    assert_eq!(Some(OsStr::new("")), strings.first().map(|x| &**x));
+
+    let pieces = strings.len() - 1;
+    let pieces = (1..).find(|x| x + x * x == pieces).unwrap();
+    assert!(strings.iter().any(|x| x.to_str().is_none()));
+    assert_eq!(1 + pieces, test_util::adversarial_os_strings(1).count());
+}
+
+#[test]
+fn test_seeded_random_os_string() {
+    for seed in 0..16 {
+        let string = test_util::seeded_random_os_string(16, seed);
+        assert_eq!(string, test_util::seeded_random_os_string(16, seed));
+    }
+}
+
+#[test]
+fn test_wtf8_os_string() {
+    let string = test_util::wtf8_os_string();
+    assert_eq!(test_util::WTF8_STRING, &*string.to_raw_bytes());
+}